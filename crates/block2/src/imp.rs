@@ -0,0 +1,209 @@
+use core::fmt;
+
+use objc2::encode::{EncodeArguments, EncodeReturn};
+use objc2::ffi;
+use objc2::runtime::Imp;
+
+use crate::{Block, BlockFn, IntoBlock, RcBlock};
+
+/// A method implementation that calls a block.
+///
+/// This allows adding methods to classes at runtime that call capturing Rust
+/// closures, which is useful for e.g. mocking in tests.
+///
+/// The block is called with the receiver as the first argument, followed by
+/// the method's arguments. Note that unlike normal method implementations,
+/// the selector is _not_ passed.
+///
+/// Since a method can be called from any thread once it has been added to a
+/// class, the closure must be [`Send`] and [`Sync`].
+///
+/// The implementation is freed with `imp_removeBlock` when this is dropped,
+/// so it must not be called afterwards. In particular, you must remove or
+/// replace it in any class that you've added it to before dropping it, or
+/// use [`into_imp`] to leak it.
+///
+/// See [Apple's documentation][apple-doc] for details.
+///
+/// [`into_imp`]: Self::into_imp
+/// [apple-doc]: https://developer.apple.com/documentation/objectivec/1418587-imp_implementationwithblock?language=objc
+///
+///
+/// # Example
+///
+/// Add a method to a class that calls a closure.
+///
+/// ```
+/// use std::ffi::CString;
+///
+/// use block2::BlockImp;
+/// use objc2::rc::Retained;
+/// use objc2::runtime::{AnyObject, ClassBuilder, NSObject};
+/// use objc2::{ffi, msg_send, msg_send_id, sel, ClassType};
+///
+/// let offset = 3;
+/// let imp = BlockImp::from_closure(move |_this: &AnyObject, value: i32| value + offset);
+///
+/// let cls = ClassBuilder::new(&CString::new("BlockImpExample").unwrap(), NSObject::class())
+///     .unwrap()
+///     .register();
+/// let types = CString::new("i@:i").unwrap();
+/// // SAFETY: The encoding matches the closure.
+/// let added = unsafe {
+///     ffi::class_addMethod(
+///         cls as *const _ as *mut _,
+///         sel!(addOffset:),
+///         imp.imp(),
+///         types.as_ptr(),
+///     )
+/// };
+/// assert!(added.as_bool());
+///
+/// let obj: Retained<AnyObject> = unsafe { msg_send_id![cls, new] };
+/// let result: i32 = unsafe { msg_send![&obj, addOffset: 4i32] };
+/// assert_eq!(result, 7);
+///
+/// // The class is registered globally, so leak the implementation.
+/// let _ = imp.into_imp();
+/// ```
+#[doc(alias = "imp_implementationWithBlock")]
+pub struct BlockImp {
+    imp: Imp,
+}
+
+impl BlockImp {
+    /// Create a method implementation that calls the given block.
+    ///
+    /// The block is copied, so it does not have to outlive the returned
+    /// value.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The block must be safe to call from any thread, also concurrently,
+    /// i.e. the closure it contains must be [`Send`] and [`Sync`].
+    ///
+    /// Additionally, it must not capture any data with a lifetime shorter
+    /// than that of the returned value.
+    #[inline]
+    pub unsafe fn new<F: ?Sized + BlockFn>(block: &Block<F>) -> Self {
+        let ptr: *const Block<F> = block;
+        // SAFETY: The pointer is a valid block, which the runtime copies.
+        let imp = unsafe { ffi::imp_implementationWithBlock(ptr as *mut _) };
+        Self { imp }
+    }
+
+    /// Create a method implementation that calls the given closure.
+    ///
+    /// The first parameter of the closure is the receiver, usually
+    /// `&AnyObject`, and the rest are the method's arguments.
+    #[inline]
+    pub fn from_closure<A, R, Closure>(closure: Closure) -> Self
+    where
+        A: EncodeArguments,
+        R: EncodeReturn,
+        Closure: IntoBlock<'static, A, R> + Send + Sync,
+    {
+        let block = RcBlock::new(closure);
+        // SAFETY: The closure is `Send + Sync + 'static`.
+        unsafe { Self::new(&block) }
+    }
+
+    /// The method implementation.
+    ///
+    /// This is only valid for as long as `self` is alive.
+    #[inline]
+    pub fn imp(&self) -> Imp {
+        self.imp
+    }
+
+    /// Leak the method implementation, such that it is never freed.
+    ///
+    /// This is useful when adding the implementation to a class that is
+    /// never disposed of.
+    #[inline]
+    pub fn into_imp(self) -> Imp {
+        let imp = self.imp;
+        core::mem::forget(self);
+        imp
+    }
+}
+
+impl Drop for BlockImp {
+    #[inline]
+    fn drop(&mut self) {
+        // SAFETY: The implementation was created with
+        // `imp_implementationWithBlock`, and is only removed once.
+        let _ = unsafe { ffi::imp_removeBlock(self.imp) };
+    }
+}
+
+impl fmt::Debug for BlockImp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockImp")
+            .field("imp", &(self.imp as *const ()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::ffi::CString;
+    use alloc::sync::Arc;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    use objc2::rc::Retained;
+    use objc2::runtime::{AnyObject, ClassBuilder, NSObject};
+    use objc2::{msg_send, msg_send_id, sel, ClassType};
+
+    use super::*;
+
+    #[test]
+    fn add_method_from_closure() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let imp = BlockImp::from_closure({
+            let calls = Arc::clone(&calls);
+            move |_this: &AnyObject, value: i32| {
+                calls.fetch_add(1, Ordering::Relaxed);
+                value * 2
+            }
+        });
+
+        let cls = ClassBuilder::new(&CString::new("BlockImpTest").unwrap(), NSObject::class())
+            .unwrap()
+            .register();
+        let types = CString::new("i@:i").unwrap();
+        let added = unsafe {
+            ffi::class_addMethod(
+                cls as *const _ as *mut _,
+                sel!(double:),
+                imp.imp(),
+                types.as_ptr(),
+            )
+        };
+        assert!(added.as_bool());
+
+        let obj: Retained<AnyObject> = unsafe { msg_send_id![cls, new] };
+        let result: i32 = unsafe { msg_send![&obj, double: 21i32] };
+        assert_eq!(result, 42);
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        // The block holds a reference to the closure until it is removed.
+        let _ = imp.into_imp();
+        assert_eq!(Arc::strong_count(&calls), 2);
+    }
+
+    #[test]
+    fn remove_on_drop() {
+        let value = Arc::new(());
+        let imp = BlockImp::from_closure({
+            let value = Arc::clone(&value);
+            move |_this: &AnyObject| {
+                let _ = &value;
+            }
+        });
+        assert_eq!(Arc::strong_count(&value), 2);
+        drop(imp);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+}
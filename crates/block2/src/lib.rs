@@ -368,12 +368,16 @@ mod debug;
 mod encoding;
 pub mod ffi;
 mod global;
+#[cfg(not(feature = "unstable-objfw"))]
+mod imp;
 mod rc_block;
 mod stack;
 mod traits;
 
 pub use self::block::Block;
 pub use self::global::GlobalBlock;
+#[cfg(not(feature = "unstable-objfw"))]
+pub use self::imp::BlockImp;
 pub use self::rc_block::RcBlock;
 pub use self::stack::StackBlock;
 pub use self::traits::{BlockFn, IntoBlock, ManualBlockEncoding};
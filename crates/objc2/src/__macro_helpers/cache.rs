@@ -32,7 +32,7 @@ impl CachedSel {
         //
         // We know this, because we construct it in `sel!` ourselves
         let sel = unsafe { Sel::register_unchecked(name) };
-        self.ptr.store(sel.as_ptr() as *mut _, Ordering::Relaxed);
+        self.ptr.store(sel.as_ptr().cast_mut(), Ordering::Relaxed);
         sel
     }
 
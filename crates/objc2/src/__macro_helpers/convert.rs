@@ -13,8 +13,8 @@ mod argument_private {
 /// Objective-C `BOOL`, where it would otherwise not be allowed (since they
 /// are not ABI compatible).
 ///
-/// This is also done specially for `&mut Retained<_>`-like arguments, to allow
-/// using those as "out" parameters.
+/// This is also done specially for `&mut Retained<_>`-like arguments and
+/// `&mut bool`, to allow using those as "out" parameters.
 pub trait ConvertArgument: argument_private::Sealed {
     /// The inner type that this can be converted to and from.
     #[doc(hidden)]
@@ -40,6 +40,8 @@ impl<T: Message> argument_private::Sealed for &mut Retained<T> {}
 impl<T: Message> argument_private::Sealed for Option<&mut Retained<T>> {}
 impl<T: Message> argument_private::Sealed for &mut Option<Retained<T>> {}
 impl<T: Message> argument_private::Sealed for Option<&mut Option<Retained<T>>> {}
+impl argument_private::Sealed for &mut bool {}
+impl argument_private::Sealed for Option<&mut bool> {}
 
 impl<T: EncodeArgument> argument_private::Sealed for T {}
 impl<T: EncodeArgument> ConvertArgument for T {
@@ -1,3 +1,4 @@
+pub use alloc::boxed::Box;
 pub use core::borrow::Borrow;
 pub use core::cell::UnsafeCell;
 pub use core::cmp::{Eq, PartialEq};
@@ -7,10 +8,10 @@ pub use core::fmt;
 pub use core::hash::{Hash, Hasher};
 pub use core::marker::{PhantomData, Sized};
 pub use core::mem::{size_of, ManuallyDrop, MaybeUninit};
-pub use core::ops::Deref;
+pub use core::ops::{self, Deref};
 pub use core::option::Option::{self, None, Some};
 pub use core::primitive::{bool, isize, str, u8};
-pub use core::{compile_error, concat, panic, stringify};
+pub use core::{compile_error, concat, panic, stringify, write};
 // TODO: Use `core::cell::LazyCell`
 pub use std::sync::Once;
 
@@ -43,7 +44,7 @@ pub use self::method_family::{
     retain_semantics, Alloc, Copy, Init, MutableCopy, New, Other, RetainSemantics,
 };
 pub use self::module_info::ModuleInfo;
-pub use self::msg_send::MsgSend;
+pub use self::msg_send::{MsgSend, NilReturn, ZeroableReturn};
 pub use self::msg_send_retained::{MaybeUnwrap, MsgSendRetained, MsgSendSuperRetained};
 pub use self::os_version::{is_available, AvailableVersion, OSVersion};
 pub use self::sync_unsafe_cell::SyncUnsafeCell;
@@ -1,9 +1,9 @@
-use core::mem::ManuallyDrop;
-use core::ptr;
+use core::mem::{self, ManuallyDrop};
+use core::ptr::{self, NonNull};
 
 use crate::encode::RefEncode;
 use crate::rc::Retained;
-use crate::runtime::{AnyClass, AnyObject, MessageReceiver, Sel};
+use crate::runtime::{AnyClass, AnyObject, Bool, MessageReceiver, Sel};
 use crate::{ClassType, Encode, Message};
 
 use super::{ConvertArguments, ConvertReturn, TupleExtender};
@@ -11,6 +11,12 @@ use super::{ConvertArguments, ConvertReturn, TupleExtender};
 pub trait MsgSend: Sized {
     type Inner: ?Sized + RefEncode;
 
+    /// Whether the receiver is an `Option`, where `None` represents `nil`.
+    ///
+    /// Messages to such receivers are not sent if the receiver is `nil`,
+    /// instead the zero value is returned, as the Objective-C runtime would.
+    const NULLABLE: bool = false;
+
     fn into_raw_receiver(self) -> *mut AnyObject;
 
     #[inline]
@@ -19,11 +25,20 @@ pub trait MsgSend: Sized {
     where
         A: ConvertArguments,
         R: ConvertReturn,
+        Self: NilReturn<R>,
     {
+        let receiver = self.into_raw_receiver();
+        if Self::NULLABLE && receiver.is_null() {
+            // The arguments are dropped without being converted, so
+            // out-parameters are left untouched.
+            drop(args);
+            return Self::nil_return();
+        }
+
         let (args, stored) = A::__into_arguments(args);
 
         // SAFETY: Upheld by caller
-        let result = unsafe { MessageReceiver::send_message(self.into_raw_receiver(), sel, args) };
+        let result = unsafe { MessageReceiver::send_message(receiver, sel, args) };
 
         // TODO: If we want `objc_retainAutoreleasedReturnValue` to
         // work, we must not do any work before it has been run; so
@@ -84,6 +99,7 @@ pub trait MsgSend: Sized {
         A: TupleExtender<*mut *mut E>,
         <A as TupleExtender<*mut *mut E>>::PlusOneArgument: ConvertArguments,
         E: Message,
+        Self: NilReturn<bool>,
     {
         let mut err: *mut E = ptr::null_mut();
         let args = args.add_argument(&mut err);
@@ -153,12 +169,107 @@ unsafe fn encountered_error<E: Message>(err: *mut E) -> Retained<E> {
         .expect("error parameter should be set if the method returns NO")
 }
 
+/// Return types that are valid when zero-initialized.
+///
+/// This is what messaging `nil` returns, so only these types can be returned
+/// from messages sent to an optional receiver.
+///
+///
+/// # Safety
+///
+/// The all-zeroes bit pattern must be a valid value of [`ConvertReturn::__Inner`].
+pub unsafe trait ZeroableReturn: ConvertReturn {}
+
+macro_rules! zeroable_return {
+    ($($t:ty),* $(,)?) => {$(
+        // SAFETY: Zero is a valid value of the type.
+        unsafe impl ZeroableReturn for $t {}
+    )*};
+}
+
+zeroable_return!(
+    (),
+    bool,
+    Bool,
+    i8,
+    i16,
+    i32,
+    i64,
+    isize,
+    u8,
+    u16,
+    u32,
+    u64,
+    usize,
+    f32,
+    f64,
+    Option<Sel>,
+);
+
+// SAFETY: Zero is the NULL pointer.
+unsafe impl<T: ?Sized + RefEncode> ZeroableReturn for *const T {}
+// SAFETY: Same as above.
+unsafe impl<T: ?Sized + RefEncode> ZeroableReturn for *mut T {}
+// SAFETY: Zero is `None`.
+unsafe impl<T: ?Sized + RefEncode> ZeroableReturn for Option<&T> {}
+// SAFETY: Same as above.
+unsafe impl<T: ?Sized + RefEncode> ZeroableReturn for Option<&mut T> {}
+// SAFETY: Same as above.
+unsafe impl<T: ?Sized + RefEncode> ZeroableReturn for Option<NonNull<T>> {}
+
+/// The value returned from a message to the receiver if it is `nil`.
+///
+/// Optional receivers can be `nil`, and thus require the return type to be
+/// [`ZeroableReturn`]. Other receivers are never `nil`, and can return any
+/// type.
+pub trait NilReturn<R> {
+    #[doc(hidden)]
+    fn nil_return() -> R;
+}
+
+impl<T: MessageReceiver, R> NilReturn<R> for T {
+    #[inline]
+    fn nil_return() -> R {
+        unreachable!("receiver is never nil")
+    }
+}
+
+impl<T: ?Sized + Message, R> NilReturn<R> for &Retained<T> {
+    #[inline]
+    fn nil_return() -> R {
+        unreachable!("receiver is never nil")
+    }
+}
+
+impl<T: ?Sized + Message, R> NilReturn<R> for ManuallyDrop<Retained<T>> {
+    #[inline]
+    fn nil_return() -> R {
+        unreachable!("receiver is never nil")
+    }
+}
+
+impl<T: ?Sized + Message, R: ZeroableReturn> NilReturn<R> for Option<&T> {
+    #[inline]
+    fn nil_return() -> R {
+        // SAFETY: Checked by the `ZeroableReturn` bound.
+        R::__from_return(unsafe { mem::zeroed() })
+    }
+}
+
+impl<T: ?Sized + Message, R: ZeroableReturn> NilReturn<R> for Option<&Retained<T>> {
+    #[inline]
+    fn nil_return() -> R {
+        // SAFETY: Checked by the `ZeroableReturn` bound.
+        R::__from_return(unsafe { mem::zeroed() })
+    }
+}
+
 impl<T: MessageReceiver> MsgSend for T {
-    type Inner = T::__Inner;
+    type Inner = <T as MessageReceiver>::__Inner;
 
     #[inline]
     fn into_raw_receiver(self) -> *mut AnyObject {
-        MessageReceiver::__as_raw_receiver(self)
+        MessageReceiver::as_raw_receiver(self)
     }
 }
 
@@ -180,6 +291,34 @@ impl<T: ?Sized + Message> MsgSend for ManuallyDrop<Retained<T>> {
     }
 }
 
+impl<T: ?Sized + Message> MsgSend for Option<&T> {
+    type Inner = T;
+
+    const NULLABLE: bool = true;
+
+    #[inline]
+    fn into_raw_receiver(self) -> *mut AnyObject {
+        match self {
+            Some(obj) => MessageReceiver::as_raw_receiver(obj),
+            None => ptr::null_mut(),
+        }
+    }
+}
+
+impl<T: ?Sized + Message> MsgSend for Option<&Retained<T>> {
+    type Inner = T;
+
+    const NULLABLE: bool = true;
+
+    #[inline]
+    fn into_raw_receiver(self) -> *mut AnyObject {
+        match self {
+            Some(obj) => MsgSend::into_raw_receiver(obj),
+            None => ptr::null_mut(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::rc::{autoreleasepool, RcTestObject, ThreadTestData};
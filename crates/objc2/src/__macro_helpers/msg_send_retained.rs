@@ -1,7 +1,7 @@
 use core::ptr::{self, NonNull};
 
 use crate::encode::{Encode, RefEncode};
-use crate::rc::{Allocated, PartialInit, Retained};
+use crate::rc::{Allocated, NewObjectError, PartialInit, Retained};
 use crate::runtime::{AnyClass, AnyObject, Sel};
 use crate::{sel, ClassType, DefinedClass, Message};
 
@@ -177,6 +177,9 @@ impl<T: MsgSend, U: ?Sized + Message> MsgSendRetained<T, Option<Retained<U>>> fo
         args: A,
     ) -> R {
         let ptr = obj.into_raw_receiver();
+        if T::NULLABLE && ptr.is_null() {
+            return R::maybe_unwrap::<Self>(None, (None, sel));
+        }
         // SAFETY: Checked by caller
         let obj = unsafe { MsgSend::send_message(ptr, sel, args) };
         // SAFETY: The selector is `new`, so this has +1 retain count
@@ -339,12 +342,16 @@ impl<T: MsgSend, U: ?Sized + Message> MsgSendRetained<T, Option<Retained<U>>> fo
         sel: Sel,
         args: A,
     ) -> R {
+        let ptr = obj.into_raw_receiver();
+        if T::NULLABLE && ptr.is_null() {
+            return R::maybe_unwrap::<Self>(None, sel);
+        }
         // SAFETY: Checked by caller
-        let obj = unsafe { MsgSend::send_message(obj, sel, args) };
+        let obj = unsafe { MsgSend::send_message(ptr, sel, args) };
         // SAFETY: The selector is `copy` or `mutableCopy`, so this has +1
         // retain count
         let obj = unsafe { Retained::from_raw(obj) };
-        R::maybe_unwrap::<Self>(obj, ())
+        R::maybe_unwrap::<Self>(obj, sel)
     }
 }
 
@@ -365,7 +372,7 @@ impl<T: MsgSend, U: ?Sized + Message> MsgSendSuperRetained<T, Option<Retained<U>
         let obj = unsafe { MsgSend::send_super_message(obj, superclass, sel, args) };
         // SAFETY: Same as in `send_message_retained`
         let obj = unsafe { Retained::from_raw(obj) };
-        R::maybe_unwrap::<Self>(obj, ())
+        R::maybe_unwrap::<Self>(obj, sel)
     }
 }
 
@@ -379,12 +386,16 @@ impl<T: MsgSend, U: ?Sized + Message> MsgSendRetained<T, Option<Retained<U>>> fo
         sel: Sel,
         args: A,
     ) -> R {
+        let ptr = obj.into_raw_receiver();
+        if T::NULLABLE && ptr.is_null() {
+            return R::maybe_unwrap::<Self>(None, sel);
+        }
         // SAFETY: Checked by caller
-        let obj = unsafe { MsgSend::send_message(obj, sel, args) };
+        let obj = unsafe { MsgSend::send_message(ptr, sel, args) };
         // SAFETY: The selector is `copy` or `mutableCopy`, so this has +1
         // retain count
         let obj = unsafe { Retained::from_raw(obj) };
-        R::maybe_unwrap::<Self>(obj, ())
+        R::maybe_unwrap::<Self>(obj, sel)
     }
 }
 
@@ -405,7 +416,7 @@ impl<T: MsgSend, U: ?Sized + Message> MsgSendSuperRetained<T, Option<Retained<U>
         let obj = unsafe { MsgSend::send_super_message(obj, superclass, sel, args) };
         // SAFETY: Same as in `send_message_retained`
         let obj = unsafe { Retained::from_raw(obj) };
-        R::maybe_unwrap::<Self>(obj, ())
+        R::maybe_unwrap::<Self>(obj, sel)
     }
 }
 
@@ -420,6 +431,9 @@ impl<T: MsgSend, U: Message> MsgSendRetained<T, Option<Retained<U>>> for Other {
         args: A,
     ) -> R {
         let ptr = obj.into_raw_receiver();
+        if T::NULLABLE && ptr.is_null() {
+            return R::maybe_unwrap::<Self>(None, (None, sel));
+        }
         // SAFETY: Checked by caller
         let obj = unsafe { MsgSend::send_message(ptr, sel, args) };
         // All code between the message send and the `retain_autoreleased`
@@ -491,6 +505,21 @@ impl<T: ?Sized> MaybeUnwrap for Retained<T> {
     }
 }
 
+impl<T: ClassType> MaybeUnwrap for Result<Retained<T>, NewObjectError> {
+    type Input = Option<Retained<T>>;
+
+    #[inline]
+    fn maybe_unwrap<'a, F: MsgSendRetainedFailed<'a>>(
+        obj: Option<Retained<T>>,
+        args: F::Args,
+    ) -> Self {
+        match obj {
+            Some(obj) => Ok(obj),
+            None => Err(NewObjectError::new(T::class(), F::sel(&args))),
+        }
+    }
+}
+
 impl<T: ?Sized> MaybeUnwrap for Allocated<T> {
     type Input = Allocated<T>;
 
@@ -511,6 +540,9 @@ pub trait MsgSendRetainedFailed<'a> {
 
     #[track_caller]
     fn failed(args: Self::Args) -> !;
+
+    /// The selector that was sent, used when returning an error instead.
+    fn sel(args: &Self::Args) -> Sel;
 }
 
 impl<'a> MsgSendRetainedFailed<'a> for New {
@@ -533,6 +565,11 @@ impl<'a> MsgSendRetainedFailed<'a> for New {
             panic!("unexpected NULL {sel}; receiver was NULL");
         }
     }
+
+    #[inline]
+    fn sel((_, sel): &Self::Args) -> Sel {
+        *sel
+    }
 }
 
 impl MsgSendRetainedFailed<'_> for Alloc {
@@ -542,6 +579,11 @@ impl MsgSendRetainedFailed<'_> for Alloc {
     fn failed(_: Self::Args) -> ! {
         unreachable!()
     }
+
+    #[inline]
+    fn sel(_: &Self::Args) -> Sel {
+        sel!(alloc)
+    }
 }
 
 impl MsgSendRetainedFailed<'_> for Init {
@@ -561,24 +603,39 @@ impl MsgSendRetainedFailed<'_> for Init {
             }
         }
     }
+
+    #[inline]
+    fn sel((_, sel): &Self::Args) -> Sel {
+        *sel
+    }
 }
 
 impl MsgSendRetainedFailed<'_> for Copy {
-    type Args = ();
+    type Args = Sel;
 
     #[cold]
     fn failed(_: Self::Args) -> ! {
         panic!("failed copying object")
     }
+
+    #[inline]
+    fn sel(sel: &Self::Args) -> Sel {
+        *sel
+    }
 }
 
 impl MsgSendRetainedFailed<'_> for MutableCopy {
-    type Args = ();
+    type Args = Sel;
 
     #[cold]
     fn failed(_: Self::Args) -> ! {
         panic!("failed copying object")
     }
+
+    #[inline]
+    fn sel(sel: &Self::Args) -> Sel {
+        *sel
+    }
 }
 
 impl<'a> MsgSendRetainedFailed<'a> for Other {
@@ -596,6 +653,11 @@ impl<'a> MsgSendRetainedFailed<'a> for Other {
             panic!("unexpected NULL {sel}; receiver was NULL");
         }
     }
+
+    #[inline]
+    fn sel((_, sel): &Self::Args) -> Sel {
+        *sel
+    }
 }
 
 #[cfg(test)]
@@ -852,6 +914,21 @@ mod tests {
         let _obj: Retained<RcTestObject> = unsafe { msg_send_id![obj, initReturningNull] };
     }
 
+    #[test]
+    fn test_init_with_null_as_result() {
+        let obj: Allocated<RcTestObject> = unsafe { msg_send_id![RcTestObject::class(), alloc] };
+        let res: Result<Retained<RcTestObject>, NewObjectError> =
+            unsafe { msg_send_id![obj, initReturningNull] };
+        let err = res.expect_err("init returned NULL");
+        assert_eq!(err.class(), RcTestObject::class());
+        assert_eq!(err.sel(), sel!(initReturningNull));
+
+        let obj: Allocated<RcTestObject> = unsafe { msg_send_id![RcTestObject::class(), alloc] };
+        let res: Result<Retained<RcTestObject>, NewObjectError> =
+            unsafe { msg_send_id![obj, init] };
+        assert!(res.is_ok());
+    }
+
     #[test]
     #[cfg_attr(debug_assertions, should_panic = "messsaging init to nil")]
     #[cfg_attr(not(debug_assertions), ignore = "failed allocating object")]
@@ -12,6 +12,7 @@ use core::ptr::NonNull;
 
 use super::ConvertArgument;
 use crate::rc::Retained;
+use crate::runtime::Bool;
 use crate::Message;
 
 // Note the `'static` bound here - this may not be necessary, but I'm unsure
@@ -214,11 +215,100 @@ impl<T: Message + 'static> ConvertArgument for Option<&mut Option<Retained<T>>>
     }
 }
 
+// `BOOL` has the same size as `bool` on all platforms except GNUStep on
+// Windows, so there we can reuse the pointer that the user provides, and fix
+// up the value afterwards. On the remaining platforms, the user has to use
+// `&mut Bool` instead.
+#[cfg(not(all(
+    feature = "gnustep-1-7",
+    not(feature = "unstable-gnustep-strict-apple-compat"),
+    windows,
+    not(all(target_pointer_width = "64", target_env = "gnu")),
+)))]
+impl ConvertArgument for &mut bool {
+    type __Inner = NonNull<Bool>;
+
+    type __StoredBeforeMessage = NonNull<Bool>;
+
+    #[inline]
+    fn __from_defined_param(inner: Self::__Inner) -> Self {
+        let ptr = inner.as_ptr();
+        // The caller may have stored any `BOOL` value, such as `2`, which
+        // would not be a valid `bool`, so normalize it before handing out a
+        // reference.
+        //
+        // SAFETY: The out-parameter is valid for reads and writes of `BOOL`
+        // for the duration of the method call, and `bool` has the same size.
+        unsafe {
+            let value = ptr.read().as_bool();
+            ptr.cast::<bool>().write(value);
+            &mut *ptr.cast::<bool>()
+        }
+    }
+
+    #[inline]
+    fn __into_argument(self) -> (Self::__Inner, Self::__StoredBeforeMessage) {
+        // `false` and `true` have the same representation as `NO` and `YES`.
+        let ptr: NonNull<Bool> = NonNull::from(self).cast();
+        (ptr, ptr)
+    }
+
+    #[inline]
+    unsafe fn __process_after_message_send(ptr: Self::__StoredBeforeMessage) {
+        // The method may have written any `BOOL` value, such as `2`, which
+        // would not be a valid `bool`, so normalize it.
+        //
+        // SAFETY: The pointer came from `&mut bool`, and is valid for reads
+        // and writes of `Bool`, since they have the same size.
+        let value = unsafe { ptr.as_ptr().read() }.as_bool();
+        // SAFETY: Same as above.
+        unsafe { ptr.cast::<bool>().as_ptr().write(value) };
+    }
+}
+
+#[cfg(not(all(
+    feature = "gnustep-1-7",
+    not(feature = "unstable-gnustep-strict-apple-compat"),
+    windows,
+    not(all(target_pointer_width = "64", target_env = "gnu")),
+)))]
+impl ConvertArgument for Option<&mut bool> {
+    type __Inner = Option<NonNull<Bool>>;
+
+    type __StoredBeforeMessage = Option<NonNull<Bool>>;
+
+    #[inline]
+    fn __from_defined_param(inner: Self::__Inner) -> Self {
+        inner.map(<&mut bool>::__from_defined_param)
+    }
+
+    #[inline]
+    fn __into_argument(self) -> (Self::__Inner, Self::__StoredBeforeMessage) {
+        if let Some(this) = self {
+            let (ptr, stored) = this.__into_argument();
+            (Some(ptr), Some(stored))
+        } else {
+            (None, None)
+        }
+    }
+
+    #[inline]
+    unsafe fn __process_after_message_send(stored: Self::__StoredBeforeMessage) {
+        if let Some(stored) = stored {
+            // SAFETY: Checked by caller
+            unsafe { <&mut bool>::__process_after_message_send(stored) };
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use core::ffi::CStr;
+
     use crate::rc::{autoreleasepool, Allocated, RcTestObject, ThreadTestData};
-    use crate::{msg_send, msg_send_id, ClassType};
+    use crate::runtime::{AnyObject, ClassBuilder, NSObject, Sel};
+    use crate::{msg_send, msg_send_id, sel, ClassType};
 
     #[test]
     fn test_bool_error() {
@@ -298,6 +388,66 @@ mod tests {
         let _: () = unsafe { msg_send![cls, outParamNull: &mut param] };
     }
 
+    #[test]
+    fn test_bool_out_param() {
+        extern "C-unwind" fn set_yes(_this: &AnyObject, _cmd: Sel, out: *mut Bool) {
+            if let Some(out) = unsafe { out.as_mut() } {
+                *out = Bool::YES;
+            }
+        }
+
+        let name = CStr::from_bytes_with_nul(b"TestBoolOutParam\0").unwrap();
+        let mut builder = ClassBuilder::new(name, NSObject::class()).unwrap();
+        unsafe { builder.add_method(sel!(setYes:), set_yes as extern "C-unwind" fn(_, _, _)) };
+        let cls = builder.register();
+        let obj: Retained<NSObject> = unsafe { msg_send_id![cls, new] };
+
+        let mut value = false;
+        let _: () = unsafe { msg_send![&obj, setYes: &mut value] };
+        assert!(value);
+
+        let mut value = false;
+        let _: () = unsafe { msg_send![&obj, setYes: Some(&mut value)] };
+        assert!(value);
+
+        let _: () = unsafe { msg_send![&obj, setYes: None::<&mut bool>] };
+    }
+
+    #[test]
+    fn test_bool_out_param_defined() {
+        use crate::define_class;
+
+        define_class!(
+            #[unsafe(super(NSObject))]
+            #[name = "TestBoolOutParamDefined"]
+            struct TestBoolOutParamDefined;
+
+            unsafe impl TestBoolOutParamDefined {
+                #[method(toggle:)]
+                fn toggle(&self, value: &mut bool) {
+                    *value = !*value;
+                }
+
+                #[method(toggleOptional:)]
+                fn toggle_optional(&self, value: Option<&mut bool>) {
+                    if let Some(value) = value {
+                        *value = !*value;
+                    }
+                }
+            }
+        );
+
+        let obj: Retained<TestBoolOutParamDefined> =
+            unsafe { msg_send_id![TestBoolOutParamDefined::class(), new] };
+
+        let mut value = false;
+        let _: () = unsafe { msg_send![&obj, toggle: &mut value] };
+        assert!(value);
+        let _: () = unsafe { msg_send![&obj, toggleOptional: Some(&mut value)] };
+        assert!(!value);
+        let _: () = unsafe { msg_send![&obj, toggleOptional: None::<&mut bool>] };
+    }
+
     // TODO: Fix this in release mode with Apple's runtime
     const AUTORELEASE_SKIPPED: bool = cfg!(feature = "gnustep-1-7");
 
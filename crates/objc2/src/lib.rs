@@ -206,6 +206,8 @@ pub mod exception;
 pub mod ffi;
 mod macros;
 mod main_thread_marker;
+#[cfg(feature = "objc-compat")]
+pub mod objc_compat;
 pub mod rc;
 pub mod runtime;
 #[cfg(test)]
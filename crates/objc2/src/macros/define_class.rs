@@ -38,13 +38,43 @@
 /// the [`DefinedClass`] trait, as well as any protocols specified in the
 /// protocol implementations.
 ///
-/// If the type implements [`Drop`], the macro will generate a `dealloc`
-/// method for you, which will call `drop` automatically.
-///
-/// The macro does not support generic types.
+/// A class method with the selector `initialize` and no parameters or
+/// return type, e.g. `#[method(initialize)] fn initialize() { ... }`, is
+/// treated specially: The runtime calls it before the class receives its
+/// first message, and the macro ensures that it is only run once for this
+/// class, and not again when subclasses that don't implement `+initialize`
+/// themselves are initialized. This is the place to put lazy one-time setup,
+/// such as populating caches or registering keys.
+///
+/// `+initialize` runs on whichever thread first sends a message to the
+/// class, and other threads messaging the class block until it has
+/// completed. Be careful not to wait on other threads that may use the
+/// class from inside it, since that will deadlock.
+///
+/// `+load` is not supported, and declaring `#[method(load)]` as a class
+/// method is a compile error: Classes created with this macro are registered
+/// lazily when first used, long after the runtime has loaded the binary, so
+/// the runtime would never call it.
+///
+/// If the type implements [`Drop`], or if the ivars need to be dropped, the
+/// macro will generate a `dealloc` method for you. When the object is
+/// deallocated, this does the following, in order:
+/// 1. Call [`Drop::drop`] on the class, if the object was fully initialized.
+///    This is the place to put any custom deinitialization logic; it can
+///    still access the ivars.
+/// 2. Drop the ivars, if they were initialized (using the normal Rust drop
+///    order for the type given in `#[ivars = ...]`).
+/// 3. Call the superclass' `dealloc` with `[super dealloc]`.
+///
+/// Overriding `dealloc` manually with `#[method(dealloc)]` is not allowed,
+/// since it is very easy to get wrong; implement [`Drop`] instead.
+///
+/// The macro does not support generic types, nor root classes (classes
+/// without a superclass); use [`ClassBuilder::root`] for the latter.
 ///
 /// [`extern_class!`]: crate::extern_class
 /// [`extern_methods!`]: crate::extern_methods
+/// [`ClassBuilder::root`]: crate::runtime::ClassBuilder::root
 /// [ec_spec]: crate::extern_class#specification
 /// [`DefinedClass`]: crate::DefinedClass
 ///
@@ -1237,6 +1267,74 @@ macro_rules! __define_class_rewrite_params {
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __define_class_method_out_inner {
+    // +load, with any signature
+    {
+        ($($qualifiers:tt)*)
+        ($name:ident)
+        ($($__ret:ty)?)
+        ($__body:block)
+
+        (add_class_method)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($__params_prefix:tt)*)
+
+        (#[$__method_attr:ident(load)])
+        ($($__retain_semantics:tt)*)
+        ($($__m_optional:tt)*)
+        ($($m_checked:tt)*)
+
+        ($($__params_converted:tt)*)
+        ($($__body_prefix:tt)*)
+    } => {
+        $($m_checked)*
+        $($qualifiers)* extern "C-unwind" fn $name(
+            _: &$crate::runtime::AnyClass,
+            _: $crate::runtime::Sel,
+        ) {
+            $crate::__macro_helpers::compile_error!($crate::__macro_helpers::concat!(
+                "`+load` is not supported, since the class is registered lazily ",
+                "after the runtime has loaded the binary. ",
+                "Use `#[method(initialize)]` instead",
+            ))
+        }
+    };
+
+    // +initialize
+    {
+        ($($qualifiers:tt)*)
+        ($name:ident)
+        ()
+        ($body:block)
+
+        (add_class_method)
+        ($__receiver:expr)
+        ($__receiver_ty:ty)
+        ($($__params_prefix:tt)*)
+
+        (#[method(initialize)])
+        ()
+        ($($__m_optional:tt)*)
+        ($($m_checked:tt)*)
+
+        ()
+        ()
+    } => {
+        $($m_checked)*
+        #[allow(clippy::diverging_sub_expression)]
+        $($qualifiers)* extern "C-unwind" fn $name(
+            __objc2_cls: &$crate::runtime::AnyClass,
+            _: $crate::runtime::Sel,
+        ) {
+            // `+initialize` is inherited, so the runtime also sends it to
+            // subclasses that don't implement it themselves. Only run the
+            // body when initializing this exact class.
+            if __objc2_cls == <Self as $crate::ClassType>::class() {
+                $body
+            }
+        }
+    };
+
     // #[method(...)]
     {
         ($($qualifiers:tt)*)
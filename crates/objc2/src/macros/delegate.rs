@@ -0,0 +1,165 @@
+/// Create a delegate object whose methods are implemented by closures.
+///
+/// Many Objective-C APIs take a delegate that implements a few methods of a
+/// protocol. For simple cases, defining a whole class with
+/// [`define_class!`] just to forward those methods to your code is quite
+/// verbose; this macro instead creates the object inline, with each method
+/// forwarding to a closure that is stored in the object.
+///
+/// The macro evaluates to a `Retained<ProtocolObject<dyn Protocol>>`, which
+/// can be passed to the `setDelegate:` method (or similar) of the object in
+/// question.
+///
+/// [`define_class!`]: crate::define_class
+///
+///
+/// # Specification
+///
+/// The macro takes the following parts:
+/// - A `#[name = "..."]` attribute, which is used as the name of the
+///   underlying class. This must be unique across the entire application,
+///   see [the docs on `define_class!`][define_class_name].
+/// - An `unsafe impl Protocol { ... }` block, where `Protocol` is a protocol
+///   declared with [`extern_protocol!`] (and which must be in scope).
+/// - Inside that, any number of method definitions of the form
+///   `#[method(selector:)] fn name(param: Type, ...) -> Ret = closure;`.
+///   `#[method_id(...)]` may be used in the same way as in
+///   [`define_class!`].
+///
+/// The parameters and return type are specified the same way as for methods
+/// in `define_class!`, except that there is no `&self` parameter; the
+/// closure is called with the remaining parameters. The closure must be
+/// `'static`, so you will usually want to use a `move` closure.
+///
+/// The underlying class is a subclass of [`NSObject`], which is defined the
+/// first time the macro invocation is evaluated; evaluating the same
+/// invocation again (e.g. in a loop) creates a new object of the same class,
+/// with the new closures.
+///
+/// [define_class_name]: crate::define_class#name--required
+/// [`extern_protocol!`]: crate::extern_protocol
+/// [`NSObject`]: crate::runtime::NSObject
+///
+///
+/// # Safety
+///
+/// The same rules as for protocol implementations in [`define_class!`]
+/// apply; that is, the selectors and types must match what the protocol
+/// expects, and any required methods must be implemented.
+///
+/// Additionally, the closures are called on whichever thread the framework
+/// sends the delegate messages on. If that is not the thread the delegate
+/// was created on, you must make sure that the closures can soundly be
+/// called from there.
+///
+///
+/// # Examples
+///
+/// Create a delegate that is notified when something finishes.
+///
+/// ```
+/// use std::cell::Cell;
+/// use std::rc::Rc;
+///
+/// use objc2::rc::Retained;
+/// use objc2::runtime::{NSObjectProtocol, ProtocolObject};
+/// use objc2::{delegate, extern_protocol, msg_send, ProtocolType};
+///
+/// extern_protocol!(
+///     unsafe trait MyTaskDelegate: NSObjectProtocol {
+///         #[optional]
+///         #[method(taskDidFinishWithCount:)]
+///         fn taskDidFinishWithCount(&self, count: usize);
+///     }
+///
+///     unsafe impl ProtocolType for dyn MyTaskDelegate {}
+/// );
+///
+/// let finished = Rc::new(Cell::new(0));
+///
+/// let delegate: Retained<ProtocolObject<dyn MyTaskDelegate>> = delegate!(
+///     #[name = "MyTaskDelegateImpl"]
+///     // SAFETY: The signature is correct, and the delegate is only used
+///     // on the current thread.
+///     unsafe impl MyTaskDelegate {
+///         #[method(taskDidFinishWithCount:)]
+///         fn taskDidFinishWithCount(count: usize) = {
+///             let finished = finished.clone();
+///             move |count| finished.set(finished.get() + count)
+///         };
+///     }
+/// );
+///
+/// // Usually, the framework would send this message.
+/// let _: () = unsafe { msg_send![&delegate, taskDidFinishWithCount: 3usize] };
+/// assert_eq!(finished.get(), 3);
+/// ```
+#[macro_export]
+macro_rules! delegate {
+    (
+        #[name = $name:literal]
+        unsafe impl $protocol:ident {
+            $(
+                #[$method_kind:ident($($sel:tt)*)]
+                fn $fn_name:ident($($param:ident : $param_ty:ty),* $(,)?) $(-> $ret:ty)?
+                    = $closure:expr;
+            )*
+        }
+    ) => {{
+        use $crate::runtime::NSObjectProtocol as __Objc2NSObjectProtocol;
+
+        #[allow(non_snake_case)]
+        struct __Objc2DelegateClosures {
+            $(
+                $fn_name: $crate::__macro_helpers::Box<
+                    dyn $crate::__macro_helpers::ops::Fn($($param_ty),*) $(-> $ret)?
+                >,
+            )*
+        }
+
+        $crate::define_class!(
+            // SAFETY: `NSObject` does not have any subclassing requirements,
+            // and the class does not implement `Drop`.
+            #[unsafe(super($crate::runtime::NSObject))]
+            #[name = $name]
+            #[ivars = __Objc2DelegateClosures]
+            struct __Objc2Delegate;
+
+            unsafe impl __Objc2NSObjectProtocol for __Objc2Delegate {}
+
+            // SAFETY: Upheld by the caller.
+            unsafe impl $protocol for __Objc2Delegate {
+                $(
+                    #[$method_kind($($sel)*)]
+                    #[allow(non_snake_case)]
+                    fn $fn_name(&self, $($param : $param_ty),*) $(-> $ret)? {
+                        ($crate::DefinedClass::ivars(self).$fn_name)($($param),*)
+                    }
+                )*
+            }
+        );
+
+        let __objc2_closures = __Objc2DelegateClosures {
+            $(
+                $fn_name: {
+                    // Helper for inferring the closure's signature.
+                    #[allow(non_snake_case)]
+                    fn $fn_name<F>(f: F) -> $crate::__macro_helpers::Box<
+                        dyn $crate::__macro_helpers::ops::Fn($($param_ty),*) $(-> $ret)?
+                    >
+                    where
+                        F: $crate::__macro_helpers::ops::Fn($($param_ty),*) $(-> $ret)? + 'static,
+                    {
+                        $crate::__macro_helpers::Box::new(f)
+                    }
+                    $fn_name($closure)
+                },
+            )*
+        };
+        let __objc2_this = <__Objc2Delegate as $crate::AllocAnyThread>::alloc()
+            .set_ivars(__objc2_closures);
+        let __objc2_this: $crate::rc::Retained<__Objc2Delegate> =
+            unsafe { $crate::msg_send_id![super(__objc2_this), init] };
+        $crate::runtime::ProtocolObject::<dyn $protocol>::from_retained(__objc2_this)
+    }};
+}
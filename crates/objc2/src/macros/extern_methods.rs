@@ -26,6 +26,14 @@
 /// [`Result`]. See the error section in [`msg_send!`] and [`msg_send_id!`]
 /// for details.
 ///
+/// Methods with the `#[method_id(...)]` attribute that are documented to
+/// return `nil` on failure (commonly initializers like `initWithContentsOfURL:`)
+/// can be declared to return `Result<Retained<Self>, NewObjectError>`
+/// instead of `Option<Retained<Self>>`. The [`NewObjectError`] then contains
+/// the class and the selector, which makes for better error messages than an
+/// `unwrap` on the caller's side. By convention, such methods are prefixed
+/// with `try_`.
+///
 /// If you use `objc2::MainThreadMarker` as a parameter type, the macro will
 /// ignore it, allowing you to neatly specify "this method must be run on the
 /// main thread". Note that due to type-system limitations, this is currently
@@ -46,6 +54,7 @@
 ///
 /// ["associated functions"]: https://doc.rust-lang.org/reference/items/associated-items.html#methods
 /// ["methods"]: https://doc.rust-lang.org/reference/items/associated-items.html#methods
+/// [`NewObjectError`]: crate::rc::NewObjectError
 /// [open an issue]: https://github.com/madsmtm/objc2/issues/new
 ///
 ///
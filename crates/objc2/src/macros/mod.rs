@@ -4,11 +4,13 @@ mod __method_msg_send;
 mod __msg_send_parse;
 mod __rewrite_self_param;
 mod available;
+mod delegate;
 mod define_class;
 mod extern_category;
 mod extern_class;
 mod extern_methods;
 mod extern_protocol;
+mod ns_enum;
 
 /// Gets a reference to an [`AnyClass`] from the given name.
 ///
@@ -35,6 +37,10 @@ mod extern_protocol;
 /// will emit special statics that will be replaced by dyld when the program
 /// starts up.
 ///
+/// This also applies to [`ClassType::class`] for classes declared with
+/// [`extern_class!`], so e.g. constructing an `NSNumber` does not need to
+/// look up or load the cached class first.
+///
 /// Errors that were previously runtime panics may now turn into linker errors
 /// if you try to use a class which is not available. Additionally, you may
 /// have to call `msg_send![cls, class]` on the result if you want to use it
@@ -45,6 +51,8 @@ mod extern_protocol;
 /// `"unstable-static-class-inlined"` corresponds to the
 /// `"unstable-static-sel-inlined"` feature here.
 ///
+/// [`ClassType::class`]: crate::ClassType::class
+/// [`extern_class!`]: crate::extern_class
 /// [sel#features]: crate::sel#features
 /// [`sel!`]: crate::sel
 ///
@@ -742,6 +750,15 @@ macro_rules! __class_inner {
 /// object. Additionally, it can even be a reference to an [`rc::Retained`]
 /// containing an object.
 ///
+/// The receiver can also be an optional reference, that is `Option<&T>` or
+/// `Option<&Retained<T>>`. If it is `None`, the message is not sent, and
+/// the zero value of the return type is returned instead, mirroring
+/// Objective-C's nil-messaging rules: `()`, `0`, `false`, a null pointer or
+/// `None`. Return types that are not valid when zeroed, such as `&T` or
+/// `NonNull<T>`, are not allowed with optional receivers. This avoids having
+/// to unwrap or match on the receiver first, when the method would simply be
+/// skipped anyway.
+///
 /// The expression can be wrapped in `super`, with an optional superclass
 /// as the second argument. If no specific superclass is specified, the
 /// direct superclass is retrieved from [`ClassType`].
@@ -869,6 +886,7 @@ macro_rules! __class_inner {
 ///    are allowed (though heavily discouraged), but _only_ if the return type
 ///    itself is a pointer.
 ///
+///
 /// 6. The method must not (yet) throw an exception.
 ///
 /// 7. You must uphold any additional safety requirements (explicit and
@@ -1146,6 +1164,15 @@ macro_rules! msg_send_bool {
 /// `Retained<T>`, this macro will automatically unwrap the object, or panic
 /// with an error message if it couldn't be retrieved.
 ///
+/// If the return type is `Result<Retained<T>, NewObjectError>`, a
+/// [`NewObjectError`] containing the class `T` and the selector is returned
+/// instead of panicking. This is useful for methods that are documented to return
+/// `nil` on failure.
+///
+/// Optional receivers (`Option<&T>` and `Option<&Retained<T>>`) are also
+/// supported for the `new`, `copy`, `mutableCopy` and no families. If the
+/// receiver is `None`, no message is sent and `None` is returned.
+///
 /// As a special case, if the last argument is the marker `_`, the macro will
 /// return a `Result<Retained<T>, Retained<E>>`, see below.
 ///
@@ -1156,6 +1183,7 @@ macro_rules! msg_send_bool {
 /// [sel-families]: https://clang.llvm.org/docs/AutomaticReferenceCounting.html#arc-method-families
 /// [`MessageReceiver`]: crate::runtime::MessageReceiver
 /// [`Retained::retain_autoreleased`]: crate::rc::Retained::retain_autoreleased
+/// [`NewObjectError`]: crate::rc::NewObjectError
 /// [arc-retainable]: https://clang.llvm.org/docs/AutomaticReferenceCounting.html#retainable-object-pointers-as-operands-and-arguments
 /// [`Retained::retain`]: crate::rc::Retained::retain
 /// [`Retained::drop`]: crate::rc::Retained::drop
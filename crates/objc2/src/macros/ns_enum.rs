@@ -0,0 +1,377 @@
+/// Declare a type matching an `NS_ENUM` from Objective-C.
+///
+/// This creates a `#[repr(transparent)]` newtype over the underlying integer
+/// type, with an associated constant for each case, and implements
+/// [`Encode`] and [`RefEncode`] for it with the encoding of the integer
+/// type, so that it can be used directly in [`msg_send!`] and
+/// [`extern_methods!`].
+///
+/// A newtype is used instead of a Rust `enum`, since Apple may add cases in
+/// newer OS versions, and the OS may thus return values that your code does
+/// not yet know about. Match on the constants, and include a fallback arm
+/// for unknown values.
+///
+/// The [`Debug`] implementation prints the name of the case, or the raw
+/// value if it is not known.
+///
+/// [`Encode`]: crate::Encode
+/// [`RefEncode`]: crate::RefEncode
+/// [`msg_send!`]: crate::msg_send
+/// [`extern_methods!`]: crate::extern_methods
+/// [`Debug`]: core::fmt::Debug
+///
+///
+/// # Examples
+///
+/// Declare an enum with the following Objective-C definition:
+///
+/// ```objc
+/// typedef NS_ENUM(NSInteger, MyDirection) {
+///     MyDirectionUp = 0,
+///     MyDirectionDown = 1,
+/// };
+/// ```
+///
+/// ```
+/// use objc2::ffi::NSInteger;
+/// use objc2::{ns_enum, Encode};
+///
+/// ns_enum! {
+///     /// The direction of something.
+///     pub struct MyDirection: NSInteger {
+///         #[doc(alias = "MyDirectionUp")]
+///         const Up = 0;
+///         #[doc(alias = "MyDirectionDown")]
+///         const Down = 1;
+///     }
+/// }
+///
+/// assert_eq!(MyDirection::ENCODING, NSInteger::ENCODING);
+///
+/// // A value returned from a newer OS version.
+/// let direction = MyDirection(2);
+/// let name = match direction {
+///     MyDirection::Up => "up",
+///     MyDirection::Down => "down",
+///     _ => "unknown",
+/// };
+/// assert_eq!(name, "unknown");
+/// assert_eq!(format!("{:?}", MyDirection::Up), "MyDirection::Up");
+/// assert_eq!(format!("{direction:?}"), "MyDirection(2)");
+/// ```
+#[macro_export]
+macro_rules! ns_enum {
+    (
+        $(#[$m:meta])*
+        $v:vis struct $name:ident: $ty:ty {
+            $(
+                $(#[$case_m:meta])*
+                const $case:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$m])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        $v struct $name(pub $ty);
+
+        impl $name {
+            $(
+                $(#[$case_m])*
+                pub const $case: Self = Self($value);
+            )*
+        }
+
+        // SAFETY: The type is a `#[repr(transparent)]` newtype over `$ty`.
+        unsafe impl $crate::Encode for $name {
+            const ENCODING: $crate::Encoding = <$ty as $crate::Encode>::ENCODING;
+        }
+
+        // SAFETY: Same as above.
+        unsafe impl $crate::RefEncode for $name {
+            const ENCODING_REF: $crate::Encoding = <$ty as $crate::RefEncode>::ENCODING_REF;
+        }
+
+        impl $crate::__macro_helpers::fmt::Debug for $name {
+            fn fmt(
+                &self,
+                f: &mut $crate::__macro_helpers::fmt::Formatter<'_>,
+            ) -> $crate::__macro_helpers::fmt::Result {
+                $(
+                    if *self == Self::$case {
+                        return $crate::__macro_helpers::fmt::Formatter::write_str(
+                            f,
+                            $crate::__macro_helpers::concat!(
+                                $crate::__macro_helpers::stringify!($name),
+                                "::",
+                                $crate::__macro_helpers::stringify!($case),
+                            ),
+                        );
+                    }
+                )*
+                f.debug_tuple($crate::__macro_helpers::stringify!($name))
+                    .field(&self.0)
+                    .finish()
+            }
+        }
+    };
+}
+
+/// Declare a type matching an `NS_OPTIONS` from Objective-C.
+///
+/// This creates a `#[repr(transparent)]` newtype over the underlying integer
+/// type, with an associated constant for each flag, and implements
+/// [`Encode`] and [`RefEncode`] for it with the encoding of the integer
+/// type, so that it can be used directly in [`msg_send!`] and
+/// [`extern_methods!`].
+///
+/// The type supports the usual bitwise operators (`|`, `&`, `^`, `!` and
+/// `-`), along with methods similar to those generated by the [`bitflags`]
+/// crate. Bits that are not part of any known flag are always preserved, as
+/// the OS may set flags that were added in a newer version.
+///
+/// [`Encode`]: crate::Encode
+/// [`RefEncode`]: crate::RefEncode
+/// [`msg_send!`]: crate::msg_send
+/// [`extern_methods!`]: crate::extern_methods
+/// [`bitflags`]: https://docs.rs/bitflags
+///
+///
+/// # Examples
+///
+/// Declare an option set with the following Objective-C definition:
+///
+/// ```objc
+/// typedef NS_OPTIONS(NSUInteger, MyEdges) {
+///     MyEdgesTop = 1 << 0,
+///     MyEdgesBottom = 1 << 1,
+/// };
+/// ```
+///
+/// ```
+/// use objc2::ffi::NSUInteger;
+/// use objc2::{ns_options, Encode};
+///
+/// ns_options! {
+///     /// A set of edges.
+///     pub struct MyEdges: NSUInteger {
+///         #[doc(alias = "MyEdgesTop")]
+///         const Top = 1 << 0;
+///         #[doc(alias = "MyEdgesBottom")]
+///         const Bottom = 1 << 1;
+///     }
+/// }
+///
+/// assert_eq!(MyEdges::ENCODING, NSUInteger::ENCODING);
+///
+/// let edges = MyEdges::Top | MyEdges::Bottom;
+/// assert!(edges.contains(MyEdges::Top));
+/// assert_eq!(edges - MyEdges::Top, MyEdges::Bottom);
+/// assert_eq!(format!("{edges:?}"), "MyEdges(Top | Bottom)");
+///
+/// // Unknown flags are preserved.
+/// let edges = MyEdges::from_bits_retain(0b101);
+/// assert_eq!(edges & MyEdges::Top, MyEdges::Top);
+/// assert_eq!(edges.bits(), 0b101);
+/// assert_eq!(format!("{edges:?}"), "MyEdges(Top | 0x4)");
+/// ```
+#[macro_export]
+macro_rules! ns_options {
+    (
+        $(#[$m:meta])*
+        $v:vis struct $name:ident: $ty:ty {
+            $(
+                $(#[$flag_m:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$m])*
+        #[repr(transparent)]
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        $v struct $name(pub $ty);
+
+        impl $name {
+            $(
+                $(#[$flag_m])*
+                pub const $flag: Self = Self($value);
+            )*
+
+            /// An option set with no flags set.
+            #[inline]
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// An option set with all the known flags set.
+            #[inline]
+            pub const fn all() -> Self {
+                Self(0 $(| $value)*)
+            }
+
+            /// The raw value of the option set.
+            #[inline]
+            pub const fn bits(&self) -> $ty {
+                self.0
+            }
+
+            /// Create an option set from the raw value, retaining any unknown
+            /// bits.
+            #[inline]
+            pub const fn from_bits_retain(bits: $ty) -> Self {
+                Self(bits)
+            }
+
+            /// Whether no flags are set.
+            #[inline]
+            pub const fn is_empty(&self) -> $crate::__macro_helpers::bool {
+                self.0 == 0
+            }
+
+            /// Whether all the flags in `other` are set in `self`.
+            #[inline]
+            pub const fn contains(&self, other: Self) -> $crate::__macro_helpers::bool {
+                self.0 & other.0 == other.0
+            }
+
+            /// Whether any of the flags in `other` are set in `self`.
+            #[inline]
+            pub const fn intersects(&self, other: Self) -> $crate::__macro_helpers::bool {
+                self.0 & other.0 != 0
+            }
+
+            /// Set the flags in `other`.
+            #[inline]
+            pub fn insert(&mut self, other: Self) {
+                self.0 |= other.0;
+            }
+
+            /// Clear the flags in `other`.
+            #[inline]
+            pub fn remove(&mut self, other: Self) {
+                self.0 &= !other.0;
+            }
+
+            /// Set or clear the flags in `other`, depending on `value`.
+            #[inline]
+            pub fn set(&mut self, other: Self, value: $crate::__macro_helpers::bool) {
+                if value {
+                    self.insert(other);
+                } else {
+                    self.remove(other);
+                }
+            }
+        }
+
+        // SAFETY: The type is a `#[repr(transparent)]` newtype over `$ty`.
+        unsafe impl $crate::Encode for $name {
+            const ENCODING: $crate::Encoding = <$ty as $crate::Encode>::ENCODING;
+        }
+
+        // SAFETY: Same as above.
+        unsafe impl $crate::RefEncode for $name {
+            const ENCODING_REF: $crate::Encoding = <$ty as $crate::RefEncode>::ENCODING_REF;
+        }
+
+        impl $crate::__macro_helpers::Default for $name {
+            #[inline]
+            fn default() -> Self {
+                Self::empty()
+            }
+        }
+
+        $crate::__ns_options_op!($name, BitOr, bitor, BitOrAssign, bitor_assign, |);
+        $crate::__ns_options_op!($name, BitAnd, bitand, BitAndAssign, bitand_assign, &);
+        $crate::__ns_options_op!($name, BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+        impl $crate::__macro_helpers::ops::Sub for $name {
+            type Output = Self;
+
+            /// The flags in `self` that are not in `rhs`.
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Self(self.0 & !rhs.0)
+            }
+        }
+
+        impl $crate::__macro_helpers::ops::SubAssign for $name {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.remove(rhs);
+            }
+        }
+
+        impl $crate::__macro_helpers::ops::Not for $name {
+            type Output = Self;
+
+            /// Flip all bits, including unknown ones.
+            #[inline]
+            fn not(self) -> Self {
+                Self(!self.0)
+            }
+        }
+
+        impl $crate::__macro_helpers::fmt::Debug for $name {
+            fn fmt(
+                &self,
+                f: &mut $crate::__macro_helpers::fmt::Formatter<'_>,
+            ) -> $crate::__macro_helpers::fmt::Result {
+                f.write_str($crate::__macro_helpers::stringify!($name))?;
+                f.write_str("(")?;
+                let mut remaining = self.0;
+                let mut first = true;
+                $(
+                    let flag = Self::$flag.0;
+                    if flag != 0 && self.0 & flag == flag && remaining & flag != 0 {
+                        if !first {
+                            f.write_str(" | ")?;
+                        }
+                        first = false;
+                        f.write_str($crate::__macro_helpers::stringify!($flag))?;
+                        remaining &= !flag;
+                    }
+                )*
+                if remaining != 0 {
+                    if !first {
+                        f.write_str(" | ")?;
+                    }
+                    first = false;
+                    $crate::__macro_helpers::write!(f, "{:#x}", remaining)?;
+                }
+                if first {
+                    f.write_str("empty")?;
+                }
+                f.write_str(")")
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ns_options_op {
+    (
+        $name:ident,
+        $trait:ident,
+        $method:ident,
+        $assign_trait:ident,
+        $assign_method:ident,
+        $op:tt
+    ) => {
+        impl $crate::__macro_helpers::ops::$trait for $name {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                Self(self.0 $op rhs.0)
+            }
+        }
+
+        impl $crate::__macro_helpers::ops::$assign_trait for $name {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Self) {
+                *self = Self(self.0 $op rhs.0);
+            }
+        }
+    };
+}
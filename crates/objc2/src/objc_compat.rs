@@ -0,0 +1,173 @@
+//! # Interoperability with the `objc` and `objc_id` crates.
+//!
+//! The types in the older [`objc`] and [`objc_id`] crates are just different
+//! wrappers around the same pointers as the types in this crate, so they can
+//! be converted back and forth freely. This allows migrating a large codebase
+//! to `objc2` one crate at a time.
+//!
+//! | `objc` / `objc_id`         | `objc2`                 |
+//! | -------------------------- | ----------------------- |
+//! | `objc::runtime::Object`    | [`AnyObject`]           |
+//! | `objc::runtime::Class`     | [`AnyClass`]            |
+//! | `objc::runtime::Sel`       | [`Sel`]                 |
+//! | `objc_id::ShareId<Object>` | [`Retained<AnyObject>`] |
+//! | `objc_id::Id<Object>`      | [`Retained<AnyObject>`] |
+//!
+//! This module is only available when the `"objc-compat"` feature is
+//! enabled. Note that `objc_id::Id<Object, Owned>` can only be converted
+//! into [`Retained`], since a [`Retained`] does not guarantee that it is the
+//! only reference to the object.
+//!
+//! [`objc`]: https://docs.rs/objc/0.2
+//! [`objc_id`]: https://docs.rs/objc_id/0.1
+//!
+//!
+//! ## Example
+//!
+//! ```
+//! use objc2::rc::Retained;
+//! use objc2::runtime::{AnyObject, NSObject};
+//!
+//! use objc::runtime::Object;
+//! use objc_id::ShareId;
+//!
+//! // Code that still uses the `objc_id` crate.
+//! fn legacy(obj: ShareId<Object>) -> ShareId<Object> {
+//!     obj
+//! }
+//!
+//! let obj: Retained<AnyObject> = NSObject::new().into_super();
+//! let obj: Retained<AnyObject> = legacy(obj.into()).into();
+//! ```
+use core::mem::ManuallyDrop;
+
+use objc::runtime::{Class, Object};
+use objc_id::{Id, Ownership, ShareId};
+
+use crate::rc::Retained;
+use crate::runtime::{AnyClass, AnyObject, Sel};
+
+impl AsRef<Object> for AnyObject {
+    #[inline]
+    fn as_ref(&self) -> &Object {
+        let ptr: *const Self = self;
+        // SAFETY: Both types represent an Objective-C object.
+        unsafe { &*ptr.cast::<Object>() }
+    }
+}
+
+impl AsRef<AnyObject> for Object {
+    #[inline]
+    fn as_ref(&self) -> &AnyObject {
+        let ptr: *const Self = self;
+        // SAFETY: Both types represent an Objective-C object.
+        unsafe { &*ptr.cast::<AnyObject>() }
+    }
+}
+
+impl AsRef<Class> for AnyClass {
+    #[inline]
+    fn as_ref(&self) -> &Class {
+        let ptr: *const Self = self;
+        // SAFETY: Both types represent an Objective-C class.
+        unsafe { &*ptr.cast::<Class>() }
+    }
+}
+
+impl AsRef<AnyClass> for Class {
+    #[inline]
+    fn as_ref(&self) -> &AnyClass {
+        let ptr: *const Self = self;
+        // SAFETY: Both types represent an Objective-C class.
+        unsafe { &*ptr.cast::<AnyClass>() }
+    }
+}
+
+impl From<Sel> for objc::runtime::Sel {
+    #[inline]
+    fn from(sel: Sel) -> Self {
+        // SAFETY: The pointer is a valid, registered selector.
+        unsafe { Self::from_ptr(sel.as_ptr()) }
+    }
+}
+
+impl From<objc::runtime::Sel> for Sel {
+    #[inline]
+    fn from(sel: objc::runtime::Sel) -> Self {
+        // SAFETY: The pointer is a valid, registered selector.
+        unsafe { Self::from_ptr(sel.as_ptr()) }.expect("selector must not be NULL")
+    }
+}
+
+impl<O: Ownership> From<Id<Object, O>> for Retained<AnyObject> {
+    /// Take over the `+1` retain count of the `objc_id` pointer.
+    #[inline]
+    fn from(obj: Id<Object, O>) -> Self {
+        let obj = ManuallyDrop::new(obj);
+        let ptr: *const Object = &**obj;
+        // SAFETY: The `Id` held a +1 retain count, which we take over by
+        // not running its destructor.
+        unsafe { Retained::from_raw(ptr as *mut AnyObject) }.expect("Id is never NULL")
+    }
+}
+
+impl From<Retained<AnyObject>> for ShareId<Object> {
+    /// Transfer the `+1` retain count to a shared `objc_id` pointer.
+    ///
+    /// Use [`Retained::cast_unchecked`] or [`Retained::into_super`] first to
+    /// convert other objects to [`AnyObject`].
+    #[inline]
+    fn from(obj: Retained<AnyObject>) -> Self {
+        let ptr = Retained::into_raw(obj);
+        // SAFETY: The pointer is valid and has a +1 retain count, which we
+        // pass on to the `ShareId`.
+        unsafe { ShareId::from_retained_ptr(ptr.cast::<Object>()) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runtime::NSObject;
+    use crate::{sel, ClassType};
+
+    #[test]
+    fn roundtrip_object() {
+        let obj = NSObject::new();
+        let expected_count = obj.retainCount();
+        let obj: Retained<AnyObject> = obj.into_super();
+        let ptr = Retained::as_ptr(&obj);
+
+        let legacy: ShareId<Object> = obj.into();
+        let legacy_ptr: *const Object = &*legacy;
+        assert_eq!(legacy_ptr.cast::<AnyObject>(), ptr);
+
+        let obj: Retained<AnyObject> = legacy.into();
+        assert_eq!(Retained::as_ptr(&obj), ptr);
+        // SAFETY: The object is an `NSObject`.
+        let obj = unsafe { Retained::cast_unchecked::<NSObject>(obj) };
+        assert_eq!(obj.retainCount(), expected_count);
+    }
+
+    #[test]
+    fn roundtrip_references() {
+        let obj = NSObject::new();
+        let obj: &AnyObject = &obj;
+        let legacy: &Object = obj.as_ref();
+        let roundtripped: &AnyObject = legacy.as_ref();
+        assert_eq!(roundtripped as *const AnyObject, obj as *const AnyObject);
+
+        let cls = NSObject::class();
+        let legacy: &Class = cls.as_ref();
+        assert_eq!(legacy.name(), "NSObject");
+        let roundtripped: &AnyClass = legacy.as_ref();
+        assert_eq!(roundtripped, cls);
+    }
+
+    #[test]
+    fn roundtrip_sel() {
+        let legacy: objc::runtime::Sel = sel!(description).into();
+        assert_eq!(legacy.name(), "description");
+        assert_eq!(Sel::from(legacy), sel!(description));
+    }
+}
@@ -198,7 +198,7 @@ impl<T: ?Sized> Drop for Allocated<T> {
         // since `objc_release` allows NULL pointers.
         //
         // Rest is same as `Retained`'s `Drop`.
-        unsafe { objc_release_fast(self.ptr as *mut _) };
+        unsafe { objc_release_fast(self.ptr.cast_mut().cast()) };
     }
 }
 
@@ -300,7 +300,7 @@ impl<T: ?Sized> Drop for PartialInit<T> {
         // since `objc_release` allows NULL pointers.
         //
         // Rest is same as `Retained`.
-        unsafe { objc_release_fast(self.ptr as *mut _) };
+        unsafe { objc_release_fast(self.ptr.cast_mut().cast()) };
     }
 }
 
@@ -0,0 +1,323 @@
+//! Accounting of the retains, releases and autoreleases done by this crate.
+use alloc::collections::BTreeMap;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use std::backtrace::Backtrace;
+use std::sync::Mutex;
+
+use crate::runtime::{AnyClass, AnyObject};
+
+/// The kind of reference counting operation that was performed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RetainEventKind {
+    /// The object was retained (`objc_retain`).
+    Retain,
+    /// The object was released (`objc_release`).
+    Release,
+    /// The object was autoreleased (`objc_autorelease`), and will be
+    /// released when the autorelease pool is drained.
+    Autorelease,
+}
+
+/// A single recorded reference counting operation.
+#[derive(Clone, Debug)]
+pub struct RetainEvent {
+    kind: RetainEventKind,
+    backtrace: Arc<Backtrace>,
+}
+
+impl RetainEvent {
+    /// The kind of operation.
+    pub fn kind(&self) -> RetainEventKind {
+        self.kind
+    }
+
+    /// The backtrace of where the operation was performed.
+    ///
+    /// This is captured with [`Backtrace::capture`], and is thus only
+    /// available if the `RUST_BACKTRACE` or `RUST_LIB_BACKTRACE` environment
+    /// variables are set.
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+}
+
+/// The maximum number of operations whose backtraces are kept per object.
+const MAX_EVENTS: usize = 32;
+
+/// The operations recorded for a single object address.
+#[derive(Clone, Debug)]
+pub struct ObjectRetains {
+    address: usize,
+    cls: &'static AnyClass,
+    retains: usize,
+    releases: usize,
+    autoreleases: usize,
+    events: Vec<RetainEvent>,
+}
+
+impl ObjectRetains {
+    /// The address of the object.
+    pub fn address(&self) -> usize {
+        self.address
+    }
+
+    /// The class of the object when it was first recorded.
+    pub fn class(&self) -> &'static AnyClass {
+        self.cls
+    }
+
+    /// The most recent operations, in the order they were performed.
+    ///
+    /// Only the last 32 operations are kept, while [`retains`],
+    /// [`releases`] and [`autoreleases`] count every operation.
+    ///
+    /// [`retains`]: Self::retains
+    /// [`releases`]: Self::releases
+    /// [`autoreleases`]: Self::autoreleases
+    pub fn events(&self) -> &[RetainEvent] {
+        &self.events
+    }
+
+    /// The number of retains.
+    pub fn retains(&self) -> usize {
+        self.retains
+    }
+
+    /// The number of releases.
+    pub fn releases(&self) -> usize {
+        self.releases
+    }
+
+    /// The number of autoreleases.
+    pub fn autoreleases(&self) -> usize {
+        self.autoreleases
+    }
+
+    /// The number of retains minus the number of releases and autoreleases.
+    ///
+    /// A negative balance means that this crate released the object more
+    /// times than it retained it, which is expected for objects that were
+    /// created with an initial retain count of one.
+    pub fn balance(&self) -> isize {
+        self.retains as isize - self.releases as isize - self.autoreleases as isize
+    }
+}
+
+/// A snapshot of the reference counting operations done by this crate.
+///
+/// Created with [`retain_report`].
+///
+/// The [`Display`][fmt::Display] implementation prints the counts for each
+/// object, along with the backtraces if they were captured.
+#[derive(Clone, Debug)]
+pub struct RetainReport {
+    objects: Vec<ObjectRetains>,
+}
+
+impl RetainReport {
+    /// The objects that have been recorded, ordered by address.
+    ///
+    /// This includes objects whose operations balance out, filter on
+    /// [`ObjectRetains::balance`] to find the ones that don't.
+    pub fn objects(&self) -> &[ObjectRetains] {
+        &self.objects
+    }
+
+    /// The operations recorded for the given object, if any.
+    pub fn object(&self, obj: &AnyObject) -> Option<&ObjectRetains> {
+        let address = address(obj);
+        self.objects
+            .binary_search_by_key(&address, |object| object.address)
+            .ok()
+            .map(|i| &self.objects[i])
+    }
+}
+
+impl fmt::Display for RetainReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for object in &self.objects {
+            writeln!(
+                f,
+                "<{}: {:#x}>: {} retains, {} releases, {} autoreleases",
+                object.cls,
+                object.address,
+                object.retains(),
+                object.releases(),
+                object.autoreleases(),
+            )?;
+            for event in &object.events {
+                writeln!(f, "    {:?}", event.kind)?;
+                if let std::backtrace::BacktraceStatus::Captured = event.backtrace.status() {
+                    writeln!(f, "{}", event.backtrace)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+static RECORDS: Mutex<BTreeMap<usize, ObjectRetains>> = Mutex::new(BTreeMap::new());
+
+fn address(obj: *const AnyObject) -> usize {
+    // Only used as a key, never converted back to a pointer.
+    obj as usize
+}
+
+/// Record an operation on the object, if it is non-NULL.
+///
+///
+/// # Safety
+///
+/// The object must be NULL or valid.
+pub(crate) unsafe fn record(obj: *mut AnyObject, kind: RetainEventKind) {
+    // SAFETY: Upheld by caller.
+    let Some(obj) = (unsafe { obj.as_ref() }) else {
+        return;
+    };
+    let event = RetainEvent {
+        kind,
+        backtrace: Arc::new(Backtrace::capture()),
+    };
+    let mut records = RECORDS.lock().unwrap_or_else(|err| err.into_inner());
+    let record = records
+        .entry(address(obj))
+        .or_insert_with(|| ObjectRetains {
+            address: address(obj),
+            cls: obj.class(),
+            retains: 0,
+            releases: 0,
+            autoreleases: 0,
+            events: Vec::new(),
+        });
+    match kind {
+        RetainEventKind::Retain => record.retains += 1,
+        RetainEventKind::Release => record.releases += 1,
+        RetainEventKind::Autorelease => record.autoreleases += 1,
+    }
+
+    // Only keep the most recent backtraces, so that frequently retained
+    // objects don't make the records grow without bounds. The number of
+    // records is bounded by the number of distinct addresses, since
+    // addresses of deallocated objects are reused.
+    if record.events.len() == MAX_EVENTS {
+        record.events.remove(0);
+    }
+    record.events.push(event);
+}
+
+/// Get a report of the retains, releases and autoreleases that this crate
+/// has performed, grouped by object.
+///
+/// Only operations done through this crate (e.g. by cloning or dropping
+/// [`Retained`]) are recorded, so the initial retain count of newly created
+/// objects, and any retains and releases done inside Objective-C, are not
+/// included. Objects that are deallocated may have their address reused, so
+/// a record may span several objects; use [`reset_retain_report`] to start
+/// over from a known point.
+///
+/// Objects whose retains are balanced by releases and autoreleases are kept
+/// in the report, use [`ObjectRetains::balance`] to find the objects that
+/// this crate currently holds extra references to, or has released more
+/// than it has retained (such as objects created with an initial retain
+/// count of one).
+///
+/// This is only available when the `"debug-retain"` feature is enabled.
+/// The most recent operations on each object are recorded with backtraces
+/// until they are reset, so this should not be used in production.
+///
+/// [`Retained`]: super::Retained
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::rc::{reset_retain_report, retain_report};
+/// use objc2::runtime::NSObject;
+///
+/// let obj = NSObject::new();
+/// reset_retain_report();
+/// let cloned = obj.clone();
+///
+/// let report = retain_report();
+/// let record = report.object(&obj).unwrap();
+/// assert_eq!(record.retains(), 1);
+/// assert_eq!(record.balance(), 1);
+/// println!("{report}");
+///
+/// // The retain is balanced by the release.
+/// drop(cloned);
+/// assert_eq!(retain_report().object(&obj).unwrap().balance(), 0);
+/// ```
+pub fn retain_report() -> RetainReport {
+    let records = RECORDS.lock().unwrap_or_else(|err| err.into_inner());
+    RetainReport {
+        objects: records.values().cloned().collect(),
+    }
+}
+
+/// Clear all recorded operations.
+///
+/// See [`retain_report`] for details.
+pub fn reset_retain_report() {
+    RECORDS
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::rc::{autoreleasepool, Retained};
+    use crate::runtime::NSObject;
+
+    #[test]
+    fn test_record() {
+        // Other tests may run concurrently, so only look at our own object.
+        // A previous object at the same address may have left a record.
+        let obj = NSObject::new();
+        let balance = || {
+            retain_report()
+                .object(&obj)
+                .map(ObjectRetains::balance)
+                .unwrap_or(0)
+        };
+        let before = balance();
+
+        let cloned = obj.clone();
+        let report = retain_report();
+        let record = report.object(&obj).unwrap();
+        assert_eq!(record.balance(), before + 1);
+        assert_eq!(
+            record.events().last().unwrap().kind(),
+            RetainEventKind::Retain
+        );
+        assert!(report.to_string().contains("retains"));
+
+        autoreleasepool(|_| {
+            let _ = Retained::autorelease_ptr(cloned);
+        });
+        assert_eq!(balance(), before);
+        let record = retain_report().object(&obj).unwrap().clone();
+        assert_eq!(
+            record.events().last().unwrap().kind(),
+            RetainEventKind::Autorelease
+        );
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let obj = NSObject::new();
+        for _ in 0..MAX_EVENTS {
+            drop(obj.clone());
+        }
+        let record = retain_report().object(&obj).unwrap().clone();
+        assert_eq!(record.events().len(), MAX_EVENTS);
+        assert!(record.retains() >= MAX_EVENTS);
+    }
+}
@@ -51,8 +51,13 @@
 
 mod allocated_partial_init;
 mod autorelease;
+#[cfg(feature = "debug-retain")]
+pub(crate) mod debug_retain;
+mod new_object_error;
+mod once;
 mod retained;
 mod retained_forwarding_impls;
+mod retained_ref;
 mod retained_traits;
 #[cfg(test)]
 mod test_object;
@@ -62,7 +67,16 @@ pub use self::allocated_partial_init::{Allocated, PartialInit};
 pub use self::autorelease::{
     autoreleasepool, autoreleasepool_leaking, AutoreleasePool, AutoreleaseSafe,
 };
+#[cfg(feature = "debug-retain")]
+pub use self::debug_retain::{
+    reset_retain_report, retain_report, ObjectRetains, RetainEvent, RetainEventKind, RetainReport,
+};
+pub use self::new_object_error::NewObjectError;
+pub use self::once::OnceObj;
 pub use self::retained::{Id, Retained};
+pub use self::retained_ref::RetainedRef;
+#[cfg(feature = "serde")]
+pub use self::retained_traits::DeserializeRetained;
 pub use self::retained_traits::{DefaultRetained, RetainedFromIterator, RetainedIntoIterator};
 #[cfg(test)]
 pub(crate) use self::test_object::{RcTestObject, ThreadTestData};
@@ -0,0 +1,95 @@
+use core::fmt;
+use std::error::Error;
+
+use crate::runtime::{AnyClass, Sel};
+
+/// An error returned when a method that creates an object returned `nil`.
+///
+/// Many initializers are documented to return `nil` on failure, for example
+/// `-[NSString initWithContentsOfFile:encoding:error:]` or
+/// `-[NSData initWithContentsOfURL:]`. Instead of returning
+/// `Option<Retained<T>>` and leaving it to the caller to [`unwrap`] it (which
+/// loses the information about which object failed to be created), methods
+/// in [`msg_send_id!`] and [`extern_methods!`] can be declared to return
+/// `Result<Retained<T>, NewObjectError>`.
+///
+/// This implements [`Error`], and includes the class and selector in its
+/// description.
+///
+/// [`unwrap`]: Option::unwrap
+/// [`msg_send_id!`]: crate::msg_send_id
+/// [`extern_methods!`]: crate::extern_methods
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::rc::{Allocated, NewObjectError, Retained};
+/// use objc2::runtime::NSObject;
+/// use objc2::{extern_methods, AllocAnyThread, ClassType};
+///
+/// extern_methods!(
+///     unsafe impl NSObject {
+///         #[method_id(init)]
+///         fn try_init(this: Allocated<Self>) -> Result<Retained<Self>, NewObjectError>;
+///     }
+/// );
+///
+/// let obj = NSObject::try_init(NSObject::alloc()).expect("failed creating object");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NewObjectError {
+    class: &'static AnyClass,
+    sel: Sel,
+}
+
+impl NewObjectError {
+    #[inline]
+    pub(crate) fn new(class: &'static AnyClass, sel: Sel) -> Self {
+        Self { class, sel }
+    }
+
+    /// The class of the object that failed to be created.
+    #[inline]
+    pub fn class(&self) -> &'static AnyClass {
+        self.class
+    }
+
+    /// The selector of the method that returned `nil`.
+    #[inline]
+    pub fn sel(&self) -> Sel {
+        self.sel
+    }
+}
+
+impl fmt::Display for NewObjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "failed creating instance of {} using {}",
+            self.class, self.sel
+        )
+    }
+}
+
+impl Error for NewObjectError {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+    use crate::runtime::NSObject;
+    use crate::{sel, ClassType};
+
+    #[test]
+    fn test_display() {
+        let err = NewObjectError::new(NSObject::class(), sel!(initWithFoo:));
+        assert_eq!(err.class(), NSObject::class());
+        assert_eq!(err.sel(), sel!(initWithFoo:));
+        assert_eq!(
+            err.to_string(),
+            "failed creating instance of NSObject using initWithFoo:"
+        );
+    }
+}
@@ -0,0 +1,154 @@
+use core::fmt;
+use std::sync::OnceLock;
+
+use super::Retained;
+use crate::Message;
+
+/// A lazily initialized, retained object, usable in a `static`.
+///
+/// This is useful for objects that are expensive to create, and that should
+/// be shared across the application, such as formatters or dispatch queues.
+/// The object is created the first time it is accessed, and never released.
+///
+/// This is a thin wrapper around `OnceLock<Retained<T>>`, that hands out
+/// references with a `'static`-compatible lifetime. Like that, it is only
+/// [`Sync`], and thus only usable in a `static`, when `T` is both [`Send`]
+/// and [`Sync`]; objects that may only be used from the main thread should
+/// instead be stored in a thread-local, or in a `MainThreadBound`.
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::rc::OnceObj;
+/// use objc2::runtime::NSObject;
+/// use objc2::{define_class, msg_send_id, ClassType};
+///
+/// define_class!(
+///     // SAFETY:
+///     // - The superclass NSObject does not have any subclassing requirements.
+///     // - `SharedCache` does not implement `Drop`.
+///     #[unsafe(super(NSObject))]
+///     #[name = "OnceObjExampleSharedCache"]
+///     struct SharedCache;
+/// );
+///
+/// // SAFETY: The class has no state, and is thus safe to share.
+/// unsafe impl Send for SharedCache {}
+/// unsafe impl Sync for SharedCache {}
+///
+/// impl SharedCache {
+///     fn shared() -> &'static Self {
+///         static SHARED: OnceObj<SharedCache> = OnceObj::new();
+///         SHARED.get_or_init(|| unsafe { msg_send_id![Self::class(), new] })
+///     }
+/// }
+///
+/// assert!(std::ptr::eq(SharedCache::shared(), SharedCache::shared()));
+/// ```
+pub struct OnceObj<T: ?Sized> {
+    cell: OnceLock<Retained<T>>,
+}
+
+impl<T: ?Sized> OnceObj<T> {
+    /// Create a new, uninitialized cell.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            cell: OnceLock::new(),
+        }
+    }
+
+    /// Get the object, if it has been initialized.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        self.cell.get().map(|obj| &**obj)
+    }
+
+    /// Get the object, creating it with the given closure if it has not been
+    /// initialized yet.
+    ///
+    /// If several threads call this at once, only one of them runs the
+    /// closure, and the others block until it has completed.
+    #[inline]
+    pub fn get_or_init(&self, f: impl FnOnce() -> Retained<T>) -> &T {
+        self.cell.get_or_init(f)
+    }
+}
+
+impl<T: Message> OnceObj<T> {
+    /// Get a new retained reference to the object, creating it with the
+    /// given closure if it has not been initialized yet.
+    ///
+    /// See [`get_or_init`](Self::get_or_init) for details.
+    #[inline]
+    pub fn get_or_init_retained(&self, f: impl FnOnce() -> Retained<T>) -> Retained<T> {
+        self.get_or_init(f).retain()
+    }
+}
+
+impl<T: ?Sized> Default for OnceObj<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ?Sized + fmt::Debug> fmt::Debug for OnceObj<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("OnceObj").field(&self.get()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::vec::Vec;
+
+    use static_assertions::{assert_impl_all, assert_not_impl_any};
+
+    use super::*;
+    use crate::runtime::{AnyObject, NSObject};
+    use crate::{define_class, msg_send_id, ClassType};
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[name = "OnceObjTestObject"]
+        struct SendSyncObject;
+    );
+
+    unsafe impl Send for SendSyncObject {}
+    unsafe impl Sync for SendSyncObject {}
+
+    #[test]
+    fn test_auto_traits() {
+        assert_impl_all!(OnceObj<SendSyncObject>: Send, Sync);
+        assert_not_impl_any!(OnceObj<NSObject>: Send, Sync);
+        assert_not_impl_any!(OnceObj<AnyObject>: Send, Sync);
+    }
+
+    #[test]
+    fn test_init_once() {
+        static OBJ: OnceObj<SendSyncObject> = OnceObj::new();
+        static INITS: AtomicUsize = AtomicUsize::new(0);
+        assert!(OBJ.get().is_none());
+
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                thread::spawn(|| {
+                    let obj = OBJ.get_or_init(|| {
+                        INITS.fetch_add(1, Ordering::Relaxed);
+                        unsafe { msg_send_id![SendSyncObject::class(), new] }
+                    });
+                    obj as *const SendSyncObject as usize
+                })
+            })
+            .collect();
+        let ptrs: Vec<_> = threads.into_iter().map(|t| t.join().unwrap()).collect();
+
+        assert_eq!(INITS.load(Ordering::Relaxed), 1);
+        let obj: *const SendSyncObject = OBJ.get().unwrap();
+        assert!(ptrs.iter().all(|&ptr| ptr == obj as usize));
+    }
+}
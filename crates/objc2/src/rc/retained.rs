@@ -177,6 +177,12 @@ impl<T: ?Sized + Message> Retained<T> {
     /// retrieved from other methods than the ones noted above, use
     /// [`Retained::retain`] instead.
     ///
+    /// The pointer is stored as-is, and is returned with the same
+    /// [provenance] from [`Retained::into_raw`] and [`Retained::as_ptr`];
+    /// none of these methods cast the pointer to or from an integer.
+    ///
+    /// [provenance]: core::ptr#provenance
+    ///
     ///
     /// # Safety
     ///
@@ -256,6 +262,33 @@ impl<T: ?Sized + Message> Retained<T> {
         ManuallyDrop::new(this).ptr.as_ptr()
     }
 
+    /// Construct an [`Retained`] from a non-null pointer that already has +1
+    /// retain count.
+    ///
+    /// This is the same as [`Retained::from_raw`], except that the pointer is
+    /// statically known to be non-null, and hence no `Option` is returned.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Retained::from_raw`], except the pointer does not have to be
+    /// checked for NULL.
+    #[inline]
+    pub unsafe fn from_raw_nonnull(ptr: NonNull<T>) -> Self {
+        // SAFETY: Upheld by the caller
+        unsafe { Retained::new_nonnull(ptr) }
+    }
+
+    /// Consumes the `Retained`, returning a non-null raw pointer with +1
+    /// retain count.
+    ///
+    /// This is the same as [`Retained::into_raw`], except that it returns a
+    /// [`NonNull`], and is the inverse of [`Retained::from_raw_nonnull`].
+    #[inline]
+    pub fn into_raw_nonnull(this: Self) -> NonNull<T> {
+        ManuallyDrop::new(this).ptr
+    }
+
     /// Returns a raw pointer to the object.
     ///
     /// The pointer is valid for at least as long as the `Retained` is held.
@@ -266,6 +299,18 @@ impl<T: ?Sized + Message> Retained<T> {
         this.ptr.as_ptr()
     }
 
+    /// Returns a non-null raw pointer to the object.
+    ///
+    /// This is the same as [`Retained::as_ptr`], except that it returns a
+    /// [`NonNull`].
+    ///
+    /// This is an associated method, and must be called as
+    /// `Retained::as_nonnull_ptr(obj)`.
+    #[inline]
+    pub fn as_nonnull_ptr(this: &Self) -> NonNull<T> {
+        this.ptr
+    }
+
     #[inline]
     pub(crate) fn consume_as_ptr_option(this: Option<Self>) -> *mut T
     where
@@ -324,7 +369,7 @@ impl<T: Message> Retained<T> {
         // if the object has a lifetime (which it does not in our case).
         let obj: &AnyObject = unsafe { &*ptr };
 
-        if obj.is_kind_of_class(U::class()).as_bool() {
+        if obj.is::<U>() {
             // SAFETY: Just checked that the object is a class of type `U`,
             // and `T` is `'static`.
             //
@@ -528,6 +573,12 @@ impl<T: Message> Retained<T> {
             };
         }
 
+        #[cfg(feature = "debug-retain")]
+        // SAFETY: Same as `Retained::retain`.
+        unsafe {
+            super::debug_retain::record(ptr.cast(), super::RetainEventKind::Retain)
+        };
+
         // SAFETY: Same as `Retained::retain`, this is just an optimization.
         let res: *mut T = unsafe { ffi::objc_retainAutoreleasedReturnValue(ptr.cast()) }.cast();
 
@@ -584,6 +635,11 @@ impl<T: Message> Retained<T> {
     #[inline]
     pub fn autorelease_ptr(this: Self) -> *mut T {
         let ptr = ManuallyDrop::new(this).ptr.as_ptr();
+        #[cfg(feature = "debug-retain")]
+        // SAFETY: The `ptr` is guaranteed to be valid.
+        unsafe {
+            super::debug_retain::record(ptr.cast(), super::RetainEventKind::Autorelease)
+        };
         // SAFETY:
         // - The `ptr` is guaranteed to be valid and have at least one
         //   retain count.
@@ -623,6 +679,12 @@ impl<T: Message> Retained<T> {
             .map(|this| ManuallyDrop::new(this).ptr.as_ptr())
             .unwrap_or_else(ptr::null_mut);
 
+        #[cfg(feature = "debug-retain")]
+        // SAFETY: The pointer is NULL or valid.
+        unsafe {
+            super::debug_retain::record(ptr.cast(), super::RetainEventKind::Autorelease)
+        };
+
         // SAFETY: Same as `autorelease_inner`, this is just an optimization.
         let res: *mut T = unsafe { ffi::objc_autoreleaseReturnValue(ptr.cast()) }.cast();
         debug_assert_eq!(
@@ -971,6 +1033,21 @@ mod tests {
         expected.assert_current();
     }
 
+    #[test]
+    fn test_raw_nonnull_roundtrip() {
+        let obj = RcTestObject::new();
+        let expected = ThreadTestData::current();
+
+        let ptr = Retained::as_nonnull_ptr(&obj);
+        assert_eq!(ptr.as_ptr().cast_const(), Retained::as_ptr(&obj));
+
+        let raw = Retained::into_raw_nonnull(obj);
+        assert_eq!(raw, ptr);
+        let obj = unsafe { Retained::from_raw_nonnull(raw) };
+        assert_eq!(Retained::as_nonnull_ptr(&obj), ptr);
+        expected.assert_current();
+    }
+
     #[test]
     fn test_cast() {
         let obj: Retained<RcTestObject> = RcTestObject::new();
@@ -413,4 +413,12 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T: ?Sized + serde::Serialize> serde::Serialize for Retained<T> {
+    #[inline]
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (**self).serialize(serializer)
+    }
+}
+
 // TODO: impl Fn traits, CoerceUnsized, Stream and so on when stabilized
@@ -0,0 +1,182 @@
+use core::fmt;
+use core::ops::Deref;
+use core::panic::{RefUnwindSafe, UnwindSafe};
+use core::ptr::NonNull;
+
+use super::Retained;
+use crate::Message;
+
+/// A borrow of a value inside an object, that keeps the object alive.
+///
+/// This is an "owning reference"; it holds a [`Retained`] to the owner, along
+/// with a reference to something that the owner contains, and dereferences
+/// to the latter. This allows you to e.g. keep a `NSDictionary` alive while
+/// holding on to one of its values, without having to retain the value
+/// itself, and without the borrow being tied to an autorelease pool.
+///
+/// Created with [`Retained::map`].
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::rc::{Retained, RetainedRef};
+/// use objc2::runtime::{AnyClass, NSObject};
+/// use objc2::ClassType;
+///
+/// let obj = NSObject::new();
+/// // SAFETY: Classes are never deallocated.
+/// let cls: RetainedRef<NSObject, AnyClass> = unsafe { Retained::map(obj, |obj| obj.class()) };
+/// assert_eq!(&*cls, NSObject::class());
+/// ```
+pub struct RetainedRef<O: ?Sized, U: ?Sized> {
+    owner: Retained<O>,
+    /// Borrowed from `owner`, and valid for as long as that is.
+    ptr: NonNull<U>,
+}
+
+impl<O: ?Sized + Message> Retained<O> {
+    /// Project the object into a reference to something that it contains,
+    /// while keeping the object alive.
+    ///
+    /// The returned [`RetainedRef`] dereferences to the value returned by the
+    /// closure.
+    ///
+    /// This is an associated method, and must be called as
+    /// `Retained::map(obj, f)`.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The reference returned by the closure must stay valid for as long as
+    /// the object is alive.
+    ///
+    /// Objective-C objects are usually mutable through shared references, so
+    /// this is not guaranteed by the borrow checker. For example, if `f`
+    /// returns a value from a `NSMutableDictionary`, you must ensure that the
+    /// value is not removed from the dictionary (and thereby possibly
+    /// deallocated) while the returned `RetainedRef` is alive.
+    #[inline]
+    pub unsafe fn map<U: ?Sized>(this: Self, f: impl FnOnce(&O) -> &U) -> RetainedRef<O, U> {
+        let ptr = NonNull::from(f(&this));
+        RetainedRef { owner: this, ptr }
+    }
+}
+
+impl<O: ?Sized, U: ?Sized> RetainedRef<O, U> {
+    /// Project further into the value.
+    ///
+    /// This is an associated method, and must be called as
+    /// `RetainedRef::map(this, f)`.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Same as [`Retained::map`]; the reference returned by the closure must
+    /// stay valid for as long as the owner is alive.
+    #[inline]
+    pub unsafe fn map<V: ?Sized>(this: Self, f: impl FnOnce(&U) -> &V) -> RetainedRef<O, V> {
+        let ptr = NonNull::from(f(&this));
+        RetainedRef {
+            owner: this.owner,
+            ptr,
+        }
+    }
+
+    /// The object that keeps the value alive.
+    ///
+    /// This is an associated method, and must be called as
+    /// `RetainedRef::owner(&this)`.
+    #[inline]
+    pub fn owner(this: &Self) -> &Retained<O> {
+        &this.owner
+    }
+
+    /// Discard the reference, and return the owner.
+    ///
+    /// This is an associated method, and must be called as
+    /// `RetainedRef::into_owner(this)`.
+    #[inline]
+    pub fn into_owner(this: Self) -> Retained<O> {
+        this.owner
+    }
+}
+
+impl<O: ?Sized, U: ?Sized> Deref for RetainedRef<O, U> {
+    type Target = U;
+
+    #[inline]
+    fn deref(&self) -> &U {
+        // SAFETY: The pointer was created from a reference that borrowed
+        // from the owner, which we keep alive.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<O: Message, U: ?Sized> Clone for RetainedRef<O, U> {
+    /// Retain the owner, and borrow the same value.
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            owner: self.owner.clone(),
+            ptr: self.ptr,
+        }
+    }
+}
+
+impl<O: ?Sized, U: ?Sized + fmt::Debug> fmt::Debug for RetainedRef<O, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<O: ?Sized, U: ?Sized + fmt::Display> fmt::Display for RetainedRef<O, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+// SAFETY: Same as `Retained<O>` and `&U`.
+unsafe impl<O: ?Sized + Sync + Send, U: ?Sized + Sync> Send for RetainedRef<O, U> {}
+// SAFETY: Same as `Retained<O>` and `&U`.
+unsafe impl<O: ?Sized + Sync + Send, U: ?Sized + Sync> Sync for RetainedRef<O, U> {}
+
+impl<O: ?Sized + RefUnwindSafe, U: ?Sized + RefUnwindSafe> RefUnwindSafe for RetainedRef<O, U> {}
+impl<O: ?Sized + RefUnwindSafe, U: ?Sized + RefUnwindSafe> UnwindSafe for RetainedRef<O, U> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rc::{RcTestObject, ThreadTestData};
+    use crate::runtime::{AnyObject, NSObject};
+
+    #[test]
+    fn test_map_keeps_owner_alive() {
+        let obj = RcTestObject::new();
+        let mut expected = ThreadTestData::current();
+
+        // SAFETY: The superclass is part of the object itself.
+        let superclass: RetainedRef<RcTestObject, NSObject> =
+            unsafe { Retained::map(obj, |obj| &**obj) };
+        let any: RetainedRef<RcTestObject, AnyObject> =
+            unsafe { RetainedRef::map(superclass, |obj| &**obj) };
+        expected.assert_current();
+
+        let cloned = any.clone();
+        expected.retain += 1;
+        expected.assert_current();
+        assert_eq!(
+            Retained::as_ptr(RetainedRef::owner(&cloned)).cast::<AnyObject>(),
+            &*any as *const AnyObject,
+        );
+
+        drop(any);
+        expected.release += 1;
+        expected.assert_current();
+
+        drop(RetainedRef::into_owner(cloned));
+        expected.release += 1;
+        expected.drop += 1;
+        expected.assert_current();
+    }
+}
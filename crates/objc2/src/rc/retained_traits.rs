@@ -114,6 +114,32 @@ impl<T, U: RetainedFromIterator<T>> FromIterator<T> for Retained<U> {
     }
 }
 
+/// Helper trait to implement [`serde::Deserialize`] on [`Retained`].
+///
+/// This should be implemented in exactly the same fashion as if you were
+/// implementing `Deserialize` for your type normally.
+#[cfg(feature = "serde")]
+pub trait DeserializeRetained<'de> {
+    /// Deserialize this value from the given deserializer.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns the deserializer's error if the input could not be
+    /// deserialized into this type.
+    fn deserialize_retained<D>(deserializer: D) -> Result<Retained<Self>, D::Error>
+    where
+        D: serde::Deserializer<'de>;
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: ?Sized + DeserializeRetained<'de>> serde::Deserialize<'de> for Retained<T> {
+    #[inline]
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize_retained(deserializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
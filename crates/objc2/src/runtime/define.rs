@@ -223,6 +223,22 @@ impl ClassBuilder {
     /// the entire `NSObject` protocol is implemented.
     /// Functionality it expects, like implementations of `-retain` and
     /// `-release` used by ARC, will not be present otherwise.
+    ///
+    /// At a minimum, to be usable with [`Retained`], a root class must
+    /// implement:
+    /// - `-retain`, which increments a reference count stored in an ivar, and
+    ///   returns `self`.
+    /// - `-release`, which decrements the reference count, and sends
+    ///   `-dealloc` once it reaches zero.
+    /// - `-dealloc`, which frees the object with [`ffi::object_dispose`].
+    ///
+    /// Instances are then created with [`ffi::class_createInstance`], since
+    /// there is no inherited `+alloc`. Root classes cannot be created with
+    /// [`define_class!`], as it relies on the superclass for memory
+    /// management.
+    ///
+    /// [`Retained`]: crate::rc::Retained
+    /// [`define_class!`]: crate::define_class
     pub fn root<F>(name: &CStr, initialize_fn: F) -> Option<Self>
     where
         F: MethodImplementation<Callee = AnyClass, Arguments = (), Return = ()>,
@@ -836,6 +852,68 @@ mod tests {
         assert!(is_present(cls));
     }
 
+    #[test]
+    fn test_root_class() {
+        use core::cell::Cell;
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        static DEALLOCATED: AtomicBool = AtomicBool::new(false);
+
+        extern "C-unwind" fn initialize(_cls: &AnyClass, _cmd: Sel) {}
+
+        fn retain_count(this: &AnyObject) -> &Cell<usize> {
+            let ivar = this.class().instance_variable(&c("_retainCount")).unwrap();
+            unsafe { ivar.load::<Cell<usize>>(this) }
+        }
+
+        extern "C-unwind" fn retain(this: &AnyObject, _cmd: Sel) -> *mut AnyObject {
+            let count = retain_count(this);
+            count.set(count.get() + 1);
+            (this as *const AnyObject).cast_mut()
+        }
+
+        unsafe extern "C-unwind" fn release(this: *mut AnyObject, _cmd: Sel) {
+            let count = retain_count(unsafe { &*this });
+            if count.get() == 0 {
+                let _: () = unsafe { msg_send![this, dealloc] };
+            } else {
+                count.set(count.get() - 1);
+            }
+        }
+
+        unsafe extern "C-unwind" fn dealloc(this: *mut AnyObject, _cmd: Sel) {
+            DEALLOCATED.store(true, Ordering::Relaxed);
+            unsafe {
+                #[allow(deprecated)]
+                ffi::object_dispose(this);
+            }
+        }
+
+        let mut builder = ClassBuilder::root(
+            &c("TestRootClass"),
+            initialize as extern "C-unwind" fn(_, _),
+        )
+        .unwrap();
+        builder.add_ivar::<Cell<usize>>(&c("_retainCount"));
+        unsafe {
+            builder.add_method(sel!(retain), retain as extern "C-unwind" fn(_, _) -> _);
+            builder.add_method(sel!(release), release as unsafe extern "C-unwind" fn(_, _));
+            builder.add_method(sel!(dealloc), dealloc as unsafe extern "C-unwind" fn(_, _));
+        }
+        let cls = builder.register();
+        assert_eq!(cls.superclass(), None);
+
+        let obj: *mut AnyObject = unsafe { ffi::class_createInstance(cls, 0) };
+        let obj = unsafe { Retained::from_raw(obj) }.unwrap();
+        let clone = obj.clone();
+        assert_eq!(retain_count(&obj).get(), 1);
+        drop(clone);
+        assert_eq!(retain_count(&obj).get(), 0);
+        assert!(!DEALLOCATED.load(Ordering::Relaxed));
+        drop(obj);
+        assert!(DEALLOCATED.load(Ordering::Relaxed));
+    }
+
     #[test]
     fn test_class_method() {
         let cls = test_utils::custom_class();
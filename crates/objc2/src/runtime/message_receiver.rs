@@ -346,7 +346,12 @@ fn panic_verify(cls: &AnyClass, sel: Sel, err: &crate::runtime::VerificationErro
     )
 }
 
-mod private {
+pub(crate) mod private {
+    /// Implemented for the receiver types provided by this crate, which all
+    /// have the same ABI as a pointer to the object.
+    ///
+    /// This allows them to be used as the receiver in method
+    /// implementations, which custom receivers cannot.
     pub trait Sealed {}
 }
 
@@ -354,17 +359,80 @@ mod private {
 ///
 /// Examples include objects pointers, class pointers, and block pointers.
 ///
+/// This can be implemented for custom smart pointers, to allow using them
+/// directly as the receiver in [`msg_send!`] and friends. Methods declared
+/// with [`extern_methods!`] take `&self`, and can be called through any
+/// smart pointer that implements [`Deref`] to the object.
+///
+/// [`msg_send!`]: crate::msg_send
+/// [`extern_methods!`]: crate::extern_methods
+/// [`Deref`]: core::ops::Deref
+///
 ///
 /// # Safety
 ///
-/// This is a sealed trait, and should not need to be implemented. Open an
-/// issue if you know a use-case where this restrition should be lifted!
-pub unsafe trait MessageReceiver: private::Sealed + Sized {
-    #[doc(hidden)]
+/// The pointer returned from [`as_raw_receiver`] must either be NULL, or
+/// point to a valid instance of [`__Inner`] (or one of its subclasses), that
+/// is kept alive at least until the message send has completed.
+///
+/// Note in particular that a receiver passed by value may be dropped before
+/// the message is sent, so implementations that own the object must ensure
+/// that it is kept alive by other means.
+///
+/// [`as_raw_receiver`]: Self::as_raw_receiver
+/// [`__Inner`]: Self::__Inner
+///
+///
+/// # Example
+///
+/// A handle that counts the messages that are sent through it.
+///
+/// ```
+/// use std::cell::Cell;
+///
+/// use objc2::runtime::{AnyObject, MessageReceiver, NSObject};
+/// use objc2::msg_send;
+///
+/// struct Counting<'a> {
+///     obj: &'a NSObject,
+///     count: &'a Cell<usize>,
+/// }
+///
+/// // SAFETY: The pointer comes from a reference, which is valid for the
+/// // duration of the message send.
+/// unsafe impl MessageReceiver for Counting<'_> {
+///     type __Inner = NSObject;
+///
+///     fn as_raw_receiver(self) -> *mut AnyObject {
+///         self.count.set(self.count.get() + 1);
+///         self.obj.as_raw_receiver()
+///     }
+/// }
+///
+/// let obj = NSObject::new();
+/// let count = Cell::new(0);
+/// let handle = || Counting { obj: &obj, count: &count };
+/// let _hash: usize = unsafe { msg_send![handle(), hash] };
+/// let _: bool = unsafe { msg_send![handle(), isEqual: &*obj] };
+/// assert_eq!(count.get(), 2);
+/// ```
+pub unsafe trait MessageReceiver: Sized {
+    /// The type of the object that the receiver points to.
     type __Inner: ?Sized + RefEncode;
 
+    /// Get a raw pointer to the receiver object, which may be NULL.
+    ///
+    /// This is called once for each message sent to the receiver.
+    // Receivers are usually `Copy`, and this does not give up ownership.
+    #[allow(clippy::wrong_self_convention)]
+    fn as_raw_receiver(self) -> *mut AnyObject;
+
     #[doc(hidden)]
-    fn __as_raw_receiver(self) -> *mut AnyObject;
+    #[deprecated = "renamed to `as_raw_receiver`"]
+    #[inline]
+    fn __as_raw_receiver(self) -> *mut AnyObject {
+        self.as_raw_receiver()
+    }
 
     /// Sends a message to the receiver with the given selector and arguments.
     ///
@@ -415,7 +483,7 @@ pub unsafe trait MessageReceiver: private::Sealed + Sized {
     #[doc(alias = "performSelector:withObject:")]
     #[doc(alias = "performSelector:withObject:withObject:")]
     unsafe fn send_message<A: EncodeArguments, R: EncodeReturn>(self, sel: Sel, args: A) -> R {
-        let receiver = self.__as_raw_receiver();
+        let receiver = self.as_raw_receiver();
         #[cfg(debug_assertions)]
         {
             // SAFETY: Caller ensures only valid or NULL pointers.
@@ -423,6 +491,9 @@ pub unsafe trait MessageReceiver: private::Sealed + Sized {
             msg_send_check(obj, sel, A::ENCODINGS, &R::ENCODING_RETURN);
         }
 
+        #[cfg(feature = "unstable-msg-send-hook")]
+        let _hook = super::msg_send_hook::before(receiver, sel);
+
         // SAFETY: Upheld by caller
         conditional_try!(|| unsafe { msg_send_primitive::send(receiver, sel, args) })
     }
@@ -457,7 +528,7 @@ pub unsafe trait MessageReceiver: private::Sealed + Sized {
         sel: Sel,
         args: A,
     ) -> R {
-        let receiver = self.__as_raw_receiver();
+        let receiver = self.as_raw_receiver();
         #[cfg(debug_assertions)]
         {
             if receiver.is_null() {
@@ -466,6 +537,9 @@ pub unsafe trait MessageReceiver: private::Sealed + Sized {
             msg_send_check_class(superclass, sel, A::ENCODINGS, &R::ENCODING_RETURN);
         }
 
+        #[cfg(feature = "unstable-msg-send-hook")]
+        let _hook = super::msg_send_hook::before(receiver, sel);
+
         // SAFETY: Upheld by caller
         conditional_try!(|| unsafe {
             msg_send_primitive::send_super(receiver, superclass, sel, args)
@@ -481,7 +555,7 @@ unsafe impl<T: ?Sized + Message> MessageReceiver for *const T {
     type __Inner = T;
 
     #[inline]
-    fn __as_raw_receiver(self) -> *mut AnyObject {
+    fn as_raw_receiver(self) -> *mut AnyObject {
         (self as *mut T).cast()
     }
 }
@@ -491,7 +565,7 @@ unsafe impl<T: ?Sized + Message> MessageReceiver for *mut T {
     type __Inner = T;
 
     #[inline]
-    fn __as_raw_receiver(self) -> *mut AnyObject {
+    fn as_raw_receiver(self) -> *mut AnyObject {
         self.cast()
     }
 }
@@ -501,7 +575,7 @@ unsafe impl<T: ?Sized + Message> MessageReceiver for NonNull<T> {
     type __Inner = T;
 
     #[inline]
-    fn __as_raw_receiver(self) -> *mut AnyObject {
+    fn as_raw_receiver(self) -> *mut AnyObject {
         self.as_ptr().cast()
     }
 }
@@ -511,7 +585,7 @@ unsafe impl<T: ?Sized + Message> MessageReceiver for &T {
     type __Inner = T;
 
     #[inline]
-    fn __as_raw_receiver(self) -> *mut AnyObject {
+    fn as_raw_receiver(self) -> *mut AnyObject {
         let ptr: *const T = self;
         (ptr as *mut T).cast()
     }
@@ -527,7 +601,7 @@ unsafe impl MessageReceiver for &mut AnyObject {
     type __Inner = AnyObject;
 
     #[inline]
-    fn __as_raw_receiver(self) -> *mut AnyObject {
+    fn as_raw_receiver(self) -> *mut AnyObject {
         self
     }
 }
@@ -620,6 +694,46 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_send_message_optional_receiver() {
+        let obj = test_utils::custom_object();
+        let _: () = unsafe { msg_send![&obj, setFoo: 4u32] };
+
+        let some: Option<&test_utils::CustomObject> = Some(&*obj);
+        let result: u32 = unsafe { msg_send![some, foo] };
+        assert_eq!(result, 4);
+
+        let nil: Option<&test_utils::CustomObject> = None;
+        let _: () = unsafe { msg_send![nil, setFoo: 5u32] };
+        let result: u32 = unsafe { msg_send![nil, foo] };
+        assert_eq!(result, 0);
+        let result: bool = unsafe { msg_send![nil, isEqual: &*obj] };
+        assert!(!result);
+        let result: Option<&AnyObject> = unsafe { msg_send![nil, self] };
+        assert!(result.is_none());
+        let result: test_utils::CustomStruct = unsafe { msg_send![nil, customStruct] };
+        let expected = test_utils::CustomStruct {
+            a: 0,
+            b: 0,
+            c: 0,
+            d: 0,
+        };
+        assert_eq!(result, expected);
+
+        let obj = NSObject::new();
+        let some: Option<&Retained<NSObject>> = Some(&obj);
+        let result: Option<Retained<NSObject>> = unsafe { msg_send_id![some, self] };
+        assert_eq!(result.as_deref(), Some(&*obj));
+
+        let nil: Option<&Retained<NSObject>> = None;
+        let result: Option<Retained<NSObject>> = unsafe { msg_send_id![nil, self] };
+        assert!(result.is_none());
+        let result: Option<Retained<NSObject>> = unsafe { msg_send_id![nil, copy] };
+        assert!(result.is_none());
+        let result: Option<Retained<NSObject>> = unsafe { msg_send_id![nil, new] };
+        assert!(result.is_none());
+    }
+
     #[test]
     fn test_send_message_super() {
         let obj = test_utils::custom_subclass_object();
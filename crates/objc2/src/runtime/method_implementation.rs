@@ -3,6 +3,7 @@ use core::mem;
 use crate::__macro_helpers::RetainedReturnValue;
 use crate::encode::{EncodeArgument, EncodeArguments, EncodeReturn, RefEncode};
 use crate::rc::Allocated;
+use crate::runtime::message_receiver::private::Sealed as BuiltinReceiver;
 use crate::runtime::{Imp, MessageReceiver, Sel};
 use crate::Message;
 
@@ -34,18 +35,18 @@ macro_rules! method_impl_inner {
     ($(($unsafe:ident))? $abi:literal; $($t:ident),*) => {
         impl<T, R, $($t),*> private::Sealed for $($unsafe)? extern $abi fn(T, Sel $(, $t)*) -> R
         where
-            T: ?Sized + MessageReceiver,
+            T: ?Sized + MessageReceiver + BuiltinReceiver,
             R: EncodeReturn,
             $($t: EncodeArgument,)*
         {}
 
         impl<T, R, $($t),*> MethodImplementation for $($unsafe)? extern $abi fn(T, Sel $(, $t)*) -> R
         where
-            T: ?Sized + MessageReceiver,
+            T: ?Sized + MessageReceiver + BuiltinReceiver,
             R: EncodeReturn,
             $($t: EncodeArgument,)*
         {
-            type Callee = T::__Inner;
+            type Callee = <T as MessageReceiver>::__Inner;
             type Arguments = ($($t,)*);
             type Return = R;
 
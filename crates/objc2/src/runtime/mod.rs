@@ -34,6 +34,8 @@ mod malloc;
 mod message_receiver;
 mod method_encoding_iter;
 mod method_implementation;
+#[cfg(feature = "unstable-msg-send-hook")]
+mod msg_send_hook;
 mod nsobject;
 mod nsproxy;
 mod nszone;
@@ -43,9 +45,9 @@ mod retain_release_fast;
 pub(crate) use self::method_encoding_iter::{EncodingParseError, MethodEncodingIter};
 pub(crate) use self::retain_release_fast::{objc_release_fast, objc_retain_fast};
 use crate::encode::{Encode, EncodeArguments, EncodeReturn, Encoding, OptionEncode, RefEncode};
-use crate::msg_send;
+use crate::rc::{autoreleasepool_leaking, Retained};
 use crate::verify::{verify_method_signature, Inner};
-use crate::{ffi, DowncastTarget, Message};
+use crate::{ffi, msg_send, msg_send_id, sel, ClassType, DowncastTarget, Message};
 
 // Note: While this is not public, it is still a breaking change to remove,
 // since `objc2-foundation` relies on it.
@@ -56,6 +58,10 @@ pub use self::bool::Bool;
 pub use self::define::{ClassBuilder, ProtocolBuilder};
 pub use self::message_receiver::MessageReceiver;
 pub use self::method_implementation::MethodImplementation;
+#[cfg(feature = "unstable-msg-send-hook")]
+pub use self::msg_send_hook::{set_msg_send_hook, MsgSendHook};
+#[cfg(feature = "unstable-msg-send-hook-tracing")]
+pub use self::msg_send_hook::TracingMsgSendHook;
 pub use self::nsobject::{NSObject, NSObjectProtocol};
 pub use self::nszone::NSZone;
 pub use self::protocol_object::{ImplementedBy, ProtocolObject};
@@ -64,6 +70,7 @@ pub use crate::verify::VerificationError;
 #[allow(deprecated)]
 pub use crate::ffi::{BOOL, NO, YES};
 
+use self::__nsstring::nsstring_to_str;
 use self::malloc::{MallocCStr, MallocSlice};
 
 /// We do not want to expose `MallocSlice` to end users, because in the
@@ -158,18 +165,36 @@ impl Sel {
     pub const unsafe fn __internal_from_ptr(ptr: *const u8) -> Self {
         // Used in static selectors.
         // SAFETY: Upheld by caller.
-        let ptr = unsafe { NonNull::new_unchecked(ptr as *mut c_void) };
+        let ptr = unsafe { NonNull::new_unchecked(ptr.cast_mut().cast::<c_void>()) };
         Self { ptr }
     }
 
+    /// Create a selector from a raw `SEL` pointer.
+    ///
+    /// Returns `None` if the pointer was NULL.
+    ///
+    /// The pointer is stored as-is, so [`Sel::as_ptr`] returns a pointer with
+    /// the same [provenance].
+    ///
+    /// [provenance]: core::ptr#provenance
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be NULL, or a selector that is registered with the
+    /// Objective-C runtime.
     #[inline]
-    pub(crate) unsafe fn from_ptr(ptr: *const c_void) -> Option<Self> {
+    pub unsafe fn from_ptr(ptr: *const c_void) -> Option<Self> {
         // SAFETY: Caller verifies that the pointer is valid.
-        NonNull::new(ptr as *mut c_void).map(|ptr| Self { ptr })
+        NonNull::new(ptr.cast_mut()).map(|ptr| Self { ptr })
     }
 
+    /// Get a raw pointer to the selector, usable as a `SEL`.
+    ///
+    /// This does not cast the pointer through an integer, so the original
+    /// provenance is retained.
     #[inline]
-    pub(crate) const fn as_ptr(&self) -> *const c_void {
+    pub const fn as_ptr(&self) -> *const c_void {
         self.ptr.as_ptr()
     }
 
@@ -512,7 +537,7 @@ impl Method {
     #[inline]
     fn as_mut_ptr(&self) -> *mut Self {
         let ptr: *const Self = self;
-        ptr as _
+        ptr.cast_mut()
     }
 
     /// Returns the name of self.
@@ -776,6 +801,38 @@ impl AnyClass {
         unsafe { Self::superclass_raw(self) }
     }
 
+    /// Whether self is the given class, or a subclass of it.
+    ///
+    /// This walks the superclass chain in the runtime, and thus works for
+    /// any class, including root classes that do not implement
+    /// `isKindOfClass:` or `isSubclassOfClass:`.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2::runtime::{ClassBuilder, NSObject};
+    /// use objc2::ClassType;
+    ///
+    /// let cls = ClassBuilder::new(c"MySubclass", NSObject::class())
+    ///     .unwrap()
+    ///     .register();
+    /// assert!(cls.is_subclass_of(NSObject::class()));
+    /// assert!(cls.is_subclass_of(cls));
+    /// assert!(!NSObject::class().is_subclass_of(cls));
+    /// ```
+    #[doc(alias = "isSubclassOfClass:")]
+    pub fn is_subclass_of(&self, cls: &AnyClass) -> bool {
+        let mut current = Some(self);
+        while let Some(class) = current {
+            if class == cls {
+                return true;
+            }
+            current = class.superclass();
+        }
+        false
+    }
+
     /// Returns the metaclass of self.
     ///
     ///
@@ -1365,6 +1422,30 @@ impl AnyObject {
         unsafe { msg_send![self, isKindOfClass: cls] }
     }
 
+    /// Whether the object is an instance of the class `T`, or one of its
+    /// subclasses.
+    ///
+    /// This is a shorthand for calling `isKindOfClass:` with `T::class()`,
+    /// and has the same caveats as [`downcast_ref`][Self::downcast_ref].
+    /// Unlike that method, it works for generic classes too, although the
+    /// generic parameters are not checked.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2::runtime::{AnyObject, NSObject};
+    ///
+    /// let obj = NSObject::new();
+    /// let obj: &AnyObject = &obj;
+    /// assert!(obj.is::<NSObject>());
+    /// ```
+    #[inline]
+    #[doc(alias = "isKindOfClass:")]
+    pub fn is<T: ClassType>(&self) -> bool {
+        self.is_kind_of_class(T::class()).as_bool()
+    }
+
     /// Attempt to downcast the object to a class of type `T`.
     ///
     /// This is the reference-variant. Use [`Retained::downcast`] if you want
@@ -1467,7 +1548,7 @@ impl AnyObject {
     /// ```
     #[inline]
     pub fn downcast_ref<T: DowncastTarget>(&self) -> Option<&T> {
-        if self.is_kind_of_class(T::class()).as_bool() {
+        if self.is::<T>() {
             // SAFETY: Just checked that the object is a class of type `T`.
             //
             // Generic `T` like `NSArray<NSString>` are ruled out by
@@ -1483,10 +1564,57 @@ impl AnyObject {
     // objc_removeAssociatedObjects
 }
 
+/// Call a description method, catching any exceptions that it throws if the
+/// `"exception"` feature is enabled.
+fn catch_description(f: impl FnOnce() -> Option<Retained<NSObject>>) -> Option<Retained<NSObject>> {
+    #[cfg(feature = "exception")]
+    {
+        // The object is not observed after an exception, other than for
+        // printing its class and pointer, which cannot be left in an
+        // inconsistent state.
+        crate::exception::catch(core::panic::AssertUnwindSafe(f))
+            .ok()
+            .flatten()
+    }
+    #[cfg(not(feature = "exception"))]
+    {
+        f()
+    }
+}
+
+/// Formats the object with `debugDescription` if it responds to that,
+/// falling back to `description`, and then to the class name and pointer.
+///
+/// If the `"exception"` feature is enabled, exceptions thrown by the
+/// description methods are caught, and the class name and pointer is used
+/// instead.
 impl fmt::Debug for AnyObject {
+    #[doc(alias = "debugDescription")]
+    #[doc(alias = "description")]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let ptr: *const Self = self;
-        write!(f, "<{}: {:p}>", self.class(), ptr)
+        let cls = self.class();
+        // SAFETY: Both methods are declared to return `NSString`, and the
+        // object responds to the selector.
+        let description = if cls.responds_to(sel!(debugDescription)) {
+            catch_description(|| unsafe { msg_send_id![self, debugDescription] })
+        } else if cls.responds_to(sel!(description)) {
+            catch_description(|| unsafe { msg_send_id![self, description] })
+        } else {
+            None
+        };
+
+        if let Some(description) = description {
+            // Same reasoning as in the `Debug` impl for `ProtocolObject`.
+            autoreleasepool_leaking(|pool| {
+                // SAFETY: The description is an `NSString`, and we control
+                // the scope in which the string is alive.
+                let s = unsafe { nsstring_to_str(&description, pool) };
+                fmt::Display::fmt(s, f)
+            })
+        } else {
+            let ptr: *const Self = self;
+            write!(f, "<{}: {:p}>", cls, ptr)
+        }
     }
 }
 
@@ -1543,6 +1671,15 @@ mod tests {
         assert_eq!(sel.name(), &*s);
     }
 
+    #[test]
+    fn test_selector_raw_roundtrip() {
+        let sel = sel!(description);
+        let ptr = sel.as_ptr();
+        assert_eq!(unsafe { Sel::from_ptr(ptr) }, Some(sel));
+        assert_eq!(unsafe { Sel::from_ptr(ptr) }.unwrap().as_ptr(), ptr);
+        assert_eq!(unsafe { Sel::from_ptr(ptr::null()) }, None);
+    }
+
     #[test]
     fn test_ivar() {
         let cls = test_utils::custom_class();
@@ -1772,6 +1909,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_is_subclass_of() {
+        let cls = test_utils::custom_class();
+        let subclass = test_utils::custom_subclass();
+        assert!(subclass.is_subclass_of(cls));
+        assert!(subclass.is_subclass_of(subclass));
+        assert!(!cls.is_subclass_of(subclass));
+        assert!(!cls.is_subclass_of(NSObject::class()));
+        assert!(!cls.is_subclass_of(cls.metaclass()));
+    }
+
+    #[test]
+    fn test_is() {
+        let obj = NSObject::new();
+        let obj: &AnyObject = &obj;
+        assert!(obj.is::<NSObject>());
+        assert!(!obj.is::<crate::rc::RcTestObject>());
+    }
+
     #[test]
     fn test_multiple_colon() {
         let class = test_utils::custom_class();
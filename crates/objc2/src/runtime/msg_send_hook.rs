@@ -0,0 +1,186 @@
+//! A global hook that is called around every message send.
+use core::cell::Cell;
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use super::{AnyClass, AnyObject, Sel};
+
+/// A hook that is called before and after every message send.
+///
+/// This is intended for debugging and profiling, for example to find out
+/// which selectors an application spends the most time in, without having to
+/// run it under Instruments. Set it with [`set_msg_send_hook`].
+///
+/// Messages sent from inside the hook do not call the hook again.
+///
+/// This is only available when the `"unstable-msg-send-hook"` feature is
+/// enabled. It adds overhead to every message send, and prevents the
+/// optimization of retaining autoreleased return values, so it should not be
+/// used in release builds.
+///
+///
+/// See [`TracingMsgSendHook`] for a hook that integrates with the [`tracing`]
+/// crate.
+///
+/// [`tracing`]: https://docs.rs/tracing
+///
+///
+/// # Example
+///
+/// Print slow message sends.
+///
+/// ```
+/// use std::time::Duration;
+/// use objc2::runtime::{set_msg_send_hook, AnyClass, MsgSendHook, Sel};
+///
+/// struct SlowMessages;
+///
+/// impl MsgSendHook for SlowMessages {
+///     fn after(&self, cls: &AnyClass, sel: Sel, duration: Duration) {
+///         if duration > Duration::from_millis(10) {
+///             eprintln!("-[{cls} {sel}] took {duration:?}");
+///         }
+///     }
+/// }
+///
+/// set_msg_send_hook(&SlowMessages);
+/// ```
+pub trait MsgSendHook: Sync {
+    /// Called before the message is sent.
+    ///
+    /// `cls` is the class of the receiver (which is a metaclass for class
+    /// methods). Messages to `nil` are not reported.
+    fn before(&self, cls: &AnyClass, sel: Sel) {
+        let _ = (cls, sel);
+    }
+
+    /// Called after the message has been sent, with the time that it took.
+    ///
+    /// This is also called if the method unwinds.
+    fn after(&self, cls: &AnyClass, sel: Sel, duration: Duration) {
+        let _ = (cls, sel, duration);
+    }
+}
+
+static HOOK: OnceLock<&'static dyn MsgSendHook> = OnceLock::new();
+
+std::thread_local! {
+    static IN_HOOK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Set the global message send hook.
+///
+/// The hook can only be set once; returns `false` if a hook was already
+/// set.
+///
+/// See [`MsgSendHook`] for details.
+pub fn set_msg_send_hook(hook: &'static dyn MsgSendHook) -> bool {
+    HOOK.set(hook).is_ok()
+}
+
+/// Resets the reentrancy flag when dropped, also if the hook unwinds.
+struct ResetInHook;
+
+impl Drop for ResetInHook {
+    fn drop(&mut self) {
+        IN_HOOK.with(|in_hook| in_hook.set(false));
+    }
+}
+
+/// Run the closure, unless we're already inside a hook on this thread.
+fn without_reentrancy(f: impl FnOnce()) {
+    if IN_HOOK.with(|in_hook| in_hook.replace(true)) {
+        return;
+    }
+    let _reset = ResetInHook;
+    f();
+}
+
+/// Calls the hook's `after` when dropped.
+pub(crate) struct HookGuard {
+    hook: &'static dyn MsgSendHook,
+    cls: &'static AnyClass,
+    sel: Sel,
+    start: Instant,
+}
+
+impl Drop for HookGuard {
+    fn drop(&mut self) {
+        let duration = self.start.elapsed();
+        without_reentrancy(|| self.hook.after(self.cls, self.sel, duration));
+    }
+}
+
+/// Call the hook's `before`, and return a guard that calls `after`.
+#[inline]
+pub(crate) fn before(receiver: *mut AnyObject, sel: Sel) -> Option<HookGuard> {
+    let hook = *HOOK.get()?;
+    // SAFETY: The receiver is either NULL or a valid object, as ensured by
+    // the caller of the message send.
+    let cls = unsafe { receiver.as_ref() }?.class();
+    if IN_HOOK.with(Cell::get) {
+        return None;
+    }
+    without_reentrancy(|| hook.before(cls, sel));
+    Some(HookGuard {
+        hook,
+        cls,
+        sel,
+        start: Instant::now(),
+    })
+}
+
+/// A [`MsgSendHook`] that records each message send as a [`tracing`] span.
+///
+/// The span is named `msg_send`, has the `class` and `selector` as fields,
+/// and is entered for the duration of the message send. Nested message sends
+/// thus show up as child spans, which allows e.g. generating a flamegraph of
+/// the selectors with `tracing-flame`.
+///
+/// Spans are created at the `TRACE` level, with the target
+/// `objc2::msg_send`.
+///
+/// This is only available when the `"unstable-msg-send-hook-tracing"`
+/// feature is enabled.
+///
+/// [`tracing`]: https://docs.rs/tracing
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::runtime::{set_msg_send_hook, TracingMsgSendHook};
+///
+/// set_msg_send_hook(&TracingMsgSendHook);
+/// ```
+#[cfg(feature = "unstable-msg-send-hook-tracing")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct TracingMsgSendHook;
+
+#[cfg(feature = "unstable-msg-send-hook-tracing")]
+std::thread_local! {
+    /// The spans that are currently entered on this thread.
+    ///
+    /// `before` and `after` are always called in pairs on the same thread,
+    /// so this works like a stack.
+    static SPANS: core::cell::RefCell<std::vec::Vec<tracing::span::EnteredSpan>> =
+        const { core::cell::RefCell::new(std::vec::Vec::new()) };
+}
+
+#[cfg(feature = "unstable-msg-send-hook-tracing")]
+impl MsgSendHook for TracingMsgSendHook {
+    fn before(&self, cls: &AnyClass, sel: Sel) {
+        let span = tracing::trace_span!(
+            target: "objc2::msg_send",
+            "msg_send",
+            class = %cls,
+            selector = %sel,
+        );
+        SPANS.with(|spans| spans.borrow_mut().push(span.entered()));
+    }
+
+    fn after(&self, _cls: &AnyClass, _sel: Sel, _duration: Duration) {
+        // Exits the span.
+        SPANS.with(|spans| drop(spans.borrow_mut().pop()));
+    }
+}
@@ -5,7 +5,7 @@ use core::panic::{RefUnwindSafe, UnwindSafe};
 
 use crate::ffi::NSUInteger;
 use crate::rc::{Allocated, DefaultRetained, Retained};
-use crate::runtime::{AnyClass, AnyObject, AnyProtocol, ImplementedBy, ProtocolObject, Sel};
+use crate::runtime::{AnyClass, AnyObject, AnyProtocol, ImplementedBy, Sel};
 use crate::DowncastTarget;
 use crate::{
     extern_methods, msg_send, msg_send_id, AllocAnyThread, ClassType, Message, ProtocolType,
@@ -108,6 +108,13 @@ unsafe impl DowncastTarget for NSObject {}
 /// bound in [`extern_protocol!`], to allow your protocol to implement `Debug`
 /// `Hash`, `PartialEq` and `Eq`.
 ///
+/// Generic code that needs these methods can use `T: NSObjectProtocol +
+/// Message` as a bound. The trait is not implemented for all [`Message`]
+/// types, since root classes other than `NSObject` and `NSProxy` are not
+/// required to implement any of these methods. Use
+/// [`AnyObject::downcast_ref`] to get a `&NSObject` from an object of
+/// unknown type.
+///
 /// This trait is exported under `objc2_foundation::NSObjectProtocol`, you
 /// probably want to use that path instead.
 ///
@@ -234,6 +241,59 @@ pub unsafe trait NSObjectProtocol {
         unsafe { msg_send![self, conformsToProtocol: aProtocol] }
     }
 
+    /// Send a message with the given selector to the object, and return the
+    /// result.
+    ///
+    /// This is useful in the rare cases where the selector is only known at
+    /// runtime; prefer [`msg_send!`] and [`msg_send_id!`] otherwise.
+    ///
+    /// See [Apple's documentation][apple-doc] for details.
+    ///
+    /// [apple-doc]: https://developer.apple.com/documentation/objectivec/1418956-nsobject/1418867-performselector?language=objc
+    /// [`msg_send!`]: crate::msg_send
+    /// [`msg_send_id!`]: crate::msg_send_id
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The method must take no arguments, and must return an object (or
+    /// `nil`) following the normal memory management rules, i.e. the method
+    /// must not be in the `new`, `alloc`, `init`, `copy` or `mutableCopy`
+    /// method families.
+    ///
+    /// Additionally, the usual safety requirements of the method apply.
+    #[doc(alias = "performSelector:")]
+    unsafe fn performSelector(&self, aSelector: Sel) -> Option<Retained<AnyObject>>
+    where
+        Self: Sized + Message,
+    {
+        unsafe { msg_send_id![self, performSelector: aSelector] }
+    }
+
+    /// Send a message with the given selector and object argument to the
+    /// object, and return the result.
+    ///
+    /// See [`performSelector`][Self::performSelector] for details.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The method must take a single object argument, and must return an
+    /// object (or `nil`) following the normal memory management rules.
+    ///
+    /// Additionally, the usual safety requirements of the method apply.
+    #[doc(alias = "performSelector:withObject:")]
+    unsafe fn performSelector_withObject(
+        &self,
+        aSelector: Sel,
+        object: Option<&AnyObject>,
+    ) -> Option<Retained<AnyObject>>
+    where
+        Self: Sized + Message,
+    {
+        unsafe { msg_send_id![self, performSelector: aSelector, withObject: object] }
+    }
+
     /// A textual representation of the object.
     ///
     /// The returned class is `NSString`, but since that is defined in
@@ -455,7 +515,7 @@ impl fmt::Debug for NSObject {
     #[doc(alias = "description")]
     #[doc(alias = "debugDescription")]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let obj: &ProtocolObject<dyn NSObjectProtocol> = ProtocolObject::from_ref(self);
+        let obj: &AnyObject = self;
         obj.fmt(f)
     }
 }
@@ -472,8 +532,8 @@ mod tests {
     use super::*;
     use alloc::format;
 
-    use crate::extern_class;
     use crate::rc::RcTestObject;
+    use crate::{extern_class, sel};
 
     extern_class!(
         #[unsafe(super(NSObject))]
@@ -557,6 +617,42 @@ mod tests {
         assert_eq!(format!("{obj:?}"), expected);
     }
 
+    #[test]
+    fn test_perform_selector() {
+        let obj = NSObject::new();
+        let res = unsafe { obj.performSelector(sel!(self)) }.unwrap();
+        assert_eq!(Retained::as_ptr(&res), (&*obj as *const NSObject).cast());
+    }
+
+    #[test]
+    fn test_debug_uses_debug_description() {
+        use core::ffi::c_char;
+
+        use crate::{class, define_class};
+
+        define_class!(
+            #[unsafe(super(NSObject))]
+            #[name = "TestDebugDescriptionObject"]
+            struct DebugDescriptionObject;
+
+            unsafe impl DebugDescriptionObject {
+                #[method_id(debugDescription)]
+                fn debug_description(&self) -> Retained<NSObject> {
+                    let s = b"custom debug description\0".as_ptr().cast::<c_char>();
+                    unsafe { msg_send_id![class!(NSString), stringWithUTF8String: s] }
+                }
+            }
+        );
+
+        let obj: Retained<DebugDescriptionObject> =
+            unsafe { msg_send_id![DebugDescriptionObject::class(), new] };
+        let obj: &AnyObject = &obj;
+        assert_eq!(format!("{obj:?}"), "custom debug description");
+
+        let obj: &NSObject = obj.downcast_ref().unwrap();
+        assert_eq!(format!("{obj:?}"), "custom debug description");
+    }
+
     #[test]
     fn test_is_kind_of() {
         let obj = NSObject::new();
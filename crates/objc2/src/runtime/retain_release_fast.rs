@@ -66,6 +66,12 @@ use crate::runtime::AnyObject;
 /// Same as `ffi::objc_retain`.
 #[inline]
 pub(crate) unsafe fn objc_retain_fast(obj: *mut AnyObject) -> *mut AnyObject {
+    #[cfg(feature = "debug-retain")]
+    // SAFETY: Upheld by caller.
+    unsafe {
+        crate::rc::debug_retain::record(obj, crate::rc::RetainEventKind::Retain)
+    };
+
     #[cfg(all(feature = "unstable-apple-new", target_arch = "aarch64"))]
     // SAFETY: See the file header.
     //
@@ -103,6 +109,12 @@ pub(crate) unsafe fn objc_retain_fast(obj: *mut AnyObject) -> *mut AnyObject {
 /// Same as `ffi::objc_release`.
 #[inline]
 pub(crate) unsafe fn objc_release_fast(obj: *mut AnyObject) {
+    #[cfg(feature = "debug-retain")]
+    // SAFETY: Upheld by caller.
+    unsafe {
+        crate::rc::debug_retain::record(obj, crate::rc::RetainEventKind::Release)
+    };
+
     #[cfg(all(feature = "unstable-apple-new", target_arch = "aarch64"))]
     // SAFETY: See the file header.
     //
@@ -42,6 +42,9 @@ unsafe impl Encode for CustomStruct {
     );
 }
 
+// SAFETY: The struct only contains integers.
+unsafe impl crate::__macro_helpers::ZeroableReturn for CustomStruct {}
+
 // TODO: Remove once c"" strings are in MSRV
 fn c(s: &str) -> CString {
     CString::new(s).unwrap()
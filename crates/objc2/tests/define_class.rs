@@ -6,7 +6,7 @@ use std::panic::{RefUnwindSafe, UnwindSafe};
 
 use objc2::rc::Retained;
 use objc2::runtime::NSObject;
-use objc2::{define_class, extern_methods, sel, ClassType, MainThreadOnly};
+use objc2::{define_class, extern_methods, msg_send_id, sel, ClassType, MainThreadOnly};
 use static_assertions::{assert_impl_all, assert_not_impl_any};
 
 // Test that adding the `deprecated` attribute does not mean that warnings
@@ -499,6 +499,42 @@ fn test_pointer_receiver_allowed() {
     let _ = PointerReceiver::class();
 }
 
+#[test]
+fn test_initialize_only_runs_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static INITIALIZED: AtomicUsize = AtomicUsize::new(0);
+
+    define_class!(
+        #[unsafe(super(NSObject))]
+        #[name = "InitializeBase"]
+        struct InitializeBase;
+
+        unsafe impl InitializeBase {
+            #[method(initialize)]
+            fn initialize() {
+                INITIALIZED.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    );
+
+    define_class!(
+        #[unsafe(super(InitializeBase))]
+        #[name = "InitializeSubclass"]
+        struct InitializeSubclass;
+    );
+
+    assert_eq!(INITIALIZED.load(Ordering::Relaxed), 0);
+
+    let _: Retained<InitializeBase> = unsafe { msg_send_id![InitializeBase::class(), new] };
+    assert_eq!(INITIALIZED.load(Ordering::Relaxed), 1);
+
+    // The subclass inherits `+initialize`, but the body is not run again.
+    let _: Retained<InitializeSubclass> = unsafe { msg_send_id![InitializeSubclass::class(), new] };
+    let _: Retained<InitializeBase> = unsafe { msg_send_id![InitializeBase::class(), new] };
+    assert_eq!(INITIALIZED.load(Ordering::Relaxed), 1);
+}
+
 #[test]
 fn test_auto_traits() {
     struct NotSend(PhantomData<*mut usize>);
@@ -0,0 +1,54 @@
+#![cfg(feature = "unstable-msg-send-hook")]
+//! The message send hook is global, so it is tested in its own binary.
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use objc2::rc::Retained;
+use objc2::runtime::{set_msg_send_hook, AnyClass, MsgSendHook, NSObject, Sel};
+use objc2::{msg_send, msg_send_id, sel, ClassType};
+
+static SENT: Mutex<Vec<Sel>> = Mutex::new(Vec::new());
+
+struct RecordingHook;
+
+impl MsgSendHook for RecordingHook {
+    fn before(&self, _cls: &AnyClass, sel: Sel) {
+        if sel == sel!(panicInHook) {
+            panic!("panic in hook");
+        }
+    }
+
+    fn after(&self, cls: &AnyClass, sel: Sel, _duration: Duration) {
+        // Sending messages in here does not recurse.
+        let _: usize = unsafe { msg_send![cls, hash] };
+        if cls.name().to_bytes() == b"NSObject" {
+            SENT.lock().unwrap().push(sel);
+        }
+    }
+}
+
+#[test]
+fn test_hook() {
+    assert!(set_msg_send_hook(&RecordingHook));
+    assert!(!set_msg_send_hook(&RecordingHook));
+
+    let obj: Retained<NSObject> = unsafe { msg_send_id![NSObject::class(), new] };
+    let _: usize = unsafe { msg_send![&obj, hash] };
+    {
+        let sent = SENT.lock().unwrap();
+        assert!(sent.contains(&sel!(new)));
+        assert!(sent.contains(&sel!(hash)));
+        assert!(!sent.contains(&sel!(class)));
+    }
+
+    // The hook panics before the message is sent.
+    let res = catch_unwind(AssertUnwindSafe(|| {
+        let _: () = unsafe { msg_send![&obj, panicInHook] };
+    }));
+    assert!(res.is_err());
+
+    // The hook is still called after it has panicked.
+    let _: &AnyClass = unsafe { msg_send![&obj, class] };
+    assert!(SENT.lock().unwrap().contains(&sel!(class)));
+}
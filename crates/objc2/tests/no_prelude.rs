@@ -138,6 +138,20 @@ new_objc2::extern_protocol!(
     unsafe impl ProtocolType for dyn CustomProtocol {}
 );
 
+new_objc2::ns_enum! {
+    struct CustomEnum: new_objc2::ffi::NSInteger {
+        const A = 0;
+        const B = 1;
+    }
+}
+
+new_objc2::ns_options! {
+    struct CustomOptions: new_objc2::ffi::NSUInteger {
+        const A = 1 << 0;
+        const B = 1 << 1;
+    }
+}
+
 #[test]
 fn test_selector() {
     let _sel = new_objc2::sel!(abc);
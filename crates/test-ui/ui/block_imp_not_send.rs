@@ -0,0 +1,13 @@
+//! Method implementations can be called from any thread, so the closure
+//! must be `Send`.
+use std::rc::Rc;
+
+use block2::BlockImp;
+use objc2::runtime::AnyObject;
+
+fn main() {
+    let rc = Rc::new(());
+    let _ = BlockImp::from_closure(move |_this: &AnyObject| {
+        let _ = &rc;
+    });
+}
@@ -0,0 +1,13 @@
+//! Method implementations can be called from several threads at once, so
+//! the closure must be `Sync`.
+use std::cell::Cell;
+
+use block2::BlockImp;
+use objc2::runtime::AnyObject;
+
+fn main() {
+    let cell = Cell::new(0);
+    let _ = BlockImp::from_closure(move |_this: &AnyObject| {
+        cell.set(cell.get() + 1);
+    });
+}
@@ -0,0 +1,42 @@
+//! `+load` is never called on classes created with `define_class!`.
+use objc2::define_class;
+use objc2::runtime::NSObject;
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "HasLoad"]
+    struct HasLoad;
+
+    unsafe impl HasLoad {
+        #[method(load)]
+        fn load() {}
+    }
+);
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "HasLoadWithReturn"]
+    struct HasLoadWithReturn;
+
+    unsafe impl HasLoadWithReturn {
+        #[method(load)]
+        fn load() -> bool {
+            true
+        }
+    }
+);
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "HasLoadRetained"]
+    struct HasLoadRetained;
+
+    unsafe impl HasLoadRetained {
+        #[method_id(load)]
+        fn load() -> Option<objc2::rc::Retained<NSObject>> {
+            None
+        }
+    }
+);
+
+fn main() {}
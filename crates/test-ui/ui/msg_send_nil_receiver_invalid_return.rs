@@ -0,0 +1,12 @@
+//! Test that optional receivers can't return types that are invalid when
+//! zero-initialized.
+use core::ptr::NonNull;
+
+use objc2::msg_send;
+use objc2::runtime::{AnyObject, NSObject};
+
+fn main() {
+    let obj: Option<&NSObject> = None;
+    let _: &AnyObject = unsafe { msg_send![obj, self] };
+    let _: NonNull<AnyObject> = unsafe { msg_send![obj, self] };
+}
@@ -1,6 +1,5 @@
 //! Utilities for the `NSArray` and `NSMutableArray` classes.
 use alloc::vec::Vec;
-#[cfg(feature = "NSEnumerator")]
 use core::fmt;
 use core::mem;
 use core::ptr::NonNull;
@@ -157,24 +156,79 @@ impl<ObjectType: Message> NSArray<ObjectType> {
     ///
     /// The array must not be mutated while the returned references are alive.
     #[doc(alias = "getObjects:")]
+    #[doc(alias = "getObjects:range:")]
     pub unsafe fn to_vec_unchecked(&self) -> Vec<&ObjectType> {
         let len = self.count();
-        let mut vec: Vec<NonNull<ObjectType>> = Vec::with_capacity(len);
-        let ptr: NonNull<NonNull<ObjectType>> = NonNull::new(vec.as_mut_ptr()).unwrap();
 
-        // SAFETY: The buffer is at least the size of the array, as guaranteed
-        // by `Vec::with_capacity`.
+        #[cfg(feature = "NSRange")]
+        // SAFETY: The range is within the bounds of the array, and the
+        // caller upholds that the array isn't mutated.
         unsafe {
-            #[allow(deprecated)]
-            self.getObjects(ptr)
-        };
+            self.objects_in_range_unchecked(0..len)
+        }
+
+        #[cfg(not(feature = "NSRange"))]
+        {
+            let mut vec: Vec<NonNull<ObjectType>> = Vec::with_capacity(len);
+            let ptr: NonNull<NonNull<ObjectType>> = NonNull::new(vec.as_mut_ptr()).unwrap();
+
+            // SAFETY: The buffer is at least the size of the array, as
+            // guaranteed by `Vec::with_capacity`.
+            unsafe {
+                #[allow(deprecated)]
+                self.getObjects(ptr)
+            };
+
+            // SAFETY: The elements were just initialized by `getObjects:`.
+            //
+            // Note: We set the length _after_ we've copied the elements, so
+            // that if `getObjects:` unwinds, we don't end up deallocating
+            // uninitialized elements.
+            unsafe { vec.set_len(len) };
+
+            // SAFETY: `NonNull<ObjectType>` has the same layout as
+            // `&ObjectType`, and the lifetime is bound to the array, and
+            // caller upholds that the array isn't mutated.
+            unsafe { mem::transmute::<Vec<NonNull<ObjectType>>, Vec<&ObjectType>>(vec) }
+        }
+    }
+
+    /// A vector containing direct references to the objects within the
+    /// given range.
+    ///
+    /// This copies all the object pointers at once with
+    /// `getObjects:range:`, which is much faster than calling
+    /// `objectAtIndex:` for each element.
+    ///
+    /// Consider using the [`objects_in_range`](Self::objects_in_range)
+    /// method instead, unless you're seeing performance issues from the
+    /// retaining.
+    ///
+    /// # Safety
+    ///
+    /// The range must be within the bounds of the array, and the array must
+    /// not be mutated while the returned references are alive.
+    #[doc(alias = "getObjects:range:")]
+    #[cfg(feature = "NSRange")]
+    pub unsafe fn objects_in_range_unchecked(
+        &self,
+        range: core::ops::Range<usize>,
+    ) -> Vec<&ObjectType> {
+        let range = crate::NSRange::from(range);
+        let mut vec: Vec<NonNull<ObjectType>> = Vec::with_capacity(range.length);
+        let ptr: NonNull<NonNull<ObjectType>> = NonNull::new(vec.as_mut_ptr()).unwrap();
+
+        // SAFETY: The buffer is at least the size of the range, as
+        // guaranteed by `Vec::with_capacity`, and the caller upholds that
+        // the range is within the bounds of the array.
+        unsafe { self.getObjects_range(ptr, range) };
 
-        // SAFETY: The elements were just initialized by `getObjects:`.
+        // SAFETY: The elements were just initialized by `getObjects:range:`.
         //
         // Note: We set the length _after_ we've copied the elements, so that
-        // if `getObjects:` unwinds, we don't end up deallocating
+        // if `getObjects:range:` unwinds, we don't end up deallocating
         // uninitialized elements.
-        unsafe { vec.set_len(len) };
+        unsafe { vec.set_len(range.length) };
 
         // SAFETY: `NonNull<ObjectType>` has the same layout as `&ObjectType`,
         // and the lifetime is bound to the array, and caller upholds that the
@@ -214,6 +268,55 @@ impl<ObjectType: Message> NSArray<ObjectType> {
         self.len() == 0
     }
 
+    /// Get the object at the given index, or `None` if the index is out of
+    /// bounds.
+    ///
+    /// This is a bounds-checked version of [`objectAtIndex`]. Use
+    /// [`firstObject`] and [`lastObject`] to access the first and last
+    /// object in the array.
+    ///
+    /// [`objectAtIndex`]: Self::objectAtIndex
+    /// [`firstObject`]: Self::firstObject
+    /// [`lastObject`]: Self::lastObject
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSArray, ns_string};
+    ///
+    /// let array = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    /// assert_eq!(array.get(1).as_deref(), Some(ns_string!("b")));
+    /// assert_eq!(array.get(2), None);
+    /// ```
+    #[doc(alias = "objectAtIndex:")]
+    pub fn get(&self, index: usize) -> Option<Retained<ObjectType>> {
+        self.try_object_at(index).ok()
+    }
+
+    /// Get the object at the given index.
+    ///
+    /// The index is checked before calling `objectAtIndex:`, so that an
+    /// out-of-bounds index results in an error instead of Foundation throwing
+    /// an `NSRangeException`.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSArray, ns_string};
+    ///
+    /// let array = NSArray::from_slice(&[ns_string!("a")]);
+    /// assert_eq!(array.try_object_at(0).as_deref(), Ok(ns_string!("a")));
+    /// let err = array.try_object_at(1).unwrap_err();
+    /// assert_eq!(err.to_string(), "index 1 out of bounds for array of length 1");
+    /// ```
+    #[doc(alias = "objectAtIndex:")]
+    pub fn try_object_at(&self, index: usize) -> Result<Retained<ObjectType>, OutOfBoundsError> {
+        OutOfBoundsError::check_index(index, self.len())?;
+        Ok(self.objectAtIndex(index))
+    }
+
     /// Convert the array to a `Vec` of the array's objects.
     #[doc(alias = "getObjects:")]
     pub fn to_vec(&self) -> Vec<Retained<ObjectType>> {
@@ -238,14 +341,24 @@ impl<ObjectType: Message> NSArray<ObjectType> {
 
     /// Returns the objects within the given range.
     ///
+    /// This copies all the object pointers at once with
+    /// `getObjects:range:`, and then retains them.
+    ///
     /// # Panics
     ///
-    /// Panics if the range was out of bounds.
+    /// Panics if the range was out of bounds, or if its start is greater
+    /// than its end.
     #[doc(alias = "getObjects:range:")]
     #[cfg(feature = "NSRange")]
     pub fn objects_in_range(&self, range: core::ops::Range<usize>) -> Vec<Retained<ObjectType>> {
         let count = self.count();
 
+        if range.start > range.end {
+            panic!(
+                "range start index {} is greater than end index {}",
+                range.start, range.end
+            );
+        }
         // TODO: Replace this check with catching the thrown NSRangeException
         if range.end > count {
             panic!(
@@ -254,40 +367,190 @@ impl<ObjectType: Message> NSArray<ObjectType> {
             );
         }
 
-        let range = crate::NSRange::from(range);
-        let mut vec: Vec<NonNull<ObjectType>> = Vec::with_capacity(range.length);
-        let ptr: NonNull<NonNull<ObjectType>> = NonNull::new(vec.as_mut_ptr()).unwrap();
-
-        // SAFETY: Mostly the same as in `to_vec_unchecked`.
-        unsafe { self.getObjects_range(ptr, range) };
-        unsafe { vec.set_len(range.length) };
-        let vec = unsafe { mem::transmute::<Vec<NonNull<ObjectType>>, Vec<&ObjectType>>(vec) };
-
+        // SAFETY: The range was checked to be in bounds above, and we retain
+        // the elements below, see `to_vec` for details.
+        let vec = unsafe { self.objects_in_range_unchecked(range) };
         vec.into_iter().map(ObjectType::retain).collect()
     }
+
+    /// Call the closure with each index and object in the array, letting
+    /// Foundation spread the calls across several threads.
+    ///
+    /// The order in which the objects are visited is unspecified. This
+    /// returns once the closure has been called for every object.
+    ///
+    /// Since the objects are accessed from other threads, they must be
+    /// [`Sync`]. If the closure panics, the process is aborted.
+    ///
+    ///
+    /// # Examples
+    ///
+    #[cfg_attr(feature = "NSValue", doc = "```")]
+    #[cfg_attr(not(feature = "NSValue"), doc = "```ignore")]
+    /// use std::sync::atomic::{AtomicI32, Ordering};
+    /// use objc2_foundation::{NSArray, NSNumber};
+    ///
+    /// let array = NSArray::from_retained_slice(&[1, 2, 3].map(NSNumber::new_i32));
+    /// let sum = AtomicI32::new(0);
+    /// array.for_each_concurrent(|_, number| {
+    ///     sum.fetch_add(number.as_i32(), Ordering::Relaxed);
+    /// });
+    /// assert_eq!(sum.into_inner(), 6);
+    /// ```
+    #[cfg(feature = "block2")]
+    #[cfg(feature = "NSObjCRuntime")]
+    #[doc(alias = "enumerateObjectsWithOptions:usingBlock:")]
+    pub fn for_each_concurrent(&self, f: impl Fn(usize, &ObjectType) + Send + Sync)
+    where
+        ObjectType: Sync,
+    {
+        use block2::StackBlock;
+        use objc2::runtime::Bool;
+
+        let block = StackBlock::new(
+            |obj: NonNull<ObjectType>, index: usize, _stop: NonNull<Bool>| {
+                // SAFETY: The object is valid for the duration of the call.
+                let obj = unsafe { obj.as_ref() };
+                util::AbortOnUnwind::call(|| f(index, obj));
+            },
+        );
+        // SAFETY: The block only accesses the closure and the objects, which
+        // are both `Sync`, and the enumeration completes before the block is
+        // dropped.
+        unsafe {
+            self.enumerateObjectsWithOptions_usingBlock(
+                crate::NSEnumerationOptions::Concurrent,
+                &block,
+            )
+        };
+    }
 }
 
 /// Convenience mutation methods.
 impl<ObjectType: Message> NSMutableArray<ObjectType> {
     /// Insert an object into the array at the given index.
     ///
+    /// Inserting at `index == len` appends the object to the end of the
+    /// array.
+    ///
     /// # Panics
     ///
-    /// Panics if the index is out of bounds.
+    /// Panics if the index is greater than the length of the array.
     #[doc(alias = "insertObject:atIndex:")]
     pub fn insert(&self, index: usize, obj: &ObjectType) {
+        let len = self.len();
+        if self.try_insert(index, obj).is_err() {
+            panic!(
+                "insertion index (is {}) should be <= len (is {})",
+                index, len
+            );
+        }
+    }
+
+    /// Insert an object into the array at the given index.
+    ///
+    /// Returns an error instead of letting Foundation throw an
+    /// `NSRangeException` if the index is greater than the length of the
+    /// array.
+    #[doc(alias = "insertObject:atIndex:")]
+    pub fn try_insert(&self, index: usize, obj: &ObjectType) -> Result<(), OutOfBoundsError> {
+        // Inserting at the end is allowed.
+        OutOfBoundsError::check_range(index..index, self.len())?;
+        self.insertObject_atIndex(obj, index);
+        Ok(())
+    }
+
+    /// Remove the object at the given index, and return it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[doc(alias = "removeObjectAtIndex:")]
+    pub fn remove(&self, index: usize) -> Retained<ObjectType> {
+        let len = self.len();
+        match self.try_remove(index) {
+            Ok(obj) => obj,
+            Err(_) => panic!("removal index (is {}) should be < len (is {})", index, len),
+        }
+    }
+
+    /// Remove the object at the given index, and return it.
+    ///
+    /// Returns an error instead of letting Foundation throw an
+    /// `NSRangeException` if the index is out of bounds.
+    #[doc(alias = "removeObjectAtIndex:")]
+    pub fn try_remove(&self, index: usize) -> Result<Retained<ObjectType>, OutOfBoundsError> {
+        let obj = self.try_object_at(index)?;
+        self.removeObjectAtIndex(index);
+        Ok(obj)
+    }
+
+    /// Replace the objects in the given range with the given objects.
+    ///
+    /// The number of objects does not have to match the length of the range,
+    /// the array grows or shrinks as needed.
+    ///
+    /// Returns an error instead of letting Foundation throw an
+    /// `NSRangeException` if the range is out of bounds.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSMutableArray, ns_string};
+    ///
+    /// let array = NSMutableArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    /// array.try_replace_range(0..1, &[ns_string!("c"), ns_string!("d")]).unwrap();
+    /// assert_eq!(array.len(), 3);
+    /// assert!(array.try_replace_range(2..4, &[]).is_err());
+    /// ```
+    #[cfg(feature = "NSRange")]
+    #[doc(alias = "replaceObjectsInRange:withObjectsFromArray:")]
+    pub fn try_replace_range(
+        &self,
+        range: core::ops::Range<usize>,
+        objects: &[&ObjectType],
+    ) -> Result<(), OutOfBoundsError> {
+        OutOfBoundsError::check_range(range.clone(), self.len())?;
+        let objects = NSArray::from_slice(objects);
+        // SAFETY: The range was checked to be in bounds above, so this does
+        // not throw an exception.
+        unsafe { self.replaceObjectsInRange_withObjectsFromArray(range.into(), &objects) };
+        Ok(())
+    }
+
+    /// Replace the object at the given index with a new object, and return
+    /// the old one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[doc(alias = "replaceObjectAtIndex:withObject:")]
+    pub fn replace(&self, index: usize, obj: &ObjectType) -> Retained<ObjectType> {
         // TODO: Replace this check with catching the thrown NSRangeException
         let len = self.len();
         if index < len {
-            self.insertObject_atIndex(obj, index)
+            let old_obj = self.objectAtIndex(index);
+            self.replaceObjectAtIndex_withObject(index, obj);
+            old_obj
         } else {
             panic!(
-                "insertion index (is {}) should be <= len (is {})",
+                "replacement index (is {}) should be < len (is {})",
                 index, len
             );
         }
     }
 
+    /// Remove the last object from the array, and return it.
+    ///
+    /// Returns `None` if the array is empty.
+    #[doc(alias = "removeLastObject")]
+    pub fn pop(&self) -> Option<Retained<ObjectType>> {
+        let obj = self.lastObject()?;
+        self.removeLastObject();
+        Some(obj)
+    }
+
     /// Sort the array by the given comparison closure.
     #[cfg(feature = "NSObjCRuntime")]
     #[doc(alias = "sortUsingFunction:context:")]
@@ -325,6 +588,77 @@ impl<ObjectType: Message> NSMutableArray<ObjectType> {
     }
 }
 
+/// The error returned when an index or range is out of bounds of an array.
+///
+/// Returned by the `try_*` methods on [`NSArray`] and [`NSMutableArray`],
+/// which check their arguments up front instead of letting Foundation throw
+/// an `NSRangeException`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OutOfBoundsError {
+    range: core::ops::Range<usize>,
+    array_len: usize,
+}
+
+impl OutOfBoundsError {
+    fn check_index(index: usize, array_len: usize) -> Result<(), Self> {
+        if index < array_len {
+            Ok(())
+        } else {
+            Err(Self {
+                range: index..index.saturating_add(1),
+                array_len,
+            })
+        }
+    }
+
+    fn check_range(range: core::ops::Range<usize>, array_len: usize) -> Result<(), Self> {
+        if range.start <= range.end && range.end <= array_len {
+            Ok(())
+        } else {
+            Err(Self { range, array_len })
+        }
+    }
+
+    /// The index range that was accessed.
+    ///
+    /// For errors from single-index accesses, this is `index..index + 1`.
+    pub fn range(&self) -> core::ops::Range<usize> {
+        self.range.clone()
+    }
+
+    /// The length of the array at the time of the access.
+    pub fn array_len(&self) -> usize {
+        self.array_len
+    }
+}
+
+impl fmt::Display for OutOfBoundsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { range, array_len } = self;
+        if range.start > range.end {
+            write!(
+                f,
+                "range start index {} is greater than end index {}",
+                range.start, range.end
+            )
+        } else if range.end == range.start.saturating_add(1) {
+            write!(
+                f,
+                "index {} out of bounds for array of length {array_len}",
+                range.start
+            )
+        } else {
+            write!(
+                f,
+                "range {range:?} out of bounds for array of length {array_len}"
+            )
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutOfBoundsError {}
+
 #[cfg(feature = "NSEnumerator")]
 unsafe impl<ObjectType: Message> iter::FastEnumerationHelper for NSArray<ObjectType> {
     type Item = ObjectType;
@@ -416,15 +750,69 @@ impl<ObjectType: fmt::Debug + Message> fmt::Debug for NSMutableArray<ObjectType>
     }
 }
 
+/// The maximum number of objects that `Extend` buffers before adding them to
+/// the array.
+const EXTEND_CHUNK_SIZE: usize = 64;
+
+/// Pass the items to `add_chunk` in chunks of at most `EXTEND_CHUNK_SIZE`.
+fn for_each_chunk<T>(iter: impl IntoIterator<Item = T>, mut add_chunk: impl FnMut(&[T])) {
+    let mut iter = iter.into_iter();
+    let capacity = iter.size_hint().0.clamp(1, EXTEND_CHUNK_SIZE);
+    let mut chunk = Vec::with_capacity(capacity);
+    loop {
+        chunk.extend(iter.by_ref().take(EXTEND_CHUNK_SIZE));
+        if chunk.is_empty() {
+            break;
+        }
+        add_chunk(&chunk);
+        if chunk.len() < EXTEND_CHUNK_SIZE {
+            break;
+        }
+        chunk.clear();
+    }
+}
+
 impl<ObjectType: Message> Extend<Retained<ObjectType>> for &NSMutableArray<ObjectType> {
+    /// Add the objects to the end of the array.
+    ///
+    /// The objects are added in batches with `addObjectsFromArray:`, which
+    /// is faster than calling `addObject:` for each of them.
+    #[doc(alias = "addObjectsFromArray:")]
     fn extend<I: IntoIterator<Item = Retained<ObjectType>>>(&mut self, iter: I) {
-        iter.into_iter().for_each(move |item| self.addObject(&item));
+        for_each_chunk(iter, |chunk| match chunk {
+            [obj] => self.addObject(obj),
+            _ => self.addObjectsFromArray(&NSArray::from_retained_slice(chunk)),
+        });
     }
 }
 
 impl<'a, ObjectType: Message> Extend<&'a ObjectType> for &NSMutableArray<ObjectType> {
+    /// Add the objects to the end of the array.
+    ///
+    /// See the `Extend<Retained<ObjectType>>` implementation for details.
+    #[doc(alias = "addObjectsFromArray:")]
     fn extend<I: IntoIterator<Item = &'a ObjectType>>(&mut self, iter: I) {
-        iter.into_iter().for_each(move |item| self.addObject(item));
+        for_each_chunk(iter, |chunk| match chunk {
+            [obj] => self.addObject(obj),
+            _ => self.addObjectsFromArray(&NSArray::from_slice(chunk)),
+        });
+    }
+}
+
+impl<ObjectType: Message> From<&NSArray<ObjectType>> for Vec<Retained<ObjectType>> {
+    /// Convert the array to a `Vec`.
+    ///
+    /// See [`NSArray::to_vec`].
+    #[inline]
+    fn from(array: &NSArray<ObjectType>) -> Self {
+        array.to_vec()
+    }
+}
+
+impl<ObjectType: Message> From<&NSMutableArray<ObjectType>> for Vec<Retained<ObjectType>> {
+    #[inline]
+    fn from(array: &NSMutableArray<ObjectType>) -> Self {
+        array.to_vec()
     }
 }
 
@@ -461,3 +849,17 @@ impl<ObjectType: Message> RetainedFromIterator<Retained<ObjectType>>
         Self::from_retained_slice(&vec)
     }
 }
+
+impl<ObjectType: Message> PartialEq<NSArray<ObjectType>> for NSMutableArray<ObjectType> {
+    #[inline]
+    fn eq(&self, other: &NSArray<ObjectType>) -> bool {
+        PartialEq::eq(&**self, other)
+    }
+}
+
+impl<ObjectType: Message> PartialEq<NSMutableArray<ObjectType>> for NSArray<ObjectType> {
+    #[inline]
+    fn eq(&self, other: &NSMutableArray<ObjectType>) -> bool {
+        PartialEq::eq(self, &**other)
+    }
+}
@@ -50,3 +50,17 @@ impl NSMutableAttributedString {
         Self::initWithAttributedString(Self::alloc(), attributed_string)
     }
 }
+
+impl PartialEq<NSAttributedString> for NSMutableAttributedString {
+    #[inline]
+    fn eq(&self, other: &NSAttributedString) -> bool {
+        PartialEq::eq(&**self, other)
+    }
+}
+
+impl PartialEq<NSMutableAttributedString> for NSAttributedString {
+    #[inline]
+    fn eq(&self, other: &NSMutableAttributedString) -> bool {
+        PartialEq::eq(self, &**other)
+    }
+}
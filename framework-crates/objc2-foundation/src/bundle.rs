@@ -9,8 +9,65 @@ impl NSBundle {
     #[cfg(feature = "NSString")]
     #[cfg(feature = "NSDictionary")]
     pub fn name(&self) -> Option<objc2::rc::Retained<crate::NSString>> {
-        let info = self.infoDictionary()?;
-        let name = info.objectForKey(crate::ns_string!("CFBundleName"))?;
-        Some(name.downcast().expect("CFBundleName to be NSString"))
+        let name = self.info_value(crate::ns_string!("CFBundleName"))?;
+        Some(name.expect("CFBundleName to be NSString"))
+    }
+
+    /// Look up a value in the bundle's `Info.plist`, and attempt to downcast
+    /// it to the given type.
+    ///
+    /// Localized values are preferred over unlocalized ones.
+    ///
+    /// Returns `None` if the key is not present, and `Some(None)` if the
+    /// value is not of the expected type.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSBundle, NSString};
+    ///
+    /// let bundle = NSBundle::mainBundle();
+    /// let version: Option<Option<_>> =
+    ///     bundle.info_value::<NSString>(ns_string!("CFBundleShortVersionString"));
+    /// ```
+    #[cfg(feature = "NSString")]
+    #[doc(alias = "objectForInfoDictionaryKey:")]
+    pub fn info_value<T: objc2::DowncastTarget>(
+        &self,
+        key: &crate::NSString,
+    ) -> Option<Option<objc2::rc::Retained<T>>> {
+        let value = self.objectForInfoDictionaryKey(key)?;
+        Some(value.downcast().ok())
+    }
+
+    /// The URL of the resource with the given name and file extension, if
+    /// it exists in the bundle.
+    ///
+    /// For localized resources, the URL for the user's preferred
+    /// localization is returned.
+    #[cfg(feature = "NSString")]
+    #[cfg(feature = "NSURL")]
+    #[doc(alias = "URLForResource:withExtension:")]
+    pub fn resource_url(
+        &self,
+        name: &crate::NSString,
+        extension: Option<&crate::NSString>,
+    ) -> Option<objc2::rc::Retained<crate::NSURL>> {
+        self.URLForResource_withExtension(Some(name), extension)
+    }
+
+    /// The localized version of the string with the given key, looked up in
+    /// the given strings table (or `Localizable.strings` if `None`).
+    ///
+    /// If the key is not found, the key itself is returned.
+    #[cfg(feature = "NSString")]
+    #[doc(alias = "localizedStringForKey:value:table:")]
+    pub fn localized_string(
+        &self,
+        key: &crate::NSString,
+        table: Option<&crate::NSString>,
+    ) -> objc2::rc::Retained<crate::NSString> {
+        self.localizedStringForKey_value_table(key, None, table)
     }
 }
@@ -0,0 +1,85 @@
+//! Utilities for the `NSCache` class.
+use alloc::boxed::Box;
+
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, NSObject, NSObjectProtocol, ProtocolObject};
+use objc2::{define_class, msg_send_id, AllocAnyThread, DefinedClass, Message};
+
+use crate::util::retain_delegate;
+use crate::{NSCache, NSCacheDelegate};
+
+type EvictionHandler = Box<dyn Fn(&AnyObject) + Send + Sync + 'static>;
+
+define_class!(
+    // SAFETY:
+    // - The superclass NSObject does not have any subclassing requirements.
+    // - `EvictionDelegate` does not implement `Drop`.
+    #[unsafe(super(NSObject))]
+    #[name = "Objc2CacheEvictionDelegate"]
+    #[ivars = EvictionHandler]
+    struct EvictionDelegate;
+
+    unsafe impl NSObjectProtocol for EvictionDelegate {}
+
+    unsafe impl NSCacheDelegate for EvictionDelegate {
+        #[method(cache:willEvictObject:)]
+        fn will_evict_object(&self, _cache: &NSCache, object: &AnyObject) {
+            (self.ivars())(object);
+        }
+    }
+);
+
+impl<KeyType: Message, ObjectType: Message> NSCache<KeyType, ObjectType> {
+    /// Removes the object at the given key from the cache, returning it if
+    /// it was present.
+    #[doc(alias = "removeObjectForKey:")]
+    pub fn remove(&self, key: &KeyType) -> Option<Retained<ObjectType>> {
+        let object = self.objectForKey(key)?;
+        self.removeObjectForKey(key);
+        Some(object)
+    }
+
+    /// Set a closure to be called whenever an object is about to be evicted
+    /// from the cache, or removed from it explicitly.
+    ///
+    /// The closure may be called on any thread, e.g. in response to memory
+    /// pressure, and must not modify the cache.
+    ///
+    /// This replaces the cache's delegate. The closure is kept alive for as
+    /// long as the cache is, so beware of capturing the cache in it, as that
+    /// creates a reference cycle.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSCache, NSString};
+    ///
+    /// let cache = NSCache::<NSString, NSString>::new();
+    /// cache.set_eviction_handler(|value| {
+    ///     println!("evicting {value}");
+    /// });
+    /// cache.setObject_forKey(ns_string!("value"), ns_string!("key"));
+    /// cache.removeAllObjects();
+    /// ```
+    #[doc(alias = "setDelegate:")]
+    #[doc(alias = "cache:willEvictObject:")]
+    pub fn set_eviction_handler(&self, handler: impl Fn(&ObjectType) + Send + Sync + 'static)
+    where
+        ObjectType: 'static,
+    {
+        let handler: EvictionHandler = Box::new(move |object: &AnyObject| {
+            let object: *const AnyObject = object;
+            // SAFETY: The cache only contains objects of type `ObjectType`.
+            handler(unsafe { &*object.cast::<ObjectType>() })
+        });
+        let delegate = EvictionDelegate::alloc().set_ivars(handler);
+        let delegate: Retained<EvictionDelegate> = unsafe { msg_send_id![super(delegate), init] };
+        // The cache's `delegate` property is `assign`, so we make the cache
+        // keep the delegate alive instead.
+        retain_delegate(self, &delegate);
+        let delegate = ProtocolObject::<dyn NSCacheDelegate>::from_retained(delegate);
+        // SAFETY: The delegate lives for as long as the cache.
+        unsafe { self.setDelegate(Some(&delegate)) };
+    }
+}
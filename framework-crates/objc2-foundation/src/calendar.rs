@@ -0,0 +1,62 @@
+//! Utilities for the `NSCalendar` and `NSDateComponents` classes.
+use objc2::rc::Retained;
+
+use crate::{NSCalendar, NSCalendarUnit, NSDateComponents};
+
+/// `NSDateComponentUndefined`, used to signal that a component is not set.
+const UNDEFINED: isize = isize::MAX;
+
+/// Convenience creation methods.
+impl NSCalendar {
+    /// The Gregorian calendar.
+    ///
+    /// Unlike [`currentCalendar`], this is independent of the user's
+    /// settings, which makes it useful for computations on dates meant for
+    /// machine consumption.
+    ///
+    /// [`currentCalendar`]: Self::currentCalendar
+    #[cfg(feature = "NSString")]
+    #[doc(alias = "NSCalendarIdentifierGregorian")]
+    pub fn gregorian() -> Retained<Self> {
+        // SAFETY: The identifier is a valid calendar identifier.
+        let identifier = unsafe { crate::NSCalendarIdentifierGregorian };
+        Self::calendarWithIdentifier(identifier)
+            .expect("the Gregorian calendar should always be available")
+    }
+}
+
+/// Typed component accessors.
+impl NSDateComponents {
+    /// The value of the given component, or `None` if it is not set.
+    ///
+    /// Only a single component unit may be given.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSCalendarUnit, NSDateComponents};
+    ///
+    /// let components = NSDateComponents::new();
+    /// components.set(NSCalendarUnit::Year, Some(2024));
+    /// assert_eq!(components.get(NSCalendarUnit::Year), Some(2024));
+    /// assert_eq!(components.get(NSCalendarUnit::Month), None);
+    /// ```
+    #[doc(alias = "valueForComponent:")]
+    pub fn get(&self, unit: NSCalendarUnit) -> Option<isize> {
+        let value = self.valueForComponent(unit);
+        if value == UNDEFINED {
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    /// Set or clear the value of the given component.
+    ///
+    /// Only a single component unit may be given.
+    #[doc(alias = "setValue:forComponent:")]
+    pub fn set(&self, unit: NSCalendarUnit, value: Option<isize>) {
+        self.setValue_forComponent(value.unwrap_or(UNDEFINED), unit);
+    }
+}
@@ -0,0 +1,77 @@
+//! Utilities for the `NSCoder` class.
+//!
+//! Classes defined with [`define_class!`] can support archiving by
+//! implementing the `NSCoding` protocol (and usually `NSSecureCoding`),
+//! encoding and decoding their instance variables with the keyed coder
+//! methods.
+//!
+//! [`define_class!`]: objc2::define_class
+//!
+//!
+//! # Examples
+//!
+//! ```
+//! use objc2::rc::{Allocated, Retained};
+//! use objc2::runtime::NSObject;
+//! use objc2::{define_class, msg_send_id, DefinedClass};
+//! use objc2_foundation::{ns_string, NSCoder, NSCoding, NSObjectProtocol, NSSecureCoding, NSString};
+//!
+//! struct Ivars {
+//!     name: Retained<NSString>,
+//!     count: i64,
+//! }
+//!
+//! define_class!(
+//!     // SAFETY:
+//!     // - The superclass NSObject does not have any subclassing requirements.
+//!     // - `Item` does not implement `Drop`.
+//!     #[unsafe(super(NSObject))]
+//!     #[name = "Item"]
+//!     #[ivars = Ivars]
+//!     struct Item;
+//!
+//!     unsafe impl NSObjectProtocol for Item {}
+//!
+//!     unsafe impl NSCoding for Item {
+//!         #[method(encodeWithCoder:)]
+//!         fn encode_with_coder(&self, coder: &NSCoder) {
+//!             coder.encodeObject_forKey(Some(&self.ivars().name), ns_string!("name"));
+//!             coder.encodeInt64_forKey(self.ivars().count, ns_string!("count"));
+//!         }
+//!
+//!         #[method_id(initWithCoder:)]
+//!         fn init_with_coder(this: Allocated<Self>, coder: &NSCoder) -> Option<Retained<Self>> {
+//!             let name = coder.decode_object::<NSString>(ns_string!("name"))?;
+//!             let count = coder.decodeInt64ForKey(ns_string!("count"));
+//!             let this = this.set_ivars(Ivars { name, count });
+//!             unsafe { msg_send_id![super(this), init] }
+//!         }
+//!     }
+//!
+//!     unsafe impl NSSecureCoding for Item {
+//!         #[method(supportsSecureCoding)]
+//!         fn supports_secure_coding() -> bool {
+//!             true
+//!         }
+//!     }
+//! );
+//! ```
+use objc2::rc::Retained;
+use objc2::DowncastTarget;
+
+use crate::{NSCoder, NSString};
+
+impl NSCoder {
+    /// Decode an object of type `T` that was previously encoded with
+    /// `encodeObject:forKey:`.
+    ///
+    /// Returns `None` if no value was encoded for the key, or if the
+    /// decoded object is not of type `T`. With secure coding, a failure
+    /// is also recorded in the coder's `error`.
+    #[doc(alias = "decodeObjectOfClass:forKey:")]
+    pub fn decode_object<T: DowncastTarget>(&self, key: &NSString) -> Option<Retained<T>> {
+        // SAFETY: The class is a valid class object.
+        let object = unsafe { self.decodeObjectOfClass_forKey(T::class(), key) }?;
+        object.downcast::<T>().ok()
+    }
+}
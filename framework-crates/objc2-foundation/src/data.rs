@@ -350,3 +350,17 @@ unsafe fn with_vec<T: objc2::Message>(obj: objc2::rc::Allocated<T>, bytes: Vec<u
         ]
     }
 }
+
+impl PartialEq<NSData> for NSMutableData {
+    #[inline]
+    fn eq(&self, other: &NSData) -> bool {
+        PartialEq::eq(&**self, other)
+    }
+}
+
+impl PartialEq<NSMutableData> for NSData {
+    #[inline]
+    fn eq(&self, other: &NSMutableData) -> bool {
+        PartialEq::eq(self, &**other)
+    }
+}
@@ -0,0 +1,115 @@
+//! Utilities for the `NSDate` class.
+#[cfg(feature = "NSObjCRuntime")]
+use core::cmp::Ordering;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use objc2::rc::Retained;
+
+use crate::NSDate;
+
+/// Conversions to and from [`SystemTime`].
+#[cfg(feature = "std")]
+impl NSDate {
+    /// Create a new date from the given system time.
+    ///
+    /// Note that `NSDate` stores the time as a floating point number of
+    /// seconds, so sub-microsecond precision may be lost.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use objc2_foundation::NSDate;
+    ///
+    /// let date = NSDate::from_system_time(UNIX_EPOCH + Duration::from_secs(60));
+    /// assert_eq!(date.timeIntervalSince1970(), 60.0);
+    /// ```
+    #[doc(alias = "dateWithTimeIntervalSince1970:")]
+    pub fn from_system_time(time: SystemTime) -> Retained<Self> {
+        let interval = match time.duration_since(UNIX_EPOCH) {
+            Ok(duration) => duration.as_secs_f64(),
+            Err(err) => -err.duration().as_secs_f64(),
+        };
+        Self::dateWithTimeIntervalSince1970(interval)
+    }
+
+    /// Convert the date to a system time.
+    ///
+    /// Returns `None` if the date cannot be represented as a `SystemTime`,
+    /// for example [`distantFuture`] on some platforms.
+    ///
+    /// [`distantFuture`]: Self::distantFuture
+    #[doc(alias = "timeIntervalSince1970")]
+    pub fn to_system_time(&self) -> Option<SystemTime> {
+        let interval = self.timeIntervalSince1970();
+        let duration = Duration::try_from_secs_f64(interval.abs()).ok()?;
+        if interval.is_sign_negative() {
+            UNIX_EPOCH.checked_sub(duration)
+        } else {
+            UNIX_EPOCH.checked_add(duration)
+        }
+    }
+}
+
+/// Arithmetic with [`Duration`].
+impl NSDate {
+    /// A new date that is the given duration after this date.
+    #[doc(alias = "dateByAddingTimeInterval:")]
+    pub fn add_duration(&self, duration: Duration) -> Retained<Self> {
+        self.dateByAddingTimeInterval(duration.as_secs_f64())
+    }
+
+    /// A new date that is the given duration before this date.
+    #[doc(alias = "dateByAddingTimeInterval:")]
+    pub fn sub_duration(&self, duration: Duration) -> Retained<Self> {
+        self.dateByAddingTimeInterval(-duration.as_secs_f64())
+    }
+
+    /// The amount of time elapsed from `earlier` to this date.
+    ///
+    /// Returns `None` if `earlier` is later than this date.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use objc2_foundation::NSDate;
+    ///
+    /// let earlier = NSDate::now();
+    /// let later = earlier.add_duration(Duration::from_secs(2));
+    /// assert_eq!(later.duration_since(&earlier), Some(Duration::from_secs(2)));
+    /// assert_eq!(earlier.duration_since(&later), None);
+    /// ```
+    #[doc(alias = "timeIntervalSinceDate:")]
+    pub fn duration_since(&self, earlier: &Self) -> Option<Duration> {
+        let interval = self.timeIntervalSinceDate(earlier);
+        if interval.is_sign_negative() {
+            None
+        } else {
+            Duration::try_from_secs_f64(interval).ok()
+        }
+    }
+}
+
+/// Compares the dates with the Objective-C method "compare:".
+#[cfg(feature = "NSObjCRuntime")]
+impl PartialOrd for NSDate {
+    #[doc(alias = "compare:")]
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the dates with the Objective-C method "compare:".
+#[cfg(feature = "NSObjCRuntime")]
+impl Ord for NSDate {
+    #[doc(alias = "compare:")]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).into()
+    }
+}
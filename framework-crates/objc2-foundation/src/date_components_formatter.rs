@@ -0,0 +1,34 @@
+//! Utilities for the `NSDateComponentsFormatter` class.
+use core::time::Duration;
+
+use objc2::rc::Retained;
+
+use crate::{NSDateComponentsFormatter, NSString};
+
+/// Formatting methods.
+impl NSDateComponentsFormatter {
+    /// Format the given duration.
+    ///
+    /// Returns `None` if the duration could not be formatted.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use objc2_foundation::{
+    ///     NSCalendarUnit, NSDateComponentsFormatter, NSDateComponentsFormatterUnitsStyle,
+    /// };
+    ///
+    /// let formatter = NSDateComponentsFormatter::new();
+    /// formatter.setUnitsStyle(NSDateComponentsFormatterUnitsStyle::Positional);
+    /// formatter.setAllowedUnits(NSCalendarUnit::Hour | NSCalendarUnit::Minute | NSCalendarUnit::Second);
+    ///
+    /// let string = formatter.format_duration(Duration::from_secs(3725)).unwrap();
+    /// assert_eq!(string.to_string(), "1:02:05");
+    /// ```
+    #[doc(alias = "stringFromTimeInterval:")]
+    pub fn format_duration(&self, duration: Duration) -> Option<Retained<NSString>> {
+        self.stringFromTimeInterval(duration.as_secs_f64())
+    }
+}
@@ -0,0 +1,82 @@
+//! Utilities for the `NSDateFormatter` and `NSISO8601DateFormatter` classes.
+use objc2::rc::Retained;
+
+use crate::NSDateFormatter;
+
+/// Convenience creation methods.
+impl NSDateFormatter {
+    /// Create a new date formatter with the given fixed format string, such
+    /// as `"yyyy-MM-dd HH:mm:ss"`.
+    ///
+    /// The locale is set to `en_US_POSIX`, which ensures that the format is
+    /// interpreted the same regardless of the user's locale settings. This
+    /// is what you want when parsing or producing dates for machine
+    /// consumption; use [`with_styles`] for user-visible dates instead.
+    ///
+    /// [`with_styles`]: Self::with_styles
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSDate, NSDateFormatter, NSTimeZone};
+    ///
+    /// let formatter = NSDateFormatter::with_fixed_format(ns_string!("yyyy-MM-dd HH:mm"));
+    /// formatter.setTimeZone(Some(&NSTimeZone::timeZoneForSecondsFromGMT(0)));
+    ///
+    /// let date = NSDate::dateWithTimeIntervalSince1970(90.0);
+    /// assert_eq!(formatter.stringFromDate(&date).to_string(), "1970-01-01 00:01");
+    ///
+    /// let parsed = formatter.dateFromString(ns_string!("1970-01-01 00:01")).unwrap();
+    /// assert_eq!(parsed.timeIntervalSince1970(), 60.0);
+    /// ```
+    #[cfg(feature = "NSLocale")]
+    #[cfg(feature = "NSString")]
+    #[doc(alias = "setDateFormat:")]
+    pub fn with_fixed_format(format: &crate::NSString) -> Retained<Self> {
+        let formatter = Self::new();
+        formatter.setLocale(Some(&crate::NSLocale::posix()));
+        formatter.setDateFormat(Some(format));
+        formatter
+    }
+
+    /// Create a new date formatter with the given date and time styles,
+    /// using the user's current locale.
+    #[doc(alias = "setDateStyle:")]
+    #[doc(alias = "setTimeStyle:")]
+    pub fn with_styles(
+        date_style: crate::NSDateFormatterStyle,
+        time_style: crate::NSDateFormatterStyle,
+    ) -> Retained<Self> {
+        let formatter = Self::new();
+        formatter.setDateStyle(date_style);
+        formatter.setTimeStyle(time_style);
+        formatter
+    }
+}
+
+/// Convenience creation methods.
+#[cfg(feature = "NSISO8601DateFormatter")]
+impl crate::NSISO8601DateFormatter {
+    /// Create a new ISO 8601 date formatter with the given options.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSDate, NSISO8601DateFormatOptions, NSISO8601DateFormatter};
+    ///
+    /// let formatter = NSISO8601DateFormatter::with_options(
+    ///     NSISO8601DateFormatOptions::WithInternetDateTime
+    ///         | NSISO8601DateFormatOptions::WithFractionalSeconds,
+    /// );
+    /// let date = NSDate::dateWithTimeIntervalSince1970(1.5);
+    /// assert_eq!(formatter.stringFromDate(&date).to_string(), "1970-01-01T00:00:01.500Z");
+    /// ```
+    #[doc(alias = "setFormatOptions:")]
+    pub fn with_options(options: crate::NSISO8601DateFormatOptions) -> Retained<Self> {
+        let formatter = Self::new();
+        formatter.setFormatOptions(options);
+        formatter
+    }
+}
@@ -0,0 +1,102 @@
+//! Utilities for the `NSDecimalNumber` class.
+//!
+//! The arithmetic operators are implemented on references, and return a new
+//! retained number. Like in Objective-C, overflow, underflow and division by
+//! zero raise an exception.
+#[cfg(feature = "NSObjCRuntime")]
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+
+use crate::{NSDecimalNumber, NSDecimalNumberHandler, NSRoundingMode};
+
+/// Rounding methods.
+impl NSDecimalNumber {
+    /// Round the number to the given number of digits after the decimal
+    /// point, using the given rounding mode.
+    ///
+    /// A negative scale rounds to the left of the decimal point.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSDecimalNumber, NSRoundingMode};
+    ///
+    /// let number = NSDecimalNumber::decimalNumberWithString(ns_string!("2.345"));
+    /// let rounded = number.round(2, NSRoundingMode::Bankers);
+    /// assert_eq!(rounded, NSDecimalNumber::decimalNumberWithString(ns_string!("2.34")));
+    /// ```
+    #[doc(alias = "decimalNumberByRoundingAccordingToBehavior:")]
+    pub fn round(&self, scale: i16, mode: NSRoundingMode) -> Retained<Self> {
+        let behavior =
+            NSDecimalNumberHandler::decimalNumberHandlerWithRoundingMode_scale_raiseOnExactness_raiseOnOverflow_raiseOnUnderflow_raiseOnDivideByZero(
+                mode, scale, false, true, true, true,
+            );
+        self.decimalNumberByRoundingAccordingToBehavior(Some(ProtocolObject::from_ref(&*behavior)))
+    }
+}
+
+macro_rules! impl_op {
+    ($trait:ident, $fn_name:ident, $method:ident, $alias:literal) => {
+        impl $trait<&NSDecimalNumber> for &NSDecimalNumber {
+            type Output = Retained<NSDecimalNumber>;
+
+            #[doc(alias = $alias)]
+            #[inline]
+            fn $fn_name(self, rhs: &NSDecimalNumber) -> Self::Output {
+                self.$method(rhs)
+            }
+        }
+    };
+}
+
+impl_op!(Add, add, decimalNumberByAdding, "decimalNumberByAdding:");
+impl_op!(
+    Sub,
+    sub,
+    decimalNumberBySubtracting,
+    "decimalNumberBySubtracting:"
+);
+impl_op!(
+    Mul,
+    mul,
+    decimalNumberByMultiplyingBy,
+    "decimalNumberByMultiplyingBy:"
+);
+impl_op!(
+    Div,
+    div,
+    decimalNumberByDividingBy,
+    "decimalNumberByDividingBy:"
+);
+
+impl Neg for &NSDecimalNumber {
+    type Output = Retained<NSDecimalNumber>;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        NSDecimalNumber::zero().decimalNumberBySubtracting(self)
+    }
+}
+
+/// Compares the numbers with the Objective-C method "compare:".
+#[cfg(feature = "NSObjCRuntime")]
+impl PartialOrd for NSDecimalNumber {
+    #[doc(alias = "compare:")]
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the numbers with the Objective-C method "compare:".
+#[cfg(feature = "NSObjCRuntime")]
+impl Ord for NSDecimalNumber {
+    #[doc(alias = "compare:")]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).into()
+    }
+}
@@ -1,12 +1,18 @@
 //! Utilities for the `NSDictionary` and `NSMutableDictionary` classes.
 use alloc::vec::Vec;
 use core::fmt;
+#[cfg(feature = "std")]
+use core::hash::Hash;
 use core::mem;
 use core::ptr::NonNull;
-use objc2::msg_send;
+use objc2::{msg_send, msg_send_id};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 use objc2::rc::Retained;
 #[cfg(feature = "NSObject")]
+use objc2::rc::RetainedFromIterator;
+#[cfg(feature = "NSObject")]
 use objc2::runtime::ProtocolObject;
 #[cfg(feature = "NSObject")]
 use objc2::AllocAnyThread;
@@ -215,20 +221,19 @@ impl<KeyType: Message, ObjectType: Message> NSDictionary<KeyType, ObjectType> {
     /// The dictionary must not be mutated while the returned references are
     /// alive.
     #[doc(alias = "getObjects:andKeys:")]
+    #[doc(alias = "getObjects:andKeys:count:")]
     pub unsafe fn to_vecs_unchecked(&self) -> (Vec<&KeyType>, Vec<&ObjectType>) {
         let len = self.len();
         let mut keys = Vec::with_capacity(len);
         let mut objs = Vec::with_capacity(len);
 
-        // SAFETY: The pointers are valid.
-        unsafe {
-            // No reason to use `getObjects:andKeys:count:`, the dictionary is
-            // not thread safe, so we know it won't change within this scope.
-            #[allow(deprecated)]
-            self.getObjects_andKeys(objs.as_mut_ptr(), keys.as_mut_ptr());
-        }
+        // SAFETY: The pointers are valid, and the buffers have space for
+        // `len` elements.
+        unsafe { self.getObjects_andKeys_count(objs.as_mut_ptr(), keys.as_mut_ptr(), len) };
 
-        // SAFETY: The vecs were just initialized by `getObjects:andKeys:`.
+        // SAFETY: The vecs were just initialized by
+        // `getObjects:andKeys:count:`, and the caller upholds that the
+        // dictionary isn't mutated, so it still has `len` elements.
         unsafe {
             keys.set_len(len);
             objs.set_len(len);
@@ -313,7 +318,7 @@ impl<KeyType: Message, ObjectType: Message> NSDictionary<KeyType, ObjectType> {
     ///     assert_eq!(key, obj);
     /// }
     /// ```
-    #[doc(alias = "getObjects:")]
+    #[doc(alias = "getObjects:andKeys:count:")]
     pub fn to_vecs(&self) -> (Vec<Retained<KeyType>>, Vec<Retained<ObjectType>>) {
         // SAFETY: We retain the elements below, so that we know that the
         // dictionary isn't mutated while they are alive.
@@ -324,6 +329,85 @@ impl<KeyType: Message, ObjectType: Message> NSDictionary<KeyType, ObjectType> {
         )
     }
 
+    /// A vector of the dictionary's key-object pairs.
+    ///
+    /// This fetches all the keys and objects at once with
+    /// `getObjects:andKeys:count:`, which is much faster than looking up
+    /// each object by key.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2_foundation::{NSDictionary, ns_string};
+    ///
+    /// let dict = NSDictionary::from_slices(&[ns_string!("a")], &[ns_string!("b")]);
+    /// let pairs = dict.keys_and_objects();
+    /// assert_eq!(pairs.len(), 1);
+    /// assert_eq!(&*pairs[0].0, ns_string!("a"));
+    /// assert_eq!(&*pairs[0].1, ns_string!("b"));
+    /// ```
+    #[doc(alias = "getObjects:andKeys:count:")]
+    pub fn keys_and_objects(&self) -> Vec<(Retained<KeyType>, Retained<ObjectType>)> {
+        // SAFETY: We retain the elements below, so that we know that the
+        // dictionary isn't mutated while they are alive.
+        let (keys, objects) = unsafe { self.to_vecs_unchecked() };
+        keys.into_iter()
+            .zip(objects)
+            .map(|(key, object)| (key.retain(), object.retain()))
+            .collect()
+    }
+
+    /// Convert the dictionary to a `HashMap`.
+    ///
+    /// See the [`From`] implementation on `HashMap` for details.
+    #[cfg(feature = "std")]
+    #[doc(alias = "getObjects:andKeys:count:")]
+    pub fn to_hashmap(&self) -> HashMap<Retained<KeyType>, Retained<ObjectType>>
+    where
+        KeyType: Eq + Hash,
+    {
+        self.into()
+    }
+
+    /// Call the closure with each key and object in the dictionary, letting
+    /// Foundation spread the calls across several threads.
+    ///
+    /// The order in which the entries are visited is unspecified. This
+    /// returns once the closure has been called for every entry.
+    ///
+    /// Since the keys and objects are accessed from other threads, they must
+    /// be [`Sync`]. If the closure panics, the process is aborted.
+    #[cfg(feature = "block2")]
+    #[cfg(feature = "NSObjCRuntime")]
+    #[doc(alias = "enumerateKeysAndObjectsWithOptions:usingBlock:")]
+    pub fn for_each_concurrent(&self, f: impl Fn(&KeyType, &ObjectType) + Send + Sync)
+    where
+        KeyType: Sync,
+        ObjectType: Sync,
+    {
+        use block2::StackBlock;
+        use objc2::runtime::Bool;
+
+        let block = StackBlock::new(
+            |key: NonNull<KeyType>, obj: NonNull<ObjectType>, _stop: NonNull<Bool>| {
+                // SAFETY: The key and object are valid for the duration of
+                // the call.
+                let (key, obj) = unsafe { (key.as_ref(), obj.as_ref()) };
+                crate::util::AbortOnUnwind::call(|| f(key, obj));
+            },
+        );
+        // SAFETY: The block only accesses the closure, the keys and the
+        // objects, which are all `Sync`, and the enumeration completes before
+        // the block is dropped.
+        unsafe {
+            self.enumerateKeysAndObjectsWithOptions_usingBlock(
+                crate::NSEnumerationOptions::Concurrent,
+                &block,
+            )
+        };
+    }
+
     /// Iterate over the dictionary's keys.
     #[cfg(feature = "NSEnumerator")]
     #[doc(alias = "keyEnumerator")]
@@ -356,6 +440,31 @@ impl<KeyType: Message, ObjectType: Message> NSDictionary<KeyType, ObjectType> {
         // SAFETY: The enumerator came from the dictionary.
         Objects(unsafe { iter::IterWithBackingEnum::new(self, enumerator) })
     }
+
+    /// Iterate over the dictionary's key-value pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSDictionary, ns_string};
+    ///
+    /// let dict = NSDictionary::from_slices(
+    ///     &[ns_string!("a"), ns_string!("b")],
+    ///     &[ns_string!("a"), ns_string!("b")],
+    /// );
+    /// for (key, obj) in dict.iter() {
+    ///     assert_eq!(key, obj);
+    /// }
+    /// ```
+    #[cfg(feature = "NSEnumerator")]
+    #[doc(alias = "keyEnumerator")]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, KeyType, ObjectType> {
+        Iter {
+            keys: self.keys(),
+            dictionary: self,
+        }
+    }
 }
 
 /// Convenience mutation methods.
@@ -385,6 +494,63 @@ impl<KeyType: Message, ObjectType: Message> NSMutableDictionary<KeyType, ObjectT
         // SAFETY: The key is copied, and then has the correct type `KeyType`.
         unsafe { self.setObject_forKey(object, key) };
     }
+
+    /// Removes a key from the dictionary, returning the object at the key
+    /// if the key was previously in the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSMutableDictionary, NSObject};
+    ///
+    /// let dict = NSMutableDictionary::new();
+    /// dict.insert(ns_string!("key"), &*NSObject::new());
+    /// assert!(dict.remove(ns_string!("key")).is_some());
+    /// assert!(dict.remove(ns_string!("key")).is_none());
+    /// ```
+    #[doc(alias = "removeObjectForKey:")]
+    pub fn remove(&self, key: &KeyType) -> Option<Retained<ObjectType>> {
+        let object = self.objectForKey(key)?;
+        self.removeObjectForKey(key);
+        Some(object)
+    }
+
+    /// Get the object at the given key, or insert the object returned by the
+    /// closure if the key is not present in the dictionary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSMutableArray, NSMutableDictionary, NSObject};
+    ///
+    /// let dict = NSMutableDictionary::new();
+    /// for key in [ns_string!("a"), ns_string!("b"), ns_string!("a")] {
+    ///     let array = dict.get_or_insert_with(key, NSMutableArray::new);
+    ///     array.addObject(&*NSObject::new());
+    /// }
+    /// assert_eq!(dict.objectForKey(ns_string!("a")).unwrap().len(), 2);
+    /// ```
+    #[cfg(feature = "NSObject")]
+    #[doc(alias = "objectForKey:")]
+    #[doc(alias = "setObject:forKey:")]
+    pub fn get_or_insert_with<CopiedKey>(
+        &self,
+        key: &CopiedKey,
+        f: impl FnOnce() -> Retained<ObjectType>,
+    ) -> Retained<ObjectType>
+    where
+        CopiedKey: Message + NSCopying + CopyingHelper<Result = KeyType>,
+    {
+        // SAFETY: The key is only used for comparisons, so it's fine that it
+        // may not have the correct type `KeyType`.
+        let existing: Option<Retained<ObjectType>> =
+            unsafe { msg_send_id![self, objectForKey: key] };
+        existing.unwrap_or_else(|| {
+            let object = f();
+            self.insert(key, &object);
+            object
+        })
+    }
 }
 
 #[cfg(feature = "NSEnumerator")]
@@ -425,6 +591,37 @@ __impl_iter! {
     impl<'a, KeyType: Message, ObjectType: Message> Iterator<Item = Retained<KeyType>> for Keys<'a, KeyType, ObjectType> { ... }
 }
 
+/// An iterator over the key-value pairs of a dictionary.
+#[derive(Debug)]
+#[cfg(feature = "NSEnumerator")]
+pub struct Iter<'a, KeyType: Message, ObjectType: Message> {
+    keys: Keys<'a, KeyType, ObjectType>,
+    dictionary: &'a NSDictionary<KeyType, ObjectType>,
+}
+
+#[cfg(feature = "NSEnumerator")]
+impl<'a, KeyType: Message, ObjectType: Message> Iterator for Iter<'a, KeyType, ObjectType> {
+    type Item = (Retained<KeyType>, Retained<ObjectType>);
+
+    #[inline]
+    #[track_caller]
+    fn next(&mut self) -> Option<Self::Item> {
+        // The keys iterator checks for mutation while enumerating, so the
+        // key should always be present in the dictionary.
+        let key = self.keys.next()?;
+        let object = self
+            .dictionary
+            .objectForKey(&key)
+            .expect("key in dictionary to have a corresponding object");
+        Some((key, object))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
 /// An iterator over unretained keys of a dictionary.
 ///
 /// # Safety
@@ -477,6 +674,67 @@ __impl_iter! {
     impl<'a, KeyType: Message, ObjectType: Message> Iterator<Item = &'a ObjectType> for ObjectsUnchecked<'a, KeyType, ObjectType> { ... }
 }
 
+#[cfg(feature = "std")]
+impl<KeyType: Message + Eq + Hash, ObjectType: Message> From<&NSDictionary<KeyType, ObjectType>>
+    for HashMap<Retained<KeyType>, Retained<ObjectType>>
+{
+    /// Convert the dictionary to a `HashMap`.
+    ///
+    /// This uses `getObjects:andKeys:count:` to fetch all the keys and
+    /// objects at once.
+    ///
+    /// Note that the hashing and equality of the Rust map is determined by
+    /// `KeyType`'s `Hash` and `Eq` implementations, which may differ from
+    /// the semantics of `NSDictionary` if these are not implemented in terms
+    /// of `hash` and `isEqual:`.
+    fn from(dictionary: &NSDictionary<KeyType, ObjectType>) -> Self {
+        dictionary.keys_and_objects().into_iter().collect()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<KeyType: Message + Eq + Hash, ObjectType: Message>
+    From<&NSMutableDictionary<KeyType, ObjectType>>
+    for HashMap<Retained<KeyType>, Retained<ObjectType>>
+{
+    #[inline]
+    fn from(dictionary: &NSMutableDictionary<KeyType, ObjectType>) -> Self {
+        Self::from(&**dictionary)
+    }
+}
+
+#[cfg(feature = "NSObject")]
+impl<CopiedKey, KeyType: Message, ObjectType: Message>
+    RetainedFromIterator<(Retained<CopiedKey>, Retained<ObjectType>)>
+    for NSDictionary<KeyType, ObjectType>
+where
+    CopiedKey: Message + NSCopying + CopyingHelper<Result = KeyType>,
+{
+    fn retained_from_iter<I: IntoIterator<Item = (Retained<CopiedKey>, Retained<ObjectType>)>>(
+        iter: I,
+    ) -> Retained<Self> {
+        let (keys, objects): (Vec<_>, Vec<_>) = iter.into_iter().unzip();
+        let keys: Vec<&CopiedKey> = keys.iter().map(|key| &**key).collect();
+        Self::from_retained_objects(&keys, &objects)
+    }
+}
+
+#[cfg(feature = "NSObject")]
+impl<CopiedKey, KeyType: Message, ObjectType: Message>
+    RetainedFromIterator<(Retained<CopiedKey>, Retained<ObjectType>)>
+    for NSMutableDictionary<KeyType, ObjectType>
+where
+    CopiedKey: Message + NSCopying + CopyingHelper<Result = KeyType>,
+{
+    fn retained_from_iter<I: IntoIterator<Item = (Retained<CopiedKey>, Retained<ObjectType>)>>(
+        iter: I,
+    ) -> Retained<Self> {
+        let (keys, objects): (Vec<_>, Vec<_>) = iter.into_iter().unzip();
+        let keys: Vec<&CopiedKey> = keys.iter().map(|key| &**key).collect();
+        Self::from_retained_objects(&keys, &objects)
+    }
+}
+
 impl<KeyType: fmt::Debug + Message, ObjectType: fmt::Debug + Message> fmt::Debug
     for NSDictionary<KeyType, ObjectType>
 {
@@ -497,3 +755,21 @@ impl<KeyType: fmt::Debug + Message, ObjectType: fmt::Debug + Message> fmt::Debug
         fmt::Debug::fmt(&**self, f)
     }
 }
+
+impl<KeyType: Message, ObjectType: Message> PartialEq<NSDictionary<KeyType, ObjectType>>
+    for NSMutableDictionary<KeyType, ObjectType>
+{
+    #[inline]
+    fn eq(&self, other: &NSDictionary<KeyType, ObjectType>) -> bool {
+        PartialEq::eq(&**self, other)
+    }
+}
+
+impl<KeyType: Message, ObjectType: Message> PartialEq<NSMutableDictionary<KeyType, ObjectType>>
+    for NSDictionary<KeyType, ObjectType>
+{
+    #[inline]
+    fn eq(&self, other: &NSMutableDictionary<KeyType, ObjectType>) -> bool {
+        PartialEq::eq(self, &**other)
+    }
+}
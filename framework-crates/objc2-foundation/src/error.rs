@@ -24,6 +24,52 @@ impl NSError {
         // still good!
         unsafe { Self::initWithDomain_code_userInfo(Self::alloc(), domain, code, None) }
     }
+
+    /// Construct a new [`NSError`] with the given code in the given domain,
+    /// and with the given user info dictionary.
+    ///
+    /// See [`NSErrorUserInfoKey`] for the keys that Foundation understands.
+    ///
+    /// [`NSErrorUserInfoKey`]: crate::NSErrorUserInfoKey
+    #[cfg(feature = "NSDictionary")]
+    #[cfg(feature = "NSString")]
+    #[doc(alias = "initWithDomain:code:userInfo:")]
+    pub fn with_user_info(
+        code: objc2::ffi::NSInteger,
+        domain: &crate::NSErrorDomain,
+        user_info: &crate::NSDictionary<crate::NSErrorUserInfoKey, objc2::runtime::AnyObject>,
+    ) -> Retained<Self> {
+        use objc2::AllocAnyThread;
+        // SAFETY: Same as in `new`, the user info dictionary is copied too.
+        unsafe { Self::initWithDomain_code_userInfo(Self::alloc(), domain, code, Some(user_info)) }
+    }
+
+    /// Construct a new [`NSError`] with the given code in the given domain,
+    /// and with the given localized description.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSError};
+    ///
+    /// let error = NSError::with_description(1, ns_string!("MyDomain"), ns_string!("Oh no!"));
+    /// assert_eq!(error.localizedDescription().to_string(), "Oh no!");
+    /// ```
+    #[cfg(feature = "NSDictionary")]
+    #[cfg(feature = "NSObject")]
+    #[cfg(feature = "NSString")]
+    pub fn with_description(
+        code: objc2::ffi::NSInteger,
+        domain: &crate::NSErrorDomain,
+        description: &crate::NSString,
+    ) -> Retained<Self> {
+        let user_info = crate::NSDictionary::from_slices(
+            &[Self::NSLocalizedDescriptionKey()],
+            &[description as &objc2::runtime::AnyObject],
+        );
+        Self::with_user_info(code, domain, &user_info)
+    }
 }
 
 /// Accessor methods.
@@ -37,6 +83,124 @@ impl NSError {
 #[cfg(feature = "std")]
 impl std::error::Error for NSError {}
 
+#[cfg(feature = "std")]
+#[cfg(feature = "NSString")]
+impl NSError {
+    /// Convert the error into an [`std::io::Error`], for use in `std::io`
+    /// trait implementations.
+    ///
+    /// Errors in `NSPOSIXErrorDomain` are mapped to the corresponding OS
+    /// error.
+    pub(crate) fn to_io_error(&self) -> std::io::Error {
+        use alloc::string::ToString;
+        // SAFETY: The static is a valid, immutable string.
+        let posix_domain = unsafe { crate::NSPOSIXErrorDomain };
+        if self.domain().isEqualToString(posix_domain) {
+            if let Ok(code) = i32::try_from(self.code()) {
+                return std::io::Error::from_raw_os_error(code);
+            }
+        }
+        std::io::Error::new(
+            std::io::ErrorKind::Other,
+            self.localizedDescription().to_string(),
+        )
+    }
+}
+
+#[cfg(all(
+    feature = "std",
+    feature = "NSDictionary",
+    feature = "NSObject",
+    feature = "NSString"
+))]
+mod rust_error {
+    use alloc::string::ToString;
+    use alloc::sync::Arc;
+    use std::error::Error;
+
+    use objc2::rc::Retained;
+    use objc2::runtime::{AnyObject, NSObject};
+    use objc2::{define_class, msg_send_id, AllocAnyThread, DefinedClass};
+
+    use crate::{ns_string, NSDictionary, NSError, NSErrorUserInfoKey, NSString};
+
+    type BoxedError = Arc<dyn Error + Send + Sync + 'static>;
+
+    define_class!(
+        // SAFETY:
+        // - The superclass NSObject does not have any subclassing requirements.
+        // - `RustErrorBox` does not implement `Drop`.
+        #[unsafe(super(NSObject))]
+        #[name = "Objc2RustErrorBox"]
+        #[ivars = BoxedError]
+        struct RustErrorBox;
+    );
+
+    impl RustErrorBox {
+        fn new(error: BoxedError) -> Retained<Self> {
+            let this = Self::alloc().set_ivars(error);
+            unsafe { msg_send_id![super(this), init] }
+        }
+    }
+
+    fn rust_error_key() -> &'static NSErrorUserInfoKey {
+        ns_string!("Objc2RustErrorKey")
+    }
+
+    /// Interoperability with Rust errors.
+    impl NSError {
+        /// Wrap a Rust error in an [`NSError`] with the given code in the
+        /// given domain.
+        ///
+        /// The localized description is set to the [`Display`]
+        /// representation of the error, and the original error can be
+        /// retrieved again with [`rust_error`].
+        ///
+        /// This is useful when implementing delegate methods or similar that
+        /// must hand an error back to Objective-C.
+        ///
+        /// [`Display`]: core::fmt::Display
+        /// [`rust_error`]: Self::rust_error
+        ///
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use objc2_foundation::{ns_string, NSError};
+        ///
+        /// let parse_error = "abc".parse::<u32>().unwrap_err();
+        /// let error = NSError::from_rust_error(1, ns_string!("MyDomain"), parse_error.clone());
+        /// assert_eq!(error.localizedDescription().to_string(), parse_error.to_string());
+        ///
+        /// let original = error.rust_error().unwrap();
+        /// assert_eq!(original.downcast_ref(), Some(&parse_error));
+        /// ```
+        pub fn from_rust_error<E: Error + Send + Sync + 'static>(
+            code: objc2::ffi::NSInteger,
+            domain: &crate::NSErrorDomain,
+            error: E,
+        ) -> Retained<Self> {
+            let description = NSString::from_str(&error.to_string());
+            let boxed = RustErrorBox::new(Arc::new(error));
+            let user_info = NSDictionary::from_slices(
+                &[Self::NSLocalizedDescriptionKey(), rust_error_key()],
+                &[&*description as &AnyObject, &*boxed as &AnyObject],
+            );
+            Self::with_user_info(code, domain, &user_info)
+        }
+
+        /// The Rust error that this error was created from with
+        /// [`from_rust_error`], if any.
+        ///
+        /// [`from_rust_error`]: Self::from_rust_error
+        pub fn rust_error(&self) -> Option<Arc<dyn Error + Send + Sync + 'static>> {
+            let obj = self.userInfo().objectForKey(rust_error_key())?;
+            let boxed = obj.downcast_ref::<RustErrorBox>()?;
+            Some(boxed.ivars().clone())
+        }
+    }
+}
+
 impl fmt::Debug for NSError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut debug = f.debug_struct("NSError");
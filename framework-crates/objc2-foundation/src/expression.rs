@@ -0,0 +1,116 @@
+//! Utilities for the `NSExpression` class.
+use alloc::vec::Vec;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+
+use crate::{NSArray, NSExpression, NSString};
+
+/// Creation methods.
+impl NSExpression {
+    /// Create an expression from a format string, substituting the given
+    /// arguments for the `%@` and `%K` placeholders.
+    ///
+    /// This uses the same syntax as `NSPredicate` format strings.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Throws an exception if the format string is invalid, or if the
+    /// number of arguments doesn't match the number of placeholders.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The format string can contain `FUNCTION(...)` expressions and key
+    /// paths, which send arbitrary messages to the evaluated object when the
+    /// expression is evaluated. The format and the arguments must be
+    /// trusted.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSExpression, NSNumber};
+    ///
+    /// // SAFETY: The expression only does arithmetic on a number.
+    /// let value = unsafe {
+    ///     let expression =
+    ///         NSExpression::from_format(ns_string!("2 * %@"), &[&*NSNumber::new_i32(21)]);
+    ///     expression.evaluate(None).unwrap()
+    /// };
+    /// assert_eq!(value.downcast_ref::<NSNumber>().unwrap().as_i32(), 42);
+    /// ```
+    #[doc(alias = "expressionWithFormat:argumentArray:")]
+    pub unsafe fn from_format(format: &NSString, arguments: &[&AnyObject]) -> Retained<Self> {
+        let arguments = NSArray::from_slice(arguments);
+        // SAFETY: Upheld by caller.
+        unsafe { Self::expressionWithFormat_argumentArray(format, &arguments) }
+    }
+
+    /// Create an expression that calls one of the predefined functions with
+    /// the given arguments.
+    ///
+    /// See the documentation for [`expressionForFunction:arguments:`][docs]
+    /// for the list of supported functions, such as `sum:`, `average:`,
+    /// `max:`, `uppercase:` or `add:to:`.
+    ///
+    /// [docs]: https://developer.apple.com/documentation/foundation/nsexpression/1413747-expressionforfunction?language=objc
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Throws an exception when evaluated if the function doesn't exist, or
+    /// if the arguments are of the wrong type.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The name must be one of the predefined functions. Other names are
+    /// sent as messages to the first argument when the expression is
+    /// evaluated.
+    #[doc(alias = "expressionForFunction:arguments:")]
+    pub unsafe fn function(name: &NSString, arguments: &[&NSExpression]) -> Retained<Self> {
+        let arguments: Vec<&AnyObject> = arguments
+            .iter()
+            .map(|&arg| {
+                let arg: &AnyObject = arg;
+                arg
+            })
+            .collect();
+        // SAFETY: Upheld by caller.
+        unsafe { Self::expressionForFunction_arguments(name, &NSArray::from_slice(&arguments)) }
+    }
+
+    /// Create an expression for a collection of the given expressions.
+    ///
+    /// This is usually used as the right hand side of an `IN` or `BETWEEN`
+    /// comparison, or as the argument to an aggregate function.
+    #[doc(alias = "expressionForAggregate:")]
+    pub fn aggregate(expressions: &[&NSExpression]) -> Retained<Self> {
+        Self::expressionForAggregate(&NSArray::from_slice(expressions))
+    }
+
+    /// Evaluate the expression with the given object.
+    ///
+    /// Key paths in the expression are evaluated relative to the object,
+    /// and the object is used for `SELF`.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Throws an exception if the expression cannot be evaluated with the
+    /// given object.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Key paths and functions in the expression send messages to the
+    /// object, and these must be safe to call on it.
+    #[cfg(feature = "NSDictionary")]
+    #[doc(alias = "expressionValueWithObject:context:")]
+    pub unsafe fn evaluate(&self, object: Option<&AnyObject>) -> Option<Retained<AnyObject>> {
+        // SAFETY: Upheld by caller.
+        unsafe { self.expressionValueWithObject_context(object, None) }
+    }
+}
@@ -0,0 +1,400 @@
+//! Utilities for the `NSFileCoordinator` class and the `NSFilePresenter`
+//! protocol.
+//!
+//! Files that may be accessed by other processes at the same time, most
+//! notably documents in iCloud Drive, must be read and written through a
+//! file coordinator, see [`NSFileCoordinator::coordinate_reading`] and
+//! [`NSFileCoordinator::coordinate_writing`].
+//!
+//! To be notified when other processes change a file that you have open,
+//! implement [`FilePresenter`] and register it with
+//! [`NSFileCoordinator::add_rust_file_presenter`].
+use alloc::boxed::Box;
+use core::cell::Cell;
+use core::fmt;
+use core::ptr::{self, NonNull};
+use std::sync::Mutex;
+
+use block2::{Block, StackBlock};
+use objc2::rc::Retained;
+use objc2::runtime::{NSObject, NSObjectProtocol, ProtocolObject};
+use objc2::{define_class, msg_send_id, AllocAnyThread, DefinedClass, Message};
+
+use crate::{
+    NSError, NSFileCoordinator, NSFileCoordinatorReadingOptions, NSFileCoordinatorWritingOptions,
+    NSFilePresenter, NSOperationQueue, NSURL,
+};
+
+/// Coordinated reading and writing.
+impl NSFileCoordinator {
+    /// Read the item at the given URL, once other processes are done
+    /// writing to it.
+    ///
+    /// The closure is called synchronously with the URL to read from, which
+    /// may differ from `url` if the item was moved in the meantime. Other
+    /// writers are blocked until the closure returns.
+    ///
+    /// Returns an error if the coordination failed (or was cancelled), in
+    /// which case the closure is not called.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2_foundation::{NSFileCoordinator, NSFileCoordinatorReadingOptions, NSURL};
+    ///
+    /// let url = NSURL::from_file_path("/path/to/document.txt").unwrap();
+    /// let coordinator = NSFileCoordinator::new();
+    /// let contents = coordinator
+    ///     .coordinate_reading(&url, NSFileCoordinatorReadingOptions::empty(), |url| {
+    ///         std::fs::read_to_string(url.to_file_path().unwrap())
+    ///     })
+    ///     .expect("failed coordinating");
+    /// ```
+    #[doc(alias = "coordinateReadingItemAtURL:options:error:byAccessor:")]
+    pub fn coordinate_reading<R>(
+        &self,
+        url: &NSURL,
+        options: NSFileCoordinatorReadingOptions,
+        f: impl FnOnce(&NSURL) -> R,
+    ) -> Result<R, Retained<NSError>> {
+        coordinate(f, |error, accessor| {
+            // SAFETY: The accessor is only used for the duration of the call.
+            unsafe {
+                self.coordinateReadingItemAtURL_options_error_byAccessor(
+                    url,
+                    options,
+                    Some(error),
+                    accessor,
+                )
+            }
+        })
+    }
+
+    /// Write to the item at the given URL, once other processes are done
+    /// reading from and writing to it.
+    ///
+    /// The closure is called synchronously with the URL to write to, which
+    /// may differ from `url` if the item was moved in the meantime. Other
+    /// readers and writers are blocked until the closure returns.
+    ///
+    /// Returns an error if the coordination failed (or was cancelled), in
+    /// which case the closure is not called.
+    #[doc(alias = "coordinateWritingItemAtURL:options:error:byAccessor:")]
+    pub fn coordinate_writing<R>(
+        &self,
+        url: &NSURL,
+        options: NSFileCoordinatorWritingOptions,
+        f: impl FnOnce(&NSURL) -> R,
+    ) -> Result<R, Retained<NSError>> {
+        coordinate(f, |error, accessor| {
+            // SAFETY: The accessor is only used for the duration of the call.
+            unsafe {
+                self.coordinateWritingItemAtURL_options_error_byAccessor(
+                    url,
+                    options,
+                    Some(error),
+                    accessor,
+                )
+            }
+        })
+    }
+}
+
+fn coordinate<R>(
+    f: impl FnOnce(&NSURL) -> R,
+    call: impl FnOnce(&mut Option<Retained<NSError>>, &Block<dyn Fn(NonNull<NSURL>) + '_>),
+) -> Result<R, Retained<NSError>> {
+    // The accessor is `Fn`, but is only called once.
+    let f = Cell::new(Some(f));
+    let result = Cell::new(None);
+    let accessor = StackBlock::new(|url: NonNull<NSURL>| {
+        if let Some(f) = f.take() {
+            // SAFETY: The URL is valid for the duration of the call.
+            result.set(Some(f(unsafe { url.as_ref() })));
+        }
+    });
+
+    let mut error = None;
+    call(&mut error, &accessor);
+    match error {
+        Some(error) => Err(error),
+        None => Ok(result
+            .into_inner()
+            .expect("file coordinator succeeded without calling the accessor")),
+    }
+}
+
+/// Handler for changes to a file or directory made through other file
+/// coordinators, usually in other processes.
+///
+/// The methods are called on a private serial queue, and may therefore run
+/// on any thread. All methods have a default implementation that does
+/// nothing.
+#[allow(unused_variables)]
+pub trait FilePresenter: Send {
+    /// The contents or attributes of the item changed.
+    #[doc(alias = "presentedItemDidChange")]
+    fn did_change(&mut self, url: &NSURL) {}
+
+    /// The item was moved or renamed.
+    ///
+    /// The presenter tracks the new URL itself, see
+    /// [`PresenterRegistration::url`].
+    #[doc(alias = "presentedItemDidMoveToURL:")]
+    fn did_move(&mut self, new_url: &NSURL) {}
+
+    /// Another process wants to read the item, so any unsaved changes
+    /// should be written to it now.
+    #[doc(alias = "savePresentedItemChangesWithCompletionHandler:")]
+    fn save_changes(&mut self, url: &NSURL) -> Result<(), Retained<NSError>> {
+        Ok(())
+    }
+
+    /// The item is about to be deleted, so any references to it should be
+    /// released now.
+    #[doc(alias = "accommodatePresentedItemDeletionWithCompletionHandler:")]
+    fn accommodate_deletion(&mut self, url: &NSURL) -> Result<(), Retained<NSError>> {
+        Ok(())
+    }
+}
+
+struct Ivars {
+    /// Updated when the item is moved.
+    url: Mutex<Retained<NSURL>>,
+    queue: Retained<NSOperationQueue>,
+    presenter: Mutex<Box<dyn FilePresenter>>,
+}
+
+define_class!(
+    // SAFETY:
+    // - The superclass NSObject does not have any subclassing requirements.
+    // - `RustFilePresenter` does not implement `Drop`.
+    #[unsafe(super(NSObject))]
+    #[name = "Objc2FilePresenter"]
+    #[ivars = Ivars]
+    struct RustFilePresenter;
+
+    unsafe impl NSObjectProtocol for RustFilePresenter {}
+
+    unsafe impl NSFilePresenter for RustFilePresenter {
+        #[method_id(presentedItemURL)]
+        fn presented_item_url(&self) -> Option<Retained<NSURL>> {
+            Some(self.url())
+        }
+
+        #[method_id(presentedItemOperationQueue)]
+        fn presented_item_operation_queue(&self) -> Retained<NSOperationQueue> {
+            self.ivars().queue.clone()
+        }
+
+        #[method(presentedItemDidChange)]
+        fn presented_item_did_change(&self) {
+            let url = self.url();
+            self.ivars().presenter.lock().unwrap().did_change(&url);
+        }
+
+        #[method(presentedItemDidMoveToURL:)]
+        fn presented_item_did_move_to_url(&self, new_url: &NSURL) {
+            *self.ivars().url.lock().unwrap() = new_url.retain();
+            self.ivars().presenter.lock().unwrap().did_move(new_url);
+        }
+
+        #[method(savePresentedItemChangesWithCompletionHandler:)]
+        fn save_presented_item_changes(&self, completion_handler: &Block<dyn Fn(*mut NSError)>) {
+            let url = self.url();
+            let result = self.ivars().presenter.lock().unwrap().save_changes(&url);
+            complete(completion_handler, result);
+        }
+
+        #[method(accommodatePresentedItemDeletionWithCompletionHandler:)]
+        fn accommodate_presented_item_deletion(
+            &self,
+            completion_handler: &Block<dyn Fn(*mut NSError)>,
+        ) {
+            let url = self.url();
+            let result = self
+                .ivars()
+                .presenter
+                .lock()
+                .unwrap()
+                .accommodate_deletion(&url);
+            complete(completion_handler, result);
+        }
+    }
+);
+
+impl RustFilePresenter {
+    fn new(url: &NSURL, presenter: Box<dyn FilePresenter>) -> Retained<Self> {
+        // Callbacks must not run concurrently.
+        let queue = NSOperationQueue::new();
+        queue.setMaxConcurrentOperationCount(1);
+        let this = Self::alloc().set_ivars(Ivars {
+            url: Mutex::new(url.retain()),
+            queue,
+            presenter: Mutex::new(presenter),
+        });
+        unsafe { msg_send_id![super(this), init] }
+    }
+
+    fn url(&self) -> Retained<NSURL> {
+        self.ivars().url.lock().unwrap().clone()
+    }
+}
+
+fn complete(
+    completion_handler: &Block<dyn Fn(*mut NSError)>,
+    result: Result<(), Retained<NSError>>,
+) {
+    let error = match &result {
+        Ok(()) => ptr::null_mut(),
+        Err(error) => Retained::as_ptr(error).cast_mut(),
+    };
+    completion_handler.call((error,));
+}
+
+impl NSFileCoordinator {
+    /// Register a presenter for the file or directory at the given URL.
+    ///
+    /// The presenter is notified of changes made through other file
+    /// coordinators until the returned registration is dropped. Use
+    /// [`PresenterRegistration::coordinator`] to access the item without
+    /// notifying the presenter of your own changes.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2_foundation::file_coordinator::FilePresenter;
+    /// use objc2_foundation::{NSFileCoordinator, NSURL};
+    ///
+    /// struct Document;
+    ///
+    /// impl FilePresenter for Document {
+    ///     fn did_change(&mut self, url: &NSURL) {
+    ///         println!("{url:?} changed, reloading");
+    ///     }
+    /// }
+    ///
+    /// let url = NSURL::from_file_path("/path/to/document.txt").unwrap();
+    /// let registration = NSFileCoordinator::add_rust_file_presenter(&url, Document);
+    /// // ... keep `registration` alive while the document is open.
+    /// ```
+    #[doc(alias = "addFilePresenter:")]
+    pub fn add_rust_file_presenter(
+        url: &NSURL,
+        presenter: impl FilePresenter + 'static,
+    ) -> PresenterRegistration {
+        let presenter = RustFilePresenter::new(url, Box::new(presenter));
+        // SAFETY: The presenter is kept alive by the registration until it
+        // has been removed again.
+        unsafe { Self::addFilePresenter(ProtocolObject::from_ref(&*presenter)) };
+        PresenterRegistration { presenter }
+    }
+}
+
+/// A presenter registered with
+/// [`NSFileCoordinator::add_rust_file_presenter`].
+///
+/// The presenter is removed when this is dropped.
+#[must_use = "the file presenter is removed when dropped"]
+pub struct PresenterRegistration {
+    presenter: Retained<RustFilePresenter>,
+}
+
+impl PresenterRegistration {
+    /// The current URL of the presented item.
+    ///
+    /// This is updated when the item is moved.
+    #[doc(alias = "presentedItemURL")]
+    pub fn url(&self) -> Retained<NSURL> {
+        self.presenter.url()
+    }
+
+    /// The underlying presenter object.
+    pub fn presenter(&self) -> &ProtocolObject<dyn NSFilePresenter> {
+        ProtocolObject::from_ref(&*self.presenter)
+    }
+
+    /// Create a file coordinator that does not notify this presenter.
+    #[doc(alias = "initWithFilePresenter:")]
+    pub fn coordinator(&self) -> Retained<NSFileCoordinator> {
+        NSFileCoordinator::initWithFilePresenter(NSFileCoordinator::alloc(), Some(self.presenter()))
+    }
+}
+
+impl Drop for PresenterRegistration {
+    fn drop(&mut self) {
+        // SAFETY: The presenter was added in `add_rust_file_presenter`.
+        unsafe { NSFileCoordinator::removeFilePresenter(self.presenter()) };
+    }
+}
+
+impl fmt::Debug for PresenterRegistration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PresenterRegistration")
+            .field("url", &self.url())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(target_vendor = "apple")]
+    fn coordinate_write_read() {
+        use super::*;
+
+        let path = std::env::temp_dir().join(alloc::format!(
+            "objc2-foundation-file-coordinator-{}",
+            std::process::id()
+        ));
+        let url = NSURL::from_file_path(&path).unwrap();
+        let coordinator = NSFileCoordinator::new();
+
+        coordinator
+            .coordinate_writing(&url, NSFileCoordinatorWritingOptions::empty(), |url| {
+                std::fs::write(url.to_file_path().unwrap(), "hello")
+            })
+            .unwrap()
+            .unwrap();
+        let contents = coordinator
+            .coordinate_reading(&url, NSFileCoordinatorReadingOptions::empty(), |url| {
+                std::fs::read_to_string(url.to_file_path().unwrap())
+            })
+            .unwrap()
+            .unwrap();
+        assert_eq!(contents, "hello");
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[cfg(target_vendor = "apple")]
+    fn register_presenter() {
+        use super::*;
+
+        struct Ignore;
+
+        impl FilePresenter for Ignore {}
+
+        let path = std::env::temp_dir().join(alloc::format!(
+            "objc2-foundation-file-presenter-{}",
+            std::process::id()
+        ));
+        let url = NSURL::from_file_path(path).unwrap();
+        let registration = NSFileCoordinator::add_rust_file_presenter(&url, Ignore);
+        assert_eq!(registration.url(), url);
+        assert!(NSFileCoordinator::filePresenters()
+            .iter()
+            .any(|presenter| ptr::eq(&*presenter, registration.presenter())));
+
+        let coordinator = registration.coordinator();
+        let called = coordinator
+            .coordinate_reading(&url, NSFileCoordinatorReadingOptions::empty(), |_| true)
+            .unwrap();
+        assert!(called);
+
+        drop(registration);
+    }
+}
@@ -0,0 +1,117 @@
+//! Utilities for the `NSFileHandle` class.
+use core::ptr::NonNull;
+use std::io;
+
+use crate::{NSData, NSFileHandle};
+
+/// Creation methods.
+impl NSFileHandle {
+    /// Create a file handle that takes ownership of the given file
+    /// descriptor.
+    ///
+    /// The file descriptor is closed when the file handle is deallocated.
+    #[cfg(unix)]
+    #[doc(alias = "initWithFileDescriptor:closeOnDealloc:")]
+    pub fn from_owned_fd(fd: std::os::fd::OwnedFd) -> objc2::rc::Retained<Self> {
+        use objc2::AllocAnyThread;
+        use std::os::fd::IntoRawFd;
+        // SAFETY: The file descriptor is valid, and we transfer ownership of
+        // it to the file handle.
+        unsafe {
+            Self::initWithFileDescriptor_closeOnDealloc(Self::alloc(), fd.into_raw_fd(), true)
+        }
+    }
+}
+
+#[cfg(unix)]
+impl std::os::fd::AsRawFd for NSFileHandle {
+    #[inline]
+    fn as_raw_fd(&self) -> std::os::fd::RawFd {
+        self.fileDescriptor()
+    }
+}
+
+/// Reads from the file handle using `readDataUpToLength:error:`.
+///
+/// Note that this is only available on macOS 10.15 and above (and
+/// equivalent on other platforms).
+impl io::Read for &NSFileHandle {
+    #[doc(alias = "readDataUpToLength:error:")]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let data = self
+            .readDataUpToLength_error(buf.len())
+            .map_err(|err| err.to_io_error())?;
+        // SAFETY: The data is immediately copied, and is not mutated.
+        let bytes = unsafe { data.as_slice_unchecked() };
+        // Guard against the file handle returning more than we asked for.
+        let len = bytes.len().min(buf.len());
+        buf[..len].copy_from_slice(&bytes[..len]);
+        Ok(len)
+    }
+}
+
+/// Writes to the file handle using `writeData:error:`.
+///
+/// Note that this is only available on macOS 10.15 and above (and
+/// equivalent on other platforms).
+impl io::Write for &NSFileHandle {
+    #[doc(alias = "writeData:error:")]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let data = NSData::with_bytes(buf);
+        self.writeData_error(&data)
+            .map_err(|err| err.to_io_error())?;
+        Ok(buf.len())
+    }
+
+    /// File handles are unbuffered, so this does nothing.
+    ///
+    /// Use `synchronizeAndReturnError:` to flush the data to disk.
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Seeks in the file handle using `seekToOffset:error:`.
+///
+/// Note that this is only available on macOS 10.15 and above (and
+/// equivalent on other platforms).
+impl io::Seek for &NSFileHandle {
+    fn seek(&mut self, pos: io::SeekFrom) -> io::Result<u64> {
+        let invalid = || {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )
+        };
+        let offset = match pos {
+            io::SeekFrom::Start(offset) => offset,
+            io::SeekFrom::End(delta) => {
+                let mut end = 0;
+                // SAFETY: The pointer is valid for writes.
+                unsafe { self.seekToEndReturningOffset_error(&mut end) }
+                    .map_err(|err| err.to_io_error())?;
+                if delta == 0 {
+                    return Ok(end);
+                }
+                end.checked_add_signed(delta).ok_or_else(invalid)?
+            }
+            io::SeekFrom::Current(delta) => {
+                let current = self.stream_position()?;
+                current.checked_add_signed(delta).ok_or_else(invalid)?
+            }
+        };
+        self.seekToOffset_error(offset)
+            .map_err(|err| err.to_io_error())?;
+        Ok(offset)
+    }
+
+    #[doc(alias = "getOffset:error:")]
+    fn stream_position(&mut self) -> io::Result<u64> {
+        let mut offset = 0;
+        // SAFETY: The pointer is valid for writes.
+        unsafe { self.getOffset_error(NonNull::from(&mut offset)) }
+            .map_err(|err| err.to_io_error())?;
+        Ok(offset)
+    }
+}
@@ -0,0 +1,75 @@
+//! Utilities for the `NSFileManager` class.
+use objc2::rc::Retained;
+use objc2::runtime::Bool;
+
+use crate::{NSError, NSFileManager, NSString, NSURL};
+
+/// Convenience methods.
+impl NSFileManager {
+    /// Whether a directory exists at the given path.
+    ///
+    /// Returns `None` if nothing exists at the path, `Some(true)` if it is a
+    /// directory, and `Some(false)` if it is some other kind of file.
+    /// Symbolic links are followed.
+    #[doc(alias = "fileExistsAtPath:isDirectory:")]
+    pub fn is_directory_at_path(&self, path: &NSString) -> Option<bool> {
+        let mut is_directory = Bool::NO;
+        // SAFETY: The pointer is valid for writes.
+        let exists = unsafe { self.fileExistsAtPath_isDirectory(path, &mut is_directory) };
+        if exists {
+            Some(is_directory.as_bool())
+        } else {
+            None
+        }
+    }
+
+    /// Move the item at the given URL to the trash.
+    ///
+    /// On success, returns the URL of the item in the trash, if available.
+    #[doc(alias = "trashItemAtURL:resultingItemURL:error:")]
+    pub fn trash_item(&self, url: &NSURL) -> Result<Option<Retained<NSURL>>, Retained<NSError>> {
+        let mut resulting_url: Option<Retained<NSURL>> = None;
+        // SAFETY: The out-pointer is valid, and is initialized to `None`.
+        unsafe { self.trashItemAtURL_resultingItemURL_error(url, Some(&mut resulting_url))? };
+        Ok(resulting_url)
+    }
+
+    /// Recursively iterate over the contents of the directory at the given
+    /// URL.
+    ///
+    /// Errors that occur while enumerating are ignored, and the
+    /// corresponding entries skipped.
+    ///
+    /// Returns `None` if the directory could not be opened.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2_foundation::{NSDirectoryEnumerationOptions, NSFileManager, NSURL};
+    ///
+    /// let manager = NSFileManager::defaultManager();
+    /// let dir = NSURL::from_directory_path("/tmp").unwrap();
+    /// for url in manager
+    ///     .enumerate_directory(&dir, NSDirectoryEnumerationOptions::SkipsHiddenFiles)
+    ///     .unwrap()
+    /// {
+    ///     println!("{:?}", url.path());
+    /// }
+    /// ```
+    #[cfg(feature = "NSEnumerator")]
+    #[doc(alias = "enumeratorAtURL:includingPropertiesForKeys:options:errorHandler:")]
+    pub fn enumerate_directory(
+        &self,
+        url: &NSURL,
+        options: crate::NSDirectoryEnumerationOptions,
+    ) -> Option<crate::enumerator::IntoIter<NSURL>> {
+        // SAFETY: Passing no error handler is allowed.
+        let enumerator = unsafe {
+            self.enumeratorAtURL_includingPropertiesForKeys_options_errorHandler(
+                url, None, options, None,
+            )
+        }?;
+        Some(Retained::into_super(enumerator).into_iter())
+    }
+}
@@ -1,6 +1,8 @@
 use objc2::encode::{Encode, Encoding, RefEncode};
 use objc2::ffi::NSUInteger;
 
+use crate::NSEdgeInsets;
+
 #[cfg(target_pointer_width = "64")]
 type InnerFloat = f64;
 #[cfg(not(target_pointer_width = "64"))]
@@ -99,6 +101,22 @@ impl CGPoint {
     pub const ZERO: Self = Self::new(0.0, 0.0);
 }
 
+impl From<(CGFloat, CGFloat)> for CGPoint {
+    /// Create a point from a tuple of its `x` and `y` coordinates.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::CGPoint;
+    /// assert_eq!(CGPoint::from((1.0, 2.0)), CGPoint::new(1.0, 2.0));
+    /// ```
+    #[inline]
+    fn from((x, y): (CGFloat, CGFloat)) -> Self {
+        Self::new(x, y)
+    }
+}
+
 /// A two-dimensional size.
 ///
 /// As this is sometimes used to represent a distance vector, rather than a
@@ -191,6 +209,22 @@ impl CGSize {
     pub const ZERO: Self = Self::new(0.0, 0.0);
 }
 
+impl From<(CGFloat, CGFloat)> for CGSize {
+    /// Create a size from a tuple of its width and height.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::CGSize;
+    /// assert_eq!(CGSize::from((1.0, 2.0)), CGSize::new(1.0, 2.0));
+    /// ```
+    #[inline]
+    fn from((width, height): (CGFloat, CGFloat)) -> Self {
+        Self::new(width, height)
+    }
+}
+
 /// The location and dimensions of a rectangle.
 ///
 /// In the default Core Graphics coordinate space (macOS), the origin is
@@ -323,25 +357,268 @@ impl CGRect {
         // self.size.width <= 0.0 || self.size.height <= 0.0
     }
 
-    // TODO: NSContainsRect / CGRectContainsRect
+    /// The width of the rectangle, which is never negative.
+    #[inline]
+    #[doc(alias = "CGRectGetWidth")]
+    #[doc(alias = "NSWidth")]
+    pub fn width(self) -> CGFloat {
+        self.size.width.abs()
+    }
+
+    /// The height of the rectangle, which is never negative.
+    #[inline]
+    #[doc(alias = "CGRectGetHeight")]
+    #[doc(alias = "NSHeight")]
+    pub fn height(self) -> CGFloat {
+        self.size.height.abs()
+    }
+
+    /// The smallest and largest coordinates of the rectangle, taking
+    /// negative sizes into account.
+    #[inline]
+    fn bounds(self) -> (CGPoint, CGPoint) {
+        let x = (self.origin.x, self.origin.x + self.size.width);
+        let y = (self.origin.y, self.origin.y + self.size.height);
+        (
+            CGPoint::new(x.0.min(x.1), y.0.min(y.1)),
+            CGPoint::new(x.0.max(x.1), y.0.max(y.1)),
+        )
+    }
+
+    #[inline]
+    fn from_bounds(min: CGPoint, max: CGPoint) -> Self {
+        Self::new(min, CGSize::new(max.x - min.x, max.y - min.y))
+    }
+
+    /// Returns the rectangle moved by the given distances.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::CGRect;
+    /// let rect = CGRect::from((1.0, 2.0, 3.0, 4.0));
+    /// assert_eq!(rect.offset(1.0, -1.0), CGRect::from((2.0, 1.0, 3.0, 4.0)));
+    /// ```
+    #[inline]
+    #[doc(alias = "CGRectOffset")]
+    #[doc(alias = "NSOffsetRect")]
+    pub fn offset(self, dx: CGFloat, dy: CGFloat) -> Self {
+        Self::new(
+            CGPoint::new(self.origin.x + dx, self.origin.y + dy),
+            self.size,
+        )
+    }
+
+    /// Returns the rectangle shrunk by `dx` on the left and right, and by
+    /// `dy` on the top and bottom.
+    ///
+    /// Negative values grow the rectangle instead. If the rectangle is
+    /// shrunk by more than its size, the result is empty.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::CGRect;
+    /// let rect = CGRect::from((0.0, 0.0, 10.0, 10.0));
+    /// assert_eq!(rect.inset(1.0, 2.0), CGRect::from((1.0, 2.0, 8.0, 6.0)));
+    /// assert!(rect.inset(6.0, 0.0).is_empty());
+    /// ```
+    #[inline]
+    #[doc(alias = "CGRectInset")]
+    #[doc(alias = "NSInsetRect")]
+    pub fn inset(self, dx: CGFloat, dy: CGFloat) -> Self {
+        let (min, max) = self.bounds();
+        Self::new(
+            CGPoint::new(min.x + dx, min.y + dy),
+            CGSize::new(max.x - min.x - 2.0 * dx, max.y - min.y - 2.0 * dy),
+        )
+    }
+
+    /// Returns the smallest rectangle that contains both rectangles.
+    ///
+    /// Empty rectangles are ignored.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::CGRect;
+    /// let a = CGRect::from((0.0, 0.0, 2.0, 2.0));
+    /// let b = CGRect::from((1.0, 1.0, 2.0, 3.0));
+    /// assert_eq!(a.union(b), CGRect::from((0.0, 0.0, 3.0, 4.0)));
+    /// assert_eq!(a.union(CGRect::ZERO), a);
+    /// ```
+    #[inline]
+    #[doc(alias = "CGRectUnion")]
+    #[doc(alias = "NSUnionRect")]
+    pub fn union(self, other: Self) -> Self {
+        if self.standardize().is_empty() {
+            return other;
+        }
+        if other.standardize().is_empty() {
+            return self;
+        }
+        let (a_min, a_max) = self.bounds();
+        let (b_min, b_max) = other.bounds();
+        Self::from_bounds(
+            CGPoint::new(a_min.x.min(b_min.x), a_min.y.min(b_min.y)),
+            CGPoint::new(a_max.x.max(b_max.x), a_max.y.max(b_max.y)),
+        )
+    }
+
+    /// Returns the overlapping area of the rectangles, or `None` if they
+    /// do not overlap.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::CGRect;
+    /// let a = CGRect::from((0.0, 0.0, 2.0, 2.0));
+    /// let b = CGRect::from((1.0, 1.0, 2.0, 3.0));
+    /// assert_eq!(a.intersection(b), Some(CGRect::from((1.0, 1.0, 1.0, 1.0))));
+    /// assert_eq!(a.intersection(a.offset(2.0, 0.0)), None);
+    /// ```
+    #[inline]
+    #[doc(alias = "CGRectIntersection")]
+    #[doc(alias = "NSIntersectionRect")]
+    pub fn intersection(self, other: Self) -> Option<Self> {
+        let (a_min, a_max) = self.bounds();
+        let (b_min, b_max) = other.bounds();
+        let min = CGPoint::new(a_min.x.max(b_min.x), a_min.y.max(b_min.y));
+        let max = CGPoint::new(a_max.x.min(b_max.x), a_max.y.min(b_max.y));
+        if min.x < max.x && min.y < max.y {
+            Some(Self::from_bounds(min, max))
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether the rectangles overlap.
+    ///
+    /// Rectangles that only share an edge do not overlap.
+    #[inline]
+    #[doc(alias = "CGRectIntersectsRect")]
+    #[doc(alias = "NSIntersectsRect")]
+    pub fn intersects(self, other: Self) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Returns whether the point lies within the rectangle.
+    ///
+    /// Points on the minimum edges are inside the rectangle, while points
+    /// on the maximum edges are not.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{CGPoint, CGRect};
+    /// let rect = CGRect::from((0.0, 0.0, 2.0, 2.0));
+    /// assert!(rect.contains(CGPoint::new(0.0, 1.0)));
+    /// assert!(!rect.contains(CGPoint::new(2.0, 1.0)));
+    /// ```
+    #[inline]
+    #[doc(alias = "CGRectContainsPoint")]
+    #[doc(alias = "NSPointInRect")]
+    pub fn contains(self, point: CGPoint) -> bool {
+        let (min, max) = self.bounds();
+        min.x <= point.x && point.x < max.x && min.y <= point.y && point.y < max.y
+    }
+
+    /// Returns whether the other rectangle lies completely within this
+    /// rectangle.
+    ///
+    /// Empty rectangles are not contained in any rectangle.
+    #[inline]
+    #[doc(alias = "CGRectContainsRect")]
+    #[doc(alias = "NSContainsRect")]
+    pub fn contains_rect(self, other: Self) -> bool {
+        if other.standardize().is_empty() {
+            return false;
+        }
+        let (a_min, a_max) = self.bounds();
+        let (b_min, b_max) = other.bounds();
+        a_min.x <= b_min.x && a_min.y <= b_min.y && b_max.x <= a_max.x && b_max.y <= a_max.y
+    }
+
     // TODO: NSDivideRect / CGRectDivide
-    // TODO: NSInsetRect / CGRectInset
     // TODO: NSIntegralRect / CGRectIntegral
-    // TODO: NSIntersectionRect / CGRectIntersection
-    // TODO: NSUnionRect / CGRectUnion
-    // TODO: NSIntersectsRect / CGRectIntersectsRect
     // TODO: NSMouseInRect
-    // TODO: NSMouseInRect
-    // TODO: NSPointInRect / CGRectContainsPoint
-    // TODO: NSOffsetRect / CGRectOffset
 
     // TODO: CGRectIsNull
     // TODO: CGRectIsInfinite
     // TODO: CGRectInfinite
     // TODO: CGRectNull
+}
 
-    // TODO: NSHeight / CGRectGetHeight (standardized)
-    // TODO: NSWidth / CGRectGetWidth (standardized)
+impl From<(CGPoint, CGSize)> for CGRect {
+    /// Create a rectangle from a tuple of its origin and size.
+    #[inline]
+    fn from((origin, size): (CGPoint, CGSize)) -> Self {
+        Self::new(origin, size)
+    }
+}
+
+impl From<(CGFloat, CGFloat, CGFloat, CGFloat)> for CGRect {
+    /// Create a rectangle from a tuple of its `x` and `y` coordinates, and
+    /// its width and height.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{CGPoint, CGRect, CGSize};
+    /// let rect = CGRect::from((1.0, 2.0, 3.0, 4.0));
+    /// assert_eq!(rect, CGRect::new(CGPoint::new(1.0, 2.0), CGSize::new(3.0, 4.0)));
+    /// ```
+    #[inline]
+    fn from((x, y, width, height): (CGFloat, CGFloat, CGFloat, CGFloat)) -> Self {
+        Self::new(CGPoint::new(x, y), CGSize::new(width, height))
+    }
+}
+
+impl NSEdgeInsets {
+    /// Create new edge insets with the given distances.
+    #[inline]
+    #[doc(alias = "NSEdgeInsetsMake")]
+    pub const fn new(top: CGFloat, left: CGFloat, bottom: CGFloat, right: CGFloat) -> Self {
+        Self {
+            top,
+            left,
+            bottom,
+            right,
+        }
+    }
+
+    /// Edge insets that are `0.0` on all edges.
+    #[doc(alias = "NSEdgeInsetsZero")]
+    pub const ZERO: Self = Self::new(0.0, 0.0, 0.0, 0.0);
+
+    /// Apply the insets to the given rectangle.
+    ///
+    /// This assumes the default, non-flipped coordinate space of macOS,
+    /// where the bottom edge is at the smallest `y` coordinate.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{CGRect, NSEdgeInsets};
+    /// let insets = NSEdgeInsets::new(1.0, 2.0, 3.0, 4.0);
+    /// let rect = CGRect::from((0.0, 0.0, 10.0, 10.0));
+    /// assert_eq!(insets.inset_rect(rect), CGRect::from((2.0, 3.0, 4.0, 6.0)));
+    /// ```
+    #[inline]
+    pub fn inset_rect(self, rect: CGRect) -> CGRect {
+        let (min, max) = rect.bounds();
+        CGRect::from_bounds(
+            CGPoint::new(min.x + self.left, min.y + self.bottom),
+            CGPoint::new(max.x - self.right, max.y - self.top),
+        )
+    }
 }
 
 /// A point in a Cartesian coordinate system.
@@ -401,6 +678,54 @@ impl NSRectEdge {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_negative_size() {
+        // Rectangles with negative sizes are treated like their standardized
+        // equivalents.
+        let rect = CGRect::from((2.0, 2.0, -2.0, -2.0));
+        assert_eq!(rect.width(), 2.0);
+        assert_eq!(rect.height(), 2.0);
+        assert!(rect.contains(CGPoint::new(0.0, 0.0)));
+        assert!(!rect.contains(CGPoint::new(2.0, 2.0)));
+        assert_eq!(rect.inset(0.5, 0.5), CGRect::from((0.5, 0.5, 1.0, 1.0)));
+
+        let other = CGRect::from((1.0, 1.0, 2.0, 2.0));
+        assert_eq!(
+            rect.intersection(other),
+            Some(CGRect::from((1.0, 1.0, 1.0, 1.0)))
+        );
+        assert_eq!(rect.union(other), CGRect::from((0.0, 0.0, 3.0, 3.0)));
+        assert!(other.contains_rect(CGRect::from((2.0, 2.0, -1.0, -1.0))));
+    }
+
+    #[test]
+    fn test_contains_rect() {
+        let rect = CGRect::from((0.0, 0.0, 4.0, 4.0));
+        assert!(rect.contains_rect(rect));
+        assert!(rect.contains_rect(CGRect::from((1.0, 1.0, 3.0, 3.0))));
+        assert!(!rect.contains_rect(CGRect::from((1.0, 1.0, 4.0, 3.0))));
+        assert!(!rect.contains_rect(CGRect::from((1.0, 1.0, 0.0, 0.0))));
+    }
+
+    #[test]
+    fn test_intersects() {
+        let rect = CGRect::from((0.0, 0.0, 2.0, 2.0));
+        assert!(rect.intersects(CGRect::from((1.0, 1.0, 2.0, 2.0))));
+        // Sharing an edge is not enough.
+        assert!(!rect.intersects(CGRect::from((2.0, 0.0, 2.0, 2.0))));
+        assert!(!rect.intersects(CGRect::ZERO));
+    }
+
+    #[test]
+    fn test_encoding() {
+        let expected = if cfg!(target_pointer_width = "64") {
+            "{NSEdgeInsets=dddd}"
+        } else {
+            "{NSEdgeInsets=ffff}"
+        };
+        assert!(NSEdgeInsets::ENCODING.equivalent_to_str(expected));
+    }
+
     #[test]
     fn test_cgsize_new() {
         CGSize::new(1.0, 1.0);
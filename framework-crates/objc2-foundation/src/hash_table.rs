@@ -0,0 +1,46 @@
+//! Utilities for the `NSHashTable` class.
+use alloc::vec::Vec;
+
+use objc2::rc::Retained;
+use objc2::Message;
+
+use crate::NSHashTable;
+
+impl<ObjectType: Message> NSHashTable<ObjectType> {
+    /// Take a snapshot of the objects that are currently in the table.
+    ///
+    /// For tables holding weak references, objects that have been
+    /// deallocated are not included, and the remaining objects are retained
+    /// for as long as the snapshot is alive. This makes it suitable for
+    /// iterating over a set of observers, even if an observer is
+    /// deallocated during the iteration.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSHashTable, NSObject};
+    ///
+    /// let observers = NSHashTable::<NSObject>::weakObjectsHashTable();
+    /// let observer = NSObject::new();
+    /// observers.addObject(Some(&observer));
+    ///
+    /// for observer in observers.snapshot() {
+    ///     // Notify observer
+    /// #   let _ = observer;
+    /// }
+    /// ```
+    #[doc(alias = "allObjects")]
+    pub fn snapshot(&self) -> Vec<Retained<ObjectType>> {
+        self.allObjects().to_vec()
+    }
+
+    /// The number of objects in the table that are still alive.
+    ///
+    /// Unlike `count`, this does not include weak references whose object
+    /// has been deallocated, but which have not yet been purged from the
+    /// table.
+    pub fn live_count(&self) -> usize {
+        self.allObjects().len()
+    }
+}
@@ -0,0 +1,30 @@
+//! Utilities for the `NSHost` class.
+use alloc::vec::Vec;
+use std::net::IpAddr;
+
+use crate::NSHost;
+
+impl NSHost {
+    /// The IPv4 and IPv6 addresses of the host.
+    ///
+    /// Addresses that cannot be parsed, such as link-local IPv6 addresses
+    /// with a zone identifier, are skipped.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSHost;
+    ///
+    /// for address in NSHost::currentHost().ip_addrs() {
+    ///     println!("{address}");
+    /// }
+    /// ```
+    #[doc(alias = "addresses")]
+    pub fn ip_addrs(&self) -> Vec<IpAddr> {
+        self.addresses()
+            .iter()
+            .filter_map(|address| address.to_string().parse().ok())
+            .collect()
+    }
+}
@@ -0,0 +1,173 @@
+//! Interoperability between `NSHTTPCookie` and the [`cookie`] crate.
+//!
+//! This allows sharing cookies between `NSURLSession` (which uses the shared
+//! `NSHTTPCookieStorage` by default) and an HTTP client written in Rust.
+use alloc::string::ToString;
+
+use cookie::time::OffsetDateTime;
+use cookie::{Cookie, CookieJar, SameSite};
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+
+use crate::{
+    NSDate, NSHTTPCookie, NSHTTPCookieDomain, NSHTTPCookieExpires, NSHTTPCookieMaximumAge,
+    NSHTTPCookieName, NSHTTPCookiePath, NSHTTPCookieSameSiteLax, NSHTTPCookieSameSitePolicy,
+    NSHTTPCookieSameSiteStrict, NSHTTPCookieSecure, NSHTTPCookieStorage, NSHTTPCookieValue,
+    NSMutableDictionary, NSString,
+};
+
+/// Conversions to and from [`cookie::Cookie`].
+impl NSHTTPCookie {
+    /// Convert the cookie to a [`Cookie`].
+    ///
+    /// The expiry date is truncated to whole seconds, and is left out if it
+    /// cannot be represented.
+    pub fn to_cookie(&self) -> Cookie<'static> {
+        let mut builder = Cookie::build((self.name().to_string(), self.value().to_string()))
+            .domain(self.domain().to_string())
+            .path(self.path().to_string())
+            .secure(self.isSecure())
+            .http_only(self.isHTTPOnly());
+
+        if let Some(date) = self.expiresDate() {
+            let timestamp = date.timeIntervalSince1970() as i64;
+            if let Ok(expires) = OffsetDateTime::from_unix_timestamp(timestamp) {
+                builder = builder.expires(expires);
+            }
+        }
+
+        if let Some(policy) = self.sameSitePolicy() {
+            // SAFETY: The statics are valid `NSString`s.
+            if &*policy == unsafe { NSHTTPCookieSameSiteLax } {
+                builder = builder.same_site(SameSite::Lax);
+            } else if &*policy == unsafe { NSHTTPCookieSameSiteStrict } {
+                builder = builder.same_site(SameSite::Strict);
+            }
+        }
+
+        builder.build()
+    }
+
+    /// Create a cookie from a [`Cookie`].
+    ///
+    /// Returns `None` if the cookie does not have a domain, or if Foundation
+    /// otherwise considers it invalid.
+    #[doc(alias = "cookieWithProperties:")]
+    pub fn from_cookie(cookie: &Cookie<'_>) -> Option<Retained<Self>> {
+        let properties = NSMutableDictionary::<NSString, AnyObject>::new();
+        let insert = |key: &NSString, value: &AnyObject| properties.insert(key, value);
+
+        // SAFETY: The statics are valid `NSString`s.
+        unsafe {
+            insert(NSHTTPCookieName, &NSString::from_str(cookie.name()));
+            insert(NSHTTPCookieValue, &NSString::from_str(cookie.value()));
+            insert(NSHTTPCookieDomain, &NSString::from_str(cookie.domain()?));
+            insert(
+                NSHTTPCookiePath,
+                &NSString::from_str(cookie.path().unwrap_or("/")),
+            );
+            if cookie.secure() == Some(true) {
+                insert(NSHTTPCookieSecure, &NSString::from_str("TRUE"));
+            }
+            if let Some(expires) = cookie.expires_datetime() {
+                let interval = expires.unix_timestamp() as f64;
+                insert(
+                    NSHTTPCookieExpires,
+                    &NSDate::dateWithTimeIntervalSince1970(interval),
+                );
+            }
+            if let Some(max_age) = cookie.max_age() {
+                let seconds = max_age.whole_seconds().to_string();
+                insert(NSHTTPCookieMaximumAge, &NSString::from_str(&seconds));
+            }
+            let same_site = match cookie.same_site() {
+                Some(SameSite::Lax) => Some(NSHTTPCookieSameSiteLax),
+                Some(SameSite::Strict) => Some(NSHTTPCookieSameSiteStrict),
+                _ => None,
+            };
+            if let Some(same_site) = same_site {
+                insert(NSHTTPCookieSameSitePolicy, same_site);
+            }
+        }
+
+        // There is no public property key for `HttpOnly`, but Foundation
+        // recognizes the attribute name.
+        if cookie.http_only() == Some(true) {
+            insert(&NSString::from_str("HttpOnly"), &NSString::from_str("TRUE"));
+        }
+
+        // SAFETY: The values in the dictionary have the types that the
+        // property keys expect.
+        unsafe { Self::cookieWithProperties(&properties) }
+    }
+}
+
+/// Conversions to and from [`cookie::CookieJar`].
+impl NSHTTPCookieStorage {
+    /// Copy all the cookies in the storage into a [`CookieJar`].
+    pub fn to_cookie_jar(&self) -> CookieJar {
+        let mut jar = CookieJar::new();
+        if let Some(cookies) = self.cookies() {
+            for cookie in cookies.to_vec() {
+                jar.add_original(cookie.to_cookie());
+            }
+        }
+        jar
+    }
+
+    /// Store a [`Cookie`], replacing any existing cookie with the same name,
+    /// domain and path.
+    ///
+    /// Returns `false` if the cookie could not be converted, see
+    /// [`NSHTTPCookie::from_cookie`].
+    #[doc(alias = "setCookie:")]
+    pub fn set_cookie(&self, cookie: &Cookie<'_>) -> bool {
+        match NSHTTPCookie::from_cookie(cookie) {
+            Some(cookie) => {
+                self.setCookie(&cookie);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[cfg(target_vendor = "apple")]
+    fn roundtrip() {
+        let expires = OffsetDateTime::from_unix_timestamp(2_000_000_000).unwrap();
+        let cookie = Cookie::build(("session", "abc123"))
+            .domain("example.com")
+            .path("/app")
+            .secure(true)
+            .http_only(true)
+            .same_site(SameSite::Lax)
+            .expires(expires)
+            .build();
+
+        let ns_cookie = NSHTTPCookie::from_cookie(&cookie).unwrap();
+        assert_eq!(ns_cookie.name().to_string(), "session");
+        assert_eq!(ns_cookie.value().to_string(), "abc123");
+        assert!(ns_cookie.isSecure());
+        assert!(ns_cookie.isHTTPOnly());
+
+        let converted = ns_cookie.to_cookie();
+        assert_eq!(converted.name(), "session");
+        assert_eq!(converted.value(), "abc123");
+        assert_eq!(converted.path(), Some("/app"));
+        assert_eq!(converted.secure(), Some(true));
+        assert_eq!(converted.http_only(), Some(true));
+        assert_eq!(converted.same_site(), Some(SameSite::Lax));
+        assert_eq!(converted.expires_datetime(), Some(expires));
+    }
+
+    #[test]
+    fn requires_domain() {
+        let cookie = Cookie::new("name", "value");
+        assert!(NSHTTPCookie::from_cookie(&cookie).is_none());
+    }
+}
@@ -0,0 +1,24 @@
+//! Utilities for the `NSIndexPath` class.
+use core::cmp::Ordering;
+
+use crate::NSIndexPath;
+
+/// Compares the index paths with the Objective-C method "compare:".
+///
+/// Index paths are ordered by their indexes, with a prefix of an index path
+/// ordered before the index path itself.
+impl PartialOrd for NSIndexPath {
+    #[doc(alias = "compare:")]
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Compares the index paths with the Objective-C method "compare:".
+impl Ord for NSIndexPath {
+    #[doc(alias = "compare:")]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.compare(other).into()
+    }
+}
@@ -0,0 +1,200 @@
+//! Utilities for the `NSIndexSet` and `NSMutableIndexSet` classes.
+#[cfg(feature = "block2")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+
+use objc2::rc::{Retained, RetainedFromIterator};
+use objc2::AllocAnyThread;
+
+use crate::util::NOT_FOUND;
+use crate::{NSIndexSet, NSMutableIndexSet, NSRange};
+
+/// Convenience creation methods.
+impl NSIndexSet {
+    /// Create a new index set containing the indexes in the given range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSIndexSet;
+    ///
+    /// let set = NSIndexSet::from_range(2..5);
+    /// assert_eq!(set.len(), 3);
+    /// assert!(set.containsIndex(4));
+    /// assert!(!set.containsIndex(5));
+    /// ```
+    #[doc(alias = "initWithIndexesInRange:")]
+    pub fn from_range(range: Range<usize>) -> Retained<Self> {
+        Self::initWithIndexesInRange(Self::alloc(), NSRange::from(range))
+    }
+}
+
+/// Convenience creation methods.
+impl NSMutableIndexSet {
+    #[doc(alias = "initWithIndexesInRange:")]
+    pub fn from_range(range: Range<usize>) -> Retained<Self> {
+        Self::initWithIndexesInRange(Self::alloc(), NSRange::from(range))
+    }
+}
+
+/// Various accessor methods.
+impl NSIndexSet {
+    /// The number of indexes in the set.
+    #[doc(alias = "count")]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.count()
+    }
+
+    /// Whether the set contains no indexes.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterate over the indexes in the set, in increasing order.
+    ///
+    /// The set is queried for the next index on every iteration, so it is
+    /// fine to mutate the set while iterating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSMutableIndexSet;
+    ///
+    /// let set = NSMutableIndexSet::from_range(1..3);
+    /// set.addIndex(7);
+    /// assert_eq!(set.iter().collect::<Vec<_>>(), [1, 2, 7]);
+    /// ```
+    #[doc(alias = "indexGreaterThanOrEqualToIndex:")]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            set: self,
+            next: Some(0),
+        }
+    }
+
+    /// The contiguous ranges of indexes in the set, in increasing order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSMutableIndexSet;
+    ///
+    /// let set = NSMutableIndexSet::from_range(1..3);
+    /// set.addIndexesInRange((5..9).into());
+    /// assert_eq!(set.ranges(), [1..3, 5..9]);
+    /// ```
+    #[cfg(feature = "block2")]
+    #[doc(alias = "enumerateRangesUsingBlock:")]
+    pub fn ranges(&self) -> Vec<Range<usize>> {
+        use block2::StackBlock;
+        use core::cell::RefCell;
+        use core::ptr::NonNull;
+        use objc2::runtime::Bool;
+
+        let ranges = RefCell::new(Vec::new());
+        let block = StackBlock::new(|range: NSRange, _stop: NonNull<Bool>| {
+            ranges.borrow_mut().push(range.into());
+        });
+        // SAFETY: The block has the correct signature, and does not mutate
+        // the index set.
+        unsafe { self.enumerateRangesUsingBlock(&block) };
+        drop(block);
+        ranges.into_inner()
+    }
+}
+
+/// An iterator over the indexes of an index set.
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    set: &'a NSIndexSet,
+    next: Option<usize>,
+}
+
+impl Iterator for Iter<'_> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        let index = self.set.indexGreaterThanOrEqualToIndex(self.next?);
+        if index == NOT_FOUND {
+            self.next = None;
+            None
+        } else {
+            self.next = index.checked_add(1);
+            Some(index)
+        }
+    }
+}
+
+impl core::iter::FusedIterator for Iter<'_> {}
+
+impl<'a> IntoIterator for &'a NSIndexSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a NSMutableIndexSet {
+    type Item = usize;
+    type IntoIter = Iter<'a>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl fmt::Debug for NSIndexSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self).finish()
+    }
+}
+
+impl fmt::Debug for NSMutableIndexSet {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl Extend<usize> for &NSMutableIndexSet {
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        iter.into_iter().for_each(move |index| self.addIndex(index));
+    }
+}
+
+impl RetainedFromIterator<usize> for NSMutableIndexSet {
+    fn retained_from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Retained<Self> {
+        let set = Self::new();
+        (&*set).extend(iter);
+        set
+    }
+}
+
+impl RetainedFromIterator<usize> for NSIndexSet {
+    fn retained_from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Retained<Self> {
+        NSMutableIndexSet::retained_from_iter(iter).into_super()
+    }
+}
+
+impl PartialEq<NSIndexSet> for NSMutableIndexSet {
+    #[inline]
+    fn eq(&self, other: &NSIndexSet) -> bool {
+        PartialEq::eq(&**self, other)
+    }
+}
+
+impl PartialEq<NSMutableIndexSet> for NSIndexSet {
+    #[inline]
+    fn eq(&self, other: &NSMutableIndexSet) -> bool {
+        PartialEq::eq(self, &**other)
+    }
+}
@@ -388,6 +388,71 @@ impl<C: FastEnumerationHelper> Iterator for Iter<'_, C> {
     }
 }
 
+// SAFETY: `NSFastEnumeration` always enumerates objects, and
+// `ProtocolObject<dyn NSFastEnumeration>` has no lifetime parameter.
+unsafe impl FastEnumerationHelper for ProtocolObject<dyn NSFastEnumeration> {
+    // We don't know the type of the items.
+    type Item = AnyObject;
+
+    #[inline]
+    fn maybe_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// An iterator over the objects in any collection that implements
+/// `NSFastEnumeration`.
+///
+/// This uses `countByEnumeratingWithState:objects:count:` to load the objects
+/// in batches, in the same manner as Objective-C's `for (id obj in
+/// collection)`, and panics if the collection is mutated while iterating.
+///
+/// Prefer using the `iter` methods on the concrete collection types when
+/// possible, as those know the type of the items; this is primarily useful
+/// for collections that do not have dedicated helpers.
+///
+///
+/// # Examples
+///
+#[cfg_attr(all(feature = "NSArray", feature = "NSString"), doc = "```")]
+#[cfg_attr(not(all(feature = "NSArray", feature = "NSString")), doc = "```ignore")]
+/// use objc2::runtime::ProtocolObject;
+/// use objc2_foundation::{ns_string, FastEnumerator, NSArray, NSString};
+///
+/// let array = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+/// let collection = ProtocolObject::from_ref(&*array);
+///
+/// for obj in FastEnumerator::new(collection) {
+///     let string = obj.downcast::<NSString>().unwrap();
+///     assert_eq!(string.len(), 1);
+/// }
+/// ```
+#[derive(Debug)]
+pub struct FastEnumerator<'a>(Iter<'a, ProtocolObject<dyn NSFastEnumeration>>);
+
+impl<'a> FastEnumerator<'a> {
+    /// Start enumerating the given collection.
+    #[inline]
+    pub fn new(collection: &'a ProtocolObject<dyn NSFastEnumeration>) -> Self {
+        Self(Iter::new(collection))
+    }
+}
+
+impl Iterator for FastEnumerator<'_> {
+    type Item = Retained<AnyObject>;
+
+    #[inline]
+    #[track_caller]
+    fn next(&mut self) -> Option<Retained<AnyObject>> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
 // Note: I first considered having `IntoIter<T>` instead of `IntoIter<C>`, but
 // that would be unsound for `NSDictionary<K, V>`, since if the `V` has a
 // lifetime, that lifetime would have been erased (so we'd have to add an
@@ -685,6 +750,23 @@ mod tests {
         assert!(enumerator.enumerate().all(|(i, obj)| obj.as_usize() == i));
     }
 
+    #[test]
+    fn test_fast_enumerator() {
+        let vec: Vec<_> = (0..40).map(NSNumber::new_usize).collect();
+        let array = NSArray::from_retained_slice(&vec);
+
+        let enumerator = FastEnumerator::new(ProtocolObject::from_ref(&*array));
+        assert!(enumerator.enumerate().all(|(i, obj)| obj
+            .downcast::<NSNumber>()
+            .unwrap()
+            .as_usize()
+            == i));
+        assert_eq!(
+            FastEnumerator::new(ProtocolObject::from_ref(&*array)).count(),
+            40
+        );
+    }
+
     #[test]
     fn test_into_enumerator() {
         let vec: Vec<_> = (0..4).map(NSNumber::new_usize).collect();
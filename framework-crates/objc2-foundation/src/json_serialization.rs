@@ -0,0 +1,182 @@
+//! Utilities for the `NSJSONSerialization` class.
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+
+use crate::{
+    NSArray, NSData, NSError, NSJSONReadingOptions, NSJSONSerialization, NSJSONWritingOptions,
+};
+
+impl NSJSONSerialization {
+    /// Parse JSON from a byte slice into a Foundation object graph.
+    ///
+    /// The result consists of `NSDictionary`, `NSArray`, `NSString`,
+    /// `NSNumber` and `NSNull` instances. Top-level values other than
+    /// dictionaries and arrays are only accepted with
+    /// [`NSJSONReadingOptions::FragmentsAllowed`].
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSArray, NSJSONReadingOptions, NSJSONSerialization};
+    ///
+    /// let object = NSJSONSerialization::from_slice(b"[1, 2, 3]", NSJSONReadingOptions::empty())
+    ///     .unwrap();
+    /// let array = object.downcast_ref::<NSArray>().unwrap();
+    /// assert_eq!(array.len(), 3);
+    /// ```
+    #[doc(alias = "JSONObjectWithData:options:error:")]
+    pub fn from_slice(
+        bytes: &[u8],
+        options: NSJSONReadingOptions,
+    ) -> Result<Retained<AnyObject>, Retained<NSError>> {
+        let data = NSData::with_bytes(bytes);
+        Self::JSONObjectWithData_options_error(&data, options)
+    }
+
+    /// Serialize a Foundation object graph into JSON data.
+    ///
+    /// Returns an error in `NSCocoaErrorDomain` if the object cannot be
+    /// represented as JSON, instead of throwing an exception like
+    /// `dataWithJSONObject:options:error:` does.
+    #[cfg(feature = "FoundationErrors")]
+    #[cfg(feature = "NSDictionary")]
+    #[cfg(feature = "NSString")]
+    #[doc(alias = "dataWithJSONObject:options:error:")]
+    #[doc(alias = "isValidJSONObject:")]
+    pub fn to_data(
+        object: &AnyObject,
+        options: NSJSONWritingOptions,
+    ) -> Result<Retained<NSData>, Retained<NSError>> {
+        let is_valid = if options.contains(NSJSONWritingOptions::FragmentsAllowed) {
+            // A value is a valid fragment if it is valid inside an array.
+            Self::isValidJSONObject(&NSArray::from_slice(&[object]))
+        } else {
+            Self::isValidJSONObject(object)
+        };
+        if !is_valid {
+            // SAFETY: The static is a valid, immutable string.
+            let domain = unsafe { crate::NSCocoaErrorDomain };
+            // Also what `NSJSONSerialization` uses for invalid input.
+            return Err(NSError::new(crate::NSPropertyListWriteInvalidError, domain));
+        }
+        // SAFETY: The object was checked to be valid above, so this won't
+        // throw an exception.
+        unsafe { Self::dataWithJSONObject_options_error(object, options) }
+    }
+}
+
+#[cfg(all(
+    feature = "serde_json",
+    feature = "NSDictionary",
+    feature = "NSNull",
+    feature = "NSObject",
+    feature = "NSString",
+    feature = "NSValue"
+))]
+mod serde_json_interop {
+    use alloc::string::ToString;
+    use alloc::vec::Vec;
+
+    use objc2::rc::Retained;
+    use objc2::runtime::AnyObject;
+    use objc2::Message;
+    use serde_json::{Map, Number, Value};
+
+    use crate::{NSArray, NSDictionary, NSJSONSerialization, NSNull, NSNumber, NSString};
+
+    fn upcast<T: Message>(object: Retained<T>) -> Retained<AnyObject> {
+        // SAFETY: All objects can be used as `AnyObject`.
+        unsafe { Retained::cast_unchecked(object) }
+    }
+
+    fn number_to_value(number: &NSNumber) -> Option<Value> {
+        use objc2::encode::Encoding;
+
+        if number.is_bool() {
+            return Some(Value::Bool(number.as_bool()));
+        }
+        Some(match number.encoding() {
+            Encoding::Char
+            | Encoding::Short
+            | Encoding::Int
+            | Encoding::Long
+            | Encoding::LongLong => Value::Number(number.as_i64().into()),
+            Encoding::UChar
+            | Encoding::UShort
+            | Encoding::UInt
+            | Encoding::ULong
+            | Encoding::ULongLong => Value::Number(number.as_u64().into()),
+            _ => Value::Number(Number::from_f64(number.as_f64())?),
+        })
+    }
+
+    /// Interoperability with [`serde_json`].
+    impl NSJSONSerialization {
+        /// Convert a Foundation object graph into a [`serde_json::Value`].
+        ///
+        /// Returns `None` if the graph contains objects other than
+        /// `NSDictionary` with string keys, `NSArray`, `NSString`,
+        /// `NSNumber` and `NSNull`, or non-finite numbers.
+        ///
+        /// Boolean `NSNumber`s become [`Value::Bool`], and integers keep
+        /// their full 64-bit precision.
+        pub fn to_value(object: &AnyObject) -> Option<Value> {
+            if let Some(string) = object.downcast_ref::<NSString>() {
+                Some(Value::String(string.to_string()))
+            } else if let Some(number) = object.downcast_ref::<NSNumber>() {
+                number_to_value(number)
+            } else if object.downcast_ref::<NSNull>().is_some() {
+                Some(Value::Null)
+            } else if let Some(array) = object.downcast_ref::<NSArray>() {
+                array
+                    .to_vec()
+                    .iter()
+                    .map(|object| Self::to_value(object))
+                    .collect::<Option<Vec<_>>>()
+                    .map(Value::Array)
+            } else if let Some(dictionary) = object.downcast_ref::<NSDictionary>() {
+                let (keys, objects) = dictionary.to_vecs();
+                let mut map = Map::with_capacity(keys.len());
+                for (key, object) in keys.iter().zip(objects.iter()) {
+                    let key = key.downcast_ref::<NSString>()?.to_string();
+                    map.insert(key, Self::to_value(object)?);
+                }
+                Some(Value::Object(map))
+            } else {
+                None
+            }
+        }
+
+        /// Convert a [`serde_json::Value`] into a Foundation object graph.
+        ///
+        /// This is the inverse of [`to_value`](Self::to_value).
+        pub fn from_value(value: &Value) -> Retained<AnyObject> {
+            match value {
+                Value::Null => upcast(NSNull::null()),
+                Value::Bool(b) => upcast(NSNumber::new_bool(*b)),
+                Value::Number(number) => upcast(if let Some(n) = number.as_u64() {
+                    NSNumber::new_u64(n)
+                } else if let Some(n) = number.as_i64() {
+                    NSNumber::new_i64(n)
+                } else {
+                    // Always succeeds without `arbitrary_precision`.
+                    NSNumber::new_f64(number.as_f64().unwrap_or(f64::NAN))
+                }),
+                Value::String(string) => upcast(NSString::from_str(string)),
+                Value::Array(array) => {
+                    let objects: Vec<_> = array.iter().map(Self::from_value).collect();
+                    upcast(NSArray::from_retained_slice(&objects))
+                }
+                Value::Object(map) => {
+                    let keys: Vec<_> = map.keys().map(|key| NSString::from_str(key)).collect();
+                    let keys: Vec<&NSString> = keys.iter().map(|key| &**key).collect();
+                    let objects: Vec<_> = map.values().map(Self::from_value).collect();
+                    upcast(NSDictionary::<NSString, AnyObject>::from_retained_objects(
+                        &keys, &objects,
+                    ))
+                }
+            }
+        }
+    }
+}
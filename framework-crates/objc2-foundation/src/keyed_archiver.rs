@@ -0,0 +1,122 @@
+//! Utilities for the `NSKeyedArchiver` and `NSKeyedUnarchiver` classes.
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+
+use crate::{NSData, NSError, NSKeyedArchiver};
+
+impl NSKeyedArchiver {
+    /// Archive an object graph to data, requiring that every object in the
+    /// graph conforms to `NSSecureCoding`.
+    ///
+    /// Use `archivedDataWithRootObject:requiringSecureCoding:error:`
+    /// directly if you need to archive objects that only support
+    /// `NSCoding`.
+    #[doc(alias = "archivedDataWithRootObject:requiringSecureCoding:error:")]
+    pub fn archive_secure(object: &AnyObject) -> Result<Retained<NSData>, Retained<NSError>> {
+        Self::archivedDataWithRootObject_requiringSecureCoding_error(object, true)
+    }
+
+    /// Whether two object graphs produce the same secure archive.
+    ///
+    /// This compares the graphs structurally, including objects that do not
+    /// override `isEqual:`, which is useful for snapshot tests of model
+    /// objects.
+    ///
+    /// Note that the archives are compared byte-for-byte, so collections
+    /// whose order is unspecified (e.g. `NSSet` and `NSDictionary`) may
+    /// compare unequal if they were built differently.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSArray, NSKeyedArchiver};
+    ///
+    /// let a = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    /// let b = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    /// assert!(NSKeyedArchiver::archives_equal(&a, &b).unwrap());
+    /// ```
+    pub fn archives_equal(a: &AnyObject, b: &AnyObject) -> Result<bool, Retained<NSError>> {
+        Ok(Self::archive_secure(a)? == Self::archive_secure(b)?)
+    }
+}
+
+#[cfg(all(
+    feature = "FoundationErrors",
+    feature = "NSSet",
+    feature = "NSDictionary",
+    feature = "NSString"
+))]
+impl crate::NSKeyedUnarchiver {
+    /// Unarchive an object graph with a root object of type `T`, using
+    /// secure coding.
+    ///
+    /// Objects in the graph must be instances of `T` or of one of the
+    /// classes in `allowed_classes` (or their subclasses), otherwise an
+    /// error is returned. Note that the allowed classes apply to all
+    /// objects in the graph, including the elements of collections.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2::ClassType;
+    /// use objc2_foundation::{ns_string, NSArray, NSKeyedArchiver, NSKeyedUnarchiver, NSString};
+    ///
+    /// let array = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    /// let data = NSKeyedArchiver::archive_secure(&array).unwrap();
+    ///
+    /// let array = NSKeyedUnarchiver::unarchive::<NSArray>(&data, &[NSString::class()]).unwrap();
+    /// assert_eq!(array.len(), 2);
+    /// ```
+    #[doc(alias = "unarchivedObjectOfClasses:fromData:error:")]
+    pub fn unarchive<T: objc2::DowncastTarget>(
+        data: &NSData,
+        allowed_classes: &[&objc2::runtime::AnyClass],
+    ) -> Result<Retained<T>, Retained<NSError>> {
+        let mut classes = alloc::vec::Vec::with_capacity(allowed_classes.len() + 1);
+        classes.push(T::class());
+        classes.extend_from_slice(allowed_classes);
+        let classes = crate::NSSet::from_slice(&classes);
+        let object = Self::unarchivedObjectOfClasses_fromData_error(&classes, data)?;
+        object.downcast::<T>().map_err(|_| {
+            // SAFETY: The static is a valid, immutable string.
+            let domain = unsafe { crate::NSCocoaErrorDomain };
+            // Also what the unarchiver uses when the root object is of a
+            // disallowed class.
+            NSError::new(crate::NSCoderReadCorruptError, domain)
+        })
+    }
+
+    /// Make a deep copy of an object graph, by archiving and unarchiving it
+    /// with secure coding.
+    ///
+    /// Unlike `copy`, which for collections only copies the collection
+    /// itself, this copies every object in the graph. Every object must
+    /// conform to `NSSecureCoding`, and the classes of the objects other than
+    /// the root must be listed in `allowed_classes`, see
+    /// [`unarchive`](Self::unarchive).
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSKeyedUnarchiver, NSMutableString};
+    ///
+    /// let string = NSMutableString::from_str("a");
+    /// let copy = NSKeyedUnarchiver::deep_copy(&*string, &[]).unwrap();
+    ///
+    /// string.appendString(ns_string!("b"));
+    /// assert_eq!(copy.to_string(), "a");
+    /// ```
+    pub fn deep_copy<T: objc2::DowncastTarget>(
+        object: &T,
+        allowed_classes: &[&objc2::runtime::AnyClass],
+    ) -> Result<Retained<T>, Retained<NSError>> {
+        let ptr: *const T = object;
+        // SAFETY: All objects can be used as `AnyObject`.
+        let object = unsafe { &*ptr.cast::<AnyObject>() };
+        let data = NSKeyedArchiver::archive_secure(object)?;
+        Self::unarchive(&data, allowed_classes)
+    }
+}
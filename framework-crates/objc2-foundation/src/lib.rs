@@ -37,6 +37,25 @@
 //! and that some things are thread-safe (`Arc`), while others are not (`Rc`).
 //!
 //!
+//! ## Equality, hashing and ordering
+//!
+//! [`PartialEq`], [`Eq`] and [`Hash`] on Foundation objects are implemented
+//! with the Objective-C methods `isEqual:` and `hash`, and [`Ord`] is
+//! implemented with `compare:` on the classes that have it (e.g. `NSString`,
+//! `NSNumber` and `NSDate`). This means that objects behave the same when
+//! used as keys in a [`HashMap`] as they do in an `NSDictionary`.
+//!
+//! Note that this follows Cocoa's conventions, not Rust's. In particular, a
+//! mutable object compares equal to its immutable counterpart if their
+//! contents are equal (so e.g. `NSMutableString` implements
+//! `PartialEq<NSString>`), and mutating an object that is used as a key in
+//! a hash map is a logic error, just like with [`Cell`] in Rust.
+//!
+//! [`Hash`]: core::hash::Hash
+//! [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+//! [`Cell`]: core::cell::Cell
+//!
+//!
 //! ## Examples
 //!
 //! Basic usage of a few Foundation types.
@@ -89,14 +108,30 @@ pub mod array;
 mod attributed_string;
 #[cfg(feature = "NSBundle")]
 mod bundle;
+#[cfg(feature = "NSCache")]
+mod cache;
+#[cfg(feature = "NSCalendar")]
+mod calendar;
+#[cfg(feature = "NSCoder")]
+#[cfg(feature = "NSString")]
+mod coder;
 #[cfg(feature = "NSObjCRuntime")]
 mod comparison_result;
 #[cfg(feature = "NSObject")]
 mod copying;
 #[cfg(feature = "NSData")]
 mod data;
+#[cfg(feature = "NSDate")]
+mod date;
+#[cfg(feature = "NSDateComponentsFormatter")]
+#[cfg(feature = "NSString")]
+mod date_components_formatter;
+#[cfg(feature = "NSDateFormatter")]
+mod date_formatter;
 #[cfg(feature = "NSDecimal")]
 mod decimal;
+#[cfg(feature = "NSDecimalNumber")]
+mod decimal_number;
 #[cfg(feature = "NSDictionary")]
 pub mod dictionary;
 #[cfg(feature = "NSEnumerator")]
@@ -105,36 +140,229 @@ pub mod enumerator;
 mod error;
 #[cfg(feature = "NSException")]
 mod exception;
+#[cfg(feature = "NSExpression")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSString")]
+mod expression;
 #[cfg(feature = "NSEnumerator")]
 mod fast_enumeration_state;
+#[cfg(feature = "NSFileCoordinator")]
+#[cfg(feature = "NSFilePresenter")]
+#[cfg(feature = "NSError")]
+#[cfg(feature = "NSOperation")]
+#[cfg(feature = "NSURL")]
+#[cfg(feature = "block2")]
+#[cfg(feature = "std")]
+pub mod file_coordinator;
+#[cfg(feature = "NSFileHandle")]
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSError")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "std")]
+mod file_handle;
+#[cfg(feature = "NSFileManager")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "NSURL")]
+#[cfg(feature = "NSError")]
+mod file_manager;
 mod generated;
+#[cfg(feature = "NSHashTable")]
+#[cfg(feature = "NSArray")]
+mod hash_table;
 #[cfg(feature = "NSGeometry")]
 mod geometry;
+#[cfg(feature = "NSHost")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "std")]
+mod host;
+#[cfg(feature = "cookie")]
+#[cfg(feature = "NSHTTPCookie")]
+#[cfg(feature = "NSHTTPCookieStorage")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSDate")]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSObject")]
+#[cfg(feature = "NSString")]
+mod http_cookie;
+#[cfg(feature = "NSIndexPath")]
+#[cfg(feature = "NSObjCRuntime")]
+mod index_path;
+#[cfg(feature = "NSIndexSet")]
+#[cfg(feature = "NSRange")]
+pub mod index_set;
+#[cfg(feature = "NSJSONSerialization")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSError")]
+mod json_serialization;
+#[cfg(feature = "NSKeyedArchiver")]
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSError")]
+mod keyed_archiver;
+#[cfg(feature = "NSLinguisticTagger")]
+#[cfg(feature = "NSOrthography")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSRange")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "block2")]
+mod linguistic_tagger;
+#[cfg(feature = "NSLocale")]
+mod locale;
 mod macros;
 #[cfg(feature = "dispatch")]
 mod main_thread_bound;
+#[cfg(feature = "dispatch")]
+#[cfg(feature = "alloc")]
+mod main_thread_executor;
+#[cfg(feature = "NSMapTable")]
+#[cfg(feature = "NSEnumerator")]
+mod map_table;
+#[cfg(feature = "NSMeasurement")]
+#[cfg(feature = "NSUnit")]
+mod measurement;
+#[cfg(feature = "NSMetadata")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSNotification")]
+#[cfg(feature = "NSOperation")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "block2")]
+pub mod metadata;
+#[cfg(feature = "NSNetServices")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "NSValue")]
+pub mod net_service;
 mod ns_consumed;
+#[cfg(feature = "NSNotification")]
+#[cfg(feature = "NSOperation")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "block2")]
+pub mod notification;
+#[cfg(feature = "NSNull")]
+mod null;
 #[cfg(feature = "NSValue")]
 mod number;
+#[cfg(feature = "NSNumberFormatter")]
+mod number_formatter;
+#[cfg(feature = "NSOrderedSet")]
+pub mod ordered_set;
+#[cfg(feature = "NSPointerArray")]
+mod pointer_array;
+#[cfg(feature = "NSPredicate")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSString")]
+mod predicate;
+#[cfg(feature = "serde")]
+#[cfg(feature = "NSPropertyList")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSDate")]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSError")]
+#[cfg(feature = "NSObject")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "NSValue")]
+pub mod plist;
 #[cfg(feature = "NSProcessInfo")]
 mod process_info;
+#[cfg(feature = "NSProgress")]
+mod progress;
+#[cfg(feature = "NSPropertyList")]
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSError")]
+mod property_list_serialization;
 #[cfg(feature = "NSRange")]
 mod range;
+#[cfg(feature = "NSRegularExpression")]
+#[cfg(feature = "NSTextCheckingResult")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "NSRange")]
+mod regular_expression;
+#[cfg(feature = "NSRunLoop")]
+#[cfg(feature = "NSObjCRuntime")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "block2")]
+mod run_loop;
+#[cfg(feature = "std")]
+mod send_wrapper;
+#[cfg(feature = "serde")]
+#[cfg(feature = "NSString")]
+mod serde_impls;
 #[cfg(feature = "NSSet")]
 pub mod set;
+#[cfg(feature = "NSSortDescriptor")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSString")]
+mod sort_descriptor;
+#[cfg(feature = "NSStream")]
+#[cfg(feature = "NSError")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "std")]
+mod stream;
 #[cfg(feature = "NSString")]
 mod string;
 #[cfg(test)]
 mod tests;
+#[cfg(feature = "NSTask")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSError")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "NSURL")]
+mod task;
 #[cfg(feature = "NSThread")]
 mod thread;
+#[cfg(feature = "NSTimeZone")]
+mod time_zone;
 #[cfg(feature = "NSObject")]
 mod to_owned;
+#[cfg(feature = "NSUbiquitousKeyValueStore")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSNotification")]
+#[cfg(feature = "NSOperation")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "NSValue")]
+#[cfg(feature = "block2")]
+pub mod ubiquitous_key_value_store;
+#[cfg(feature = "NSURL")]
+mod url;
+#[cfg(feature = "NSURLCache")]
+mod url_cache;
+#[cfg(feature = "NSURLCredential")]
+mod url_credential;
+#[cfg(feature = "NSURLSession")]
+#[cfg(feature = "NSURLRequest")]
+#[cfg(feature = "NSURLResponse")]
+#[cfg(feature = "NSURLError")]
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSError")]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "block2")]
+#[cfg(feature = "std")]
+pub mod url_session;
+#[cfg(feature = "NSUserDefaults")]
+#[cfg(feature = "NSString")]
+mod user_defaults;
 mod util;
 #[cfg(feature = "NSUUID")]
 mod uuid;
 #[cfg(feature = "NSValue")]
 mod value;
+#[cfg(feature = "NSXMLParser")]
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSError")]
+#[cfg(feature = "NSString")]
+pub mod xml_parser;
+#[cfg(feature = "NSXPCConnection")]
+#[cfg(feature = "NSError")]
+#[cfg(feature = "block2")]
+mod xpc_connection;
 
 #[cfg(feature = "NSObjCRuntime")]
 pub use self::comparison_result::NSComparisonResult;
@@ -144,18 +372,27 @@ pub use self::copying::{CopyingHelper, MutableCopyingHelper, NSCopying, NSMutabl
 pub use self::decimal::NSDecimal;
 #[cfg(feature = "NSEnumerator")]
 pub use self::fast_enumeration_state::NSFastEnumerationState;
+#[cfg(feature = "NSEnumerator")]
+pub use self::iter::FastEnumerator;
 #[allow(unused_imports, unreachable_pub)]
 pub use self::generated::*;
 #[cfg(feature = "NSGeometry")]
 pub use self::geometry::{CGFloat, CGPoint, CGRect, CGSize, NSPoint, NSRect, NSRectEdge, NSSize};
 #[cfg(feature = "dispatch")]
 pub use self::main_thread_bound::*;
+#[cfg(feature = "dispatch")]
+#[cfg(feature = "alloc")]
+pub use self::main_thread_executor::{spawn_local_on_main, spawn_on_main};
 #[cfg(feature = "NSMapTable")]
 pub use self::ns_consumed::NSFreeMapTable;
 #[cfg(feature = "NSRange")]
 pub use self::range::NSRange;
+#[cfg(feature = "std")]
+pub use self::send_wrapper::SendWrapper;
 #[cfg(feature = "NSThread")]
 pub use self::thread::*;
+#[cfg(feature = "NSURLCredential")]
+pub use self::url_credential::{SecIdentity, SecTrust};
 
 // Available under Foundation, so makes sense here as well:
 // https://developer.apple.com/documentation/foundation/numbers_data_and_basic_values?language=objc
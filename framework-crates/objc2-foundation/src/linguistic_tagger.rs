@@ -0,0 +1,203 @@
+//! Utilities for the `NSLinguisticTagger` and `NSOrthography` classes.
+//!
+//! Note that the ranges returned by these methods are measured in UTF-16
+//! code units, see [`NSString::len_utf16`].
+//!
+//! `NSLinguisticTagger` is deprecated in favour of the NaturalLanguage
+//! framework, but is still useful for tokenizing text the same way as
+//! existing Objective-C code does.
+// The entire `NSLinguisticTagger` class is marked as deprecated.
+#![allow(deprecated)]
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::ops::Range;
+use core::ptr::NonNull;
+
+use block2::StackBlock;
+use objc2::rc::Retained;
+use objc2::runtime::Bool;
+use objc2::AllocAnyThread;
+
+use crate::{
+    NSArray, NSLinguisticTag, NSLinguisticTagScheme, NSLinguisticTagger, NSLinguisticTaggerOptions,
+    NSLinguisticTaggerUnit, NSOrthography, NSRange, NSString,
+};
+
+/// Creation methods.
+impl NSLinguisticTagger {
+    /// Create a new tagger for the given tag schemes, and set the string to
+    /// be tagged.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// use objc2_foundation::{
+    ///     ns_string, NSLinguisticTagSchemeTokenType, NSLinguisticTagger,
+    ///     NSLinguisticTaggerOptions, NSLinguisticTaggerUnit,
+    /// };
+    ///
+    /// let scheme = unsafe { NSLinguisticTagSchemeTokenType };
+    /// let tagger = NSLinguisticTagger::with_string(&[scheme], ns_string!("Hello, world!"));
+    /// let tokens: Vec<_> = tagger
+    ///     .tags(
+    ///         NSLinguisticTaggerUnit::Word,
+    ///         scheme,
+    ///         NSLinguisticTaggerOptions::OmitWhitespace,
+    ///     )
+    ///     .into_iter()
+    ///     .map(|(_, range)| range)
+    ///     .collect();
+    /// assert_eq!(tokens, [0..5, 5..6, 7..12, 12..13]);
+    /// ```
+    #[doc(alias = "initWithTagSchemes:options:")]
+    #[doc(alias = "setString:")]
+    pub fn with_string(schemes: &[&NSLinguisticTagScheme], string: &NSString) -> Retained<Self> {
+        let schemes = NSArray::from_slice(schemes);
+        let tagger = Self::initWithTagSchemes_options(Self::alloc(), &schemes, 0);
+        tagger.setString(Some(string));
+        tagger
+    }
+}
+
+/// Tagging methods.
+///
+/// These all work on the entire string, use the underlying methods directly
+/// if you need to restrict them to a specific range.
+impl NSLinguisticTagger {
+    /// The tags and UTF-16 ranges of all the tokens of the given unit in
+    /// the string.
+    ///
+    /// The tag is `None` for tokens that the scheme does not assign a tag.
+    #[doc(alias = "enumerateTagsInRange:unit:scheme:options:usingBlock:")]
+    pub fn tags(
+        &self,
+        unit: NSLinguisticTaggerUnit,
+        scheme: &NSLinguisticTagScheme,
+        options: NSLinguisticTaggerOptions,
+    ) -> Vec<(Option<Retained<NSLinguisticTag>>, Range<usize>)> {
+        let Some(string) = self.string() else {
+            return Vec::new();
+        };
+        let tags = RefCell::new(Vec::new());
+        let block = StackBlock::new(
+            |tag: *mut NSLinguisticTag, range: NSRange, _stop: NonNull<Bool>| {
+                // SAFETY: The tag is either NULL or a valid string.
+                let tag = unsafe { Retained::retain(tag) };
+                tags.borrow_mut().push((tag, range.into()));
+            },
+        );
+        // SAFETY: The range is within the string, and the block has the
+        // correct signature and does not modify the tagger.
+        unsafe {
+            self.enumerateTagsInRange_unit_scheme_options_usingBlock(
+                NSRange::new(0, string.len_utf16()),
+                unit,
+                scheme,
+                options,
+                &block,
+            )
+        };
+        drop(block);
+        tags.into_inner()
+    }
+
+    /// The tag and UTF-16 range of the token of the given unit that
+    /// contains the given UTF-16 index.
+    ///
+    /// Returns `None` if the index is out of bounds, or if the scheme does
+    /// not assign a tag to the token.
+    #[doc(alias = "tagAtIndex:unit:scheme:tokenRange:")]
+    pub fn tag_at(
+        &self,
+        index: usize,
+        unit: NSLinguisticTaggerUnit,
+        scheme: &NSLinguisticTagScheme,
+    ) -> Option<(Retained<NSLinguisticTag>, Range<usize>)> {
+        if index >= self.string()?.len_utf16() {
+            return None;
+        }
+        let mut range = NSRange::new(0, 0);
+        // SAFETY: The index was checked to be in bounds above, and the token
+        // range is a valid pointer.
+        let tag =
+            unsafe { self.tagAtIndex_unit_scheme_tokenRange(index, unit, scheme, &mut range) };
+        Some((tag?, range.into()))
+    }
+
+    /// Use the given orthography for the entire string, instead of letting
+    /// the tagger determine it.
+    #[doc(alias = "setOrthography:range:")]
+    pub fn set_orthography(&self, orthography: &NSOrthography) {
+        let len = self.string().map_or(0, |string| string.len_utf16());
+        // SAFETY: The range is within the string.
+        unsafe { self.setOrthography_range(Some(orthography), NSRange::new(0, len)) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(target_vendor = "apple")]
+    fn token_types() {
+        use alloc::string::ToString;
+
+        use super::*;
+        use crate::{
+            ns_string, NSLinguisticTagPunctuation, NSLinguisticTagSchemeTokenType,
+            NSLinguisticTagWord,
+        };
+
+        let scheme = unsafe { NSLinguisticTagSchemeTokenType };
+        let tagger = NSLinguisticTagger::with_string(&[scheme], ns_string!("Hi, there!"));
+        let orthography = NSOrthography::defaultOrthographyForLanguage(ns_string!("en"));
+        tagger.set_orthography(&orthography);
+
+        let tags: Vec<_> = tagger
+            .tags(
+                NSLinguisticTaggerUnit::Word,
+                scheme,
+                NSLinguisticTaggerOptions::OmitWhitespace,
+            )
+            .into_iter()
+            .map(|(tag, range)| (tag.unwrap().to_string(), range))
+            .collect();
+        let (word, punctuation) = unsafe {
+            (
+                NSLinguisticTagWord.to_string(),
+                NSLinguisticTagPunctuation.to_string(),
+            )
+        };
+        assert_eq!(
+            tags,
+            [
+                (word.clone(), 0..2),
+                (punctuation.clone(), 2..3),
+                (word.clone(), 4..9),
+                (punctuation, 9..10),
+            ]
+        );
+
+        let (tag, range) = tagger
+            .tag_at(1, NSLinguisticTaggerUnit::Word, scheme)
+            .unwrap();
+        assert_eq!(tag.to_string(), word);
+        assert_eq!(range, 0..2);
+        assert!(tagger
+            .tag_at(10, NSLinguisticTaggerUnit::Word, scheme)
+            .is_none());
+    }
+
+    #[test]
+    #[cfg(target_vendor = "apple")]
+    fn default_orthography() {
+        use alloc::string::ToString;
+
+        use crate::{ns_string, NSOrthography};
+
+        let orthography = NSOrthography::defaultOrthographyForLanguage(ns_string!("en"));
+        assert_eq!(orthography.dominantScript().to_string(), "Latn");
+        assert_eq!(orthography.dominantLanguage().to_string(), "en");
+    }
+}
@@ -0,0 +1,29 @@
+//! Utilities for the `NSLocale` class.
+use objc2::rc::Retained;
+
+use crate::NSLocale;
+
+/// Convenience creation methods.
+impl NSLocale {
+    /// The `en_US_POSIX` locale.
+    ///
+    /// This locale is guaranteed not to change behaviour between OS
+    /// versions or user settings, and should be used when formatting or
+    /// parsing data that is meant for machines instead of humans.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSLocale;
+    ///
+    /// let locale = NSLocale::posix();
+    /// assert_eq!(locale.localeIdentifier().to_string(), "en_US_POSIX");
+    /// assert_eq!(locale.decimalSeparator().to_string(), ".");
+    /// ```
+    #[cfg(feature = "NSString")]
+    #[doc(alias = "en_US_POSIX")]
+    pub fn posix() -> Retained<Self> {
+        Self::localeWithLocaleIdentifier(crate::ns_string!("en_US_POSIX"))
+    }
+}
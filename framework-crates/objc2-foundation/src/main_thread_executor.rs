@@ -0,0 +1,151 @@
+//! A minimal executor that polls futures on the main thread.
+//!
+//! Cocoa applications already run an event loop on the main thread, which
+//! also services the main dispatch queue. Instead of running a separate
+//! executor on another thread, the futures spawned here are polled by
+//! submitting work to that queue whenever they are woken.
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::task::Wake;
+use core::cell::RefCell;
+use core::future::Future;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+use core::task::{Context, Waker};
+
+use objc2::MainThreadMarker;
+
+use crate::MainThreadBound;
+
+type LocalFuture = Pin<Box<dyn Future<Output = ()>>>;
+
+struct Task {
+    /// `None` once the future has completed.
+    future: MainThreadBound<RefCell<Option<LocalFuture>>>,
+    /// Whether a poll has been submitted to the main queue, but not yet run.
+    ///
+    /// Used to avoid submitting more than one poll when the task is woken
+    /// several times in a row.
+    scheduled: AtomicBool,
+    /// Whether the task was run while it was already being polled further
+    /// up the stack, and must be polled again once that poll returns.
+    repoll: AtomicBool,
+}
+
+impl Task {
+    fn schedule(self: Arc<Self>) {
+        if !self.scheduled.swap(true, Ordering::AcqRel) {
+            dispatch::Queue::main().exec_async(move || {
+                // SAFETY: The closure is submitted to the main queue, so
+                // it's guaranteed to run on the main thread.
+                let mtm = unsafe { MainThreadMarker::new_unchecked() };
+                self.run(mtm);
+            });
+        }
+    }
+
+    fn run(self: Arc<Self>, mtm: MainThreadMarker) {
+        // Reset before polling, such that wakes during the poll schedule
+        // another poll.
+        self.scheduled.store(false, Ordering::Release);
+
+        let Ok(mut slot) = self.future.get(mtm).try_borrow_mut() else {
+            // The future is already being polled, and ran a nested run loop
+            // (such as a modal session) which serviced the main queue. Defer
+            // the wake until the outer poll has returned.
+            self.repoll.store(true, Ordering::Release);
+            return;
+        };
+        let waker = Waker::from(Arc::clone(&self));
+        let mut cx = Context::from_waker(&waker);
+        if let Some(future) = slot.as_mut() {
+            if future.as_mut().poll(&mut cx).is_ready() {
+                *slot = None;
+            }
+        }
+        let done = slot.is_none();
+        drop(slot);
+        if self.repoll.swap(false, Ordering::AcqRel) && !done {
+            self.schedule();
+        }
+    }
+}
+
+impl Wake for Task {
+    fn wake(self: Arc<Self>) {
+        self.schedule();
+    }
+}
+
+/// Spawn a future that is polled on the main thread.
+///
+/// The future is first polled on the next iteration of the main thread's
+/// event loop, and again every time it is woken, regardless of which thread
+/// it was woken from. This allows awaiting futures created from completion
+/// handlers in a GUI application, without running a second event loop on
+/// another thread.
+///
+/// The future is driven by the main dispatch queue, so this should only be
+/// used in applications whose main thread is running an event loop with
+/// `dispatch_main`, `UIApplicationMain`, `NSApplicationMain`, `NSRunLoop`
+/// or similar; otherwise the future is never polled.
+///
+/// See [`spawn_local_on_main`] for futures that are not [`Send`].
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use objc2_foundation::spawn_on_main;
+///
+/// spawn_on_main(async {
+///     // Await futures here, and update the UI once they have completed.
+/// });
+/// ```
+pub fn spawn_on_main<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    dispatch::Queue::main().exec_async(move || {
+        // SAFETY: The closure is submitted to the main queue, so it's
+        // guaranteed to run on the main thread.
+        let mtm = unsafe { MainThreadMarker::new_unchecked() };
+        spawn_local_on_main(future, mtm);
+    });
+}
+
+/// Spawn a future that is polled on the main thread, from the main thread.
+///
+/// Unlike [`spawn_on_main`], the future does not have to be [`Send`], and
+/// can thus hold on to main-thread-only objects such as views and windows.
+///
+/// See [`spawn_on_main`] for details and caveats.
+///
+///
+/// # Example
+///
+/// ```no_run
+/// use objc2::MainThreadMarker;
+/// use objc2_foundation::spawn_local_on_main;
+///
+/// let mtm = MainThreadMarker::new().expect("must be on the main thread");
+/// spawn_local_on_main(
+///     async move {
+///         // `mtm` can be used inside the future.
+///         let _mtm = mtm;
+///     },
+///     mtm,
+/// );
+/// ```
+pub fn spawn_local_on_main<F>(future: F, mtm: MainThreadMarker)
+where
+    F: Future<Output = ()> + 'static,
+{
+    let future: LocalFuture = Box::pin(future);
+    let task = Arc::new(Task {
+        future: MainThreadBound::new(RefCell::new(Some(future)), mtm),
+        scheduled: AtomicBool::new(false),
+        repoll: AtomicBool::new(false),
+    });
+    task.schedule();
+}
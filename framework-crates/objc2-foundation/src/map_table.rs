@@ -0,0 +1,51 @@
+//! Utilities for the `NSMapTable` class.
+use alloc::vec::Vec;
+
+use objc2::rc::Retained;
+use objc2::Message;
+
+use crate::NSMapTable;
+
+impl<KeyType: Message, ObjectType: Message> NSMapTable<KeyType, ObjectType> {
+    /// Take a snapshot of the key-value pairs that are currently in the
+    /// table.
+    ///
+    /// For tables holding weak references, entries where the key or the
+    /// value has been deallocated are not included, and the remaining keys
+    /// and values are retained for as long as the snapshot is alive.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSMapTable, NSObject, NSString};
+    ///
+    /// let table = NSMapTable::<NSString, NSObject>::strongToWeakObjectsMapTable();
+    /// let object = NSObject::new();
+    /// table.setObject_forKey(Some(&object), Some(ns_string!("key")));
+    /// assert_eq!(table.snapshot().len(), 1);
+    /// ```
+    #[doc(alias = "keyEnumerator")]
+    #[doc(alias = "objectForKey:")]
+    pub fn snapshot(&self) -> Vec<(Retained<KeyType>, Retained<ObjectType>)> {
+        // Collect the keys first, the table must not be accessed while it
+        // is being enumerated.
+        let keys: Vec<Retained<KeyType>> = self.keyEnumerator().into_iter().collect();
+        keys.into_iter()
+            .filter_map(|key| {
+                let object = self.objectForKey(Some(&key))?;
+                Some((key, object))
+            })
+            .collect()
+    }
+
+    /// The number of entries in the table whose key and value are still
+    /// alive.
+    ///
+    /// Unlike `count`, this does not include entries with weak references
+    /// whose object has been deallocated, but which have not yet been
+    /// purged from the table.
+    pub fn live_count(&self) -> usize {
+        self.snapshot().len()
+    }
+}
@@ -0,0 +1,40 @@
+//! Utilities for the `NSMeasurement` class.
+use objc2::rc::Retained;
+use objc2::{AllocAnyThread, Message};
+
+use crate::NSMeasurement;
+
+/// Convenience methods.
+impl<UnitType: Message> NSMeasurement<UnitType> {
+    /// Create a new measurement with the given value and unit.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// Convert between units of the same dimension.
+    ///
+    /// ```
+    /// use objc2_foundation::{NSMeasurement, NSUnitLength};
+    ///
+    /// let distance = NSMeasurement::new(5.0, &NSUnitLength::kilometers());
+    /// let distance = distance.convert_to(&NSUnitLength::meters());
+    /// assert_eq!(distance.doubleValue(), 5000.0);
+    /// ```
+    #[doc(alias = "initWithDoubleValue:unit:")]
+    pub fn new(value: f64, unit: &UnitType) -> Retained<Self> {
+        Self::initWithDoubleValue_unit(Self::alloc(), value, unit)
+    }
+
+    /// Convert the measurement to the given unit of the same dimension.
+    #[doc(alias = "measurementByConvertingToUnit:")]
+    pub fn convert_to(&self, unit: &UnitType) -> Retained<Self>
+    where
+        UnitType: AsRef<crate::NSDimension>,
+    {
+        let dimension: &crate::NSDimension = unit.as_ref();
+        let measurement = self.measurementByConvertingToUnit(dimension);
+        // SAFETY: The unit of the converted measurement is the given unit,
+        // which is of type `UnitType`.
+        unsafe { Retained::cast_unchecked(measurement) }
+    }
+}
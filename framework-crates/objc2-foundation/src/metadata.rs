@@ -0,0 +1,237 @@
+//! Utilities for the `NSMetadataQuery` and `NSMetadataItem` classes.
+//!
+//! Spotlight queries gather their results asynchronously on the run loop of
+//! the thread they were started on (usually the main thread), and keep them
+//! updated as files change. Use [`NSMetadataQuery::observe`] to be notified
+//! about this in a Rust closure.
+use alloc::vec::Vec;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::DowncastTarget;
+
+use crate::notification::Observer;
+use crate::{
+    NSArray, NSMetadataItem, NSMetadataQuery, NSMetadataQueryDidFinishGatheringNotification,
+    NSMetadataQueryDidStartGatheringNotification, NSMetadataQueryDidUpdateNotification,
+    NSMetadataQueryGatheringProgressNotification, NSMetadataQueryUpdateAddedItemsKey,
+    NSMetadataQueryUpdateChangedItemsKey, NSMetadataQueryUpdateRemovedItemsKey, NSNotification,
+    NSNotificationCenter, NSString,
+};
+
+/// An event reported by a running [`NSMetadataQuery`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum QueryEvent {
+    /// The query started gathering the initial results.
+    #[doc(alias = "NSMetadataQueryDidStartGatheringNotification")]
+    StartedGathering,
+    /// Some of the initial results have been gathered.
+    #[doc(alias = "NSMetadataQueryGatheringProgressNotification")]
+    GatheringProgress,
+    /// All the initial results have been gathered.
+    #[doc(alias = "NSMetadataQueryDidFinishGatheringNotification")]
+    FinishedGathering,
+    /// The results changed after the initial gathering, because files
+    /// matching the query were created, modified or deleted.
+    #[doc(alias = "NSMetadataQueryDidUpdateNotification")]
+    Updated {
+        /// The items that were added to the results.
+        added: Vec<Retained<NSMetadataItem>>,
+        /// The items in the results whose attributes changed.
+        changed: Vec<Retained<NSMetadataItem>>,
+        /// The items that were removed from the results.
+        removed: Vec<Retained<NSMetadataItem>>,
+    },
+}
+
+impl QueryEvent {
+    pub(crate) fn from_notification(notification: &NSNotification) -> Option<Self> {
+        let name = notification.name();
+        // SAFETY: The statics are valid notification names.
+        unsafe {
+            if &*name == NSMetadataQueryDidStartGatheringNotification {
+                Some(Self::StartedGathering)
+            } else if &*name == NSMetadataQueryGatheringProgressNotification {
+                Some(Self::GatheringProgress)
+            } else if &*name == NSMetadataQueryDidFinishGatheringNotification {
+                Some(Self::FinishedGathering)
+            } else if &*name == NSMetadataQueryDidUpdateNotification {
+                Some(Self::Updated {
+                    added: updated_items(notification, NSMetadataQueryUpdateAddedItemsKey),
+                    changed: updated_items(notification, NSMetadataQueryUpdateChangedItemsKey),
+                    removed: updated_items(notification, NSMetadataQueryUpdateRemovedItemsKey),
+                })
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn updated_items(notification: &NSNotification, key: &NSString) -> Vec<Retained<NSMetadataItem>> {
+    let Some(user_info) = notification.userInfo() else {
+        return Vec::new();
+    };
+    let Some(items) = user_info.objectForKey(key.as_ref()) else {
+        return Vec::new();
+    };
+    // SAFETY: The update keys are documented to contain arrays of
+    // `NSMetadataItem`.
+    let items = unsafe { Retained::cast_unchecked::<NSArray<NSMetadataItem>>(items) };
+    items.to_vec()
+}
+
+impl NSMetadataQuery {
+    /// Register a closure to be called when the query gathers or updates
+    /// its results.
+    ///
+    /// The closure is called on the thread that the query was started on,
+    /// with updates to the results disabled, so that they can be safely
+    /// inspected from within the closure.
+    ///
+    /// The returned [`Observer`] unregisters the closure when dropped.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2::runtime::AnyObject;
+    /// use objc2_foundation::metadata::QueryEvent;
+    /// use objc2_foundation::{
+    ///     ns_string, NSArray, NSMetadataQuery, NSMetadataQueryUserHomeScope, NSPredicate,
+    ///     NSRunLoop,
+    /// };
+    ///
+    /// let query = NSMetadataQuery::new();
+    /// // SAFETY: The format only compares the file name.
+    /// let predicate =
+    ///     unsafe { NSPredicate::from_format(ns_string!("kMDItemFSName LIKE '*.rs'"), &[]) };
+    /// query.setPredicate(Some(&predicate));
+    /// let scope: &AnyObject = unsafe { NSMetadataQueryUserHomeScope }.as_ref();
+    /// // SAFETY: The scope is one of the predefined search scopes.
+    /// unsafe { query.setSearchScopes(&NSArray::from_slice(&[scope])) };
+    ///
+    /// let _observer = query.observe(|query, event| {
+    ///     if let QueryEvent::FinishedGathering = event {
+    ///         for item in query.items() {
+    ///             println!("{:?}", item.path());
+    ///         }
+    ///     }
+    /// });
+    /// // SAFETY: The predicate is valid for Spotlight.
+    /// unsafe { query.startQuery() };
+    /// NSRunLoop::currentRunLoop().run();
+    /// ```
+    pub fn observe(
+        &self,
+        f: impl Fn(&NSMetadataQuery, QueryEvent) + Send + Sync + 'static,
+    ) -> Observer {
+        let center = NSNotificationCenter::defaultCenter();
+        center.observe(None, Some(self.as_ref()), move |notification| {
+            let Some(event) = QueryEvent::from_notification(notification) else {
+                return;
+            };
+            let Some(query) = notification.object() else {
+                return;
+            };
+            let Ok(query) = query.downcast::<NSMetadataQuery>() else {
+                return;
+            };
+            query.disableUpdates();
+            f(&query, event);
+            query.enableUpdates();
+        })
+    }
+
+    /// The results of the query that are metadata items.
+    ///
+    /// Updates to the results are disabled while they are copied.
+    #[doc(alias = "results")]
+    pub fn items(&self) -> Vec<Retained<NSMetadataItem>> {
+        self.disableUpdates();
+        let results = self.results().to_vec();
+        self.enableUpdates();
+        results
+            .into_iter()
+            .filter_map(|result| result.downcast().ok())
+            .collect()
+    }
+}
+
+impl NSMetadataItem {
+    /// Look up the value of an attribute, and attempt to downcast it to the
+    /// given type.
+    ///
+    /// Returns `None` if the item does not have the attribute, or if the
+    /// value is not of the expected type.
+    #[doc(alias = "valueForAttribute:")]
+    pub fn attribute<T: DowncastTarget>(&self, key: &NSString) -> Option<Retained<T>> {
+        let value: Retained<AnyObject> = self.valueForAttribute(key)?;
+        value.downcast().ok()
+    }
+
+    /// The path of the file.
+    #[cfg(feature = "NSMetadataAttributes")]
+    #[doc(alias = "NSMetadataItemPathKey")]
+    pub fn path(&self) -> Option<Retained<NSString>> {
+        // SAFETY: The static is a valid attribute name.
+        self.attribute(unsafe { crate::NSMetadataItemPathKey })
+    }
+
+    /// The name of the file, as displayed to the user.
+    #[cfg(feature = "NSMetadataAttributes")]
+    #[doc(alias = "NSMetadataItemDisplayNameKey")]
+    pub fn display_name(&self) -> Option<Retained<NSString>> {
+        // SAFETY: The static is a valid attribute name.
+        self.attribute(unsafe { crate::NSMetadataItemDisplayNameKey })
+    }
+
+    /// The size of the file in bytes.
+    #[cfg(feature = "NSMetadataAttributes")]
+    #[cfg(feature = "NSValue")]
+    #[doc(alias = "NSMetadataItemFSSizeKey")]
+    pub fn file_size(&self) -> Option<u64> {
+        // SAFETY: The static is a valid attribute name.
+        let size = self.attribute::<crate::NSNumber>(unsafe { crate::NSMetadataItemFSSizeKey })?;
+        Some(size.as_u64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(target_os = "macos")]
+    fn event_from_notification() {
+        use super::*;
+        use crate::{
+            ns_string, NSMetadataQueryDidFinishGatheringNotification,
+            NSMetadataQueryDidUpdateNotification,
+        };
+
+        let name = unsafe { NSMetadataQueryDidFinishGatheringNotification };
+        let notification = NSNotification::notificationWithName_object(name, None);
+        assert!(matches!(
+            QueryEvent::from_notification(&notification),
+            Some(QueryEvent::FinishedGathering)
+        ));
+
+        let name = unsafe { NSMetadataQueryDidUpdateNotification };
+        let notification = NSNotification::notificationWithName_object(name, None);
+        match QueryEvent::from_notification(&notification) {
+            Some(QueryEvent::Updated {
+                added,
+                changed,
+                removed,
+            }) => {
+                assert!(added.is_empty());
+                assert!(changed.is_empty());
+                assert!(removed.is_empty());
+            }
+            event => panic!("unexpected event {event:?}"),
+        }
+
+        let notification = NSNotification::notificationWithName_object(ns_string!("Other"), None);
+        assert!(QueryEvent::from_notification(&notification).is_none());
+    }
+}
@@ -0,0 +1,555 @@
+//! Utilities for the `NSNetService` and `NSNetServiceBrowser` classes.
+//!
+//! Bonjour services are published, discovered and resolved asynchronously,
+//! with events reported to a delegate on the run loop that the service or
+//! browser is scheduled in (by default the current run loop). Implement
+//! [`ServiceDelegate`] or [`BrowserDelegate`] to handle these events in
+//! Rust.
+use alloc::boxed::Box;
+use core::cell::RefCell;
+#[cfg(feature = "std")]
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
+
+use objc2::rc::Retained;
+use objc2::runtime::{NSObject, NSObjectProtocol, ProtocolObject};
+use objc2::{define_class, msg_send_id, AllocAnyThread, DefinedClass};
+
+use crate::util::retain_delegate;
+use crate::{
+    NSData, NSDictionary, NSNetService, NSNetServiceBrowser, NSNetServiceBrowserDelegate,
+    NSNetServiceDelegate, NSNumber, NSString,
+};
+
+/// Handler for the events of an [`NSNetService`].
+///
+/// All methods have a default implementation that does nothing.
+///
+/// The error dictionaries contain the error code under the key
+/// `NSNetServicesErrorCode`, and the error domain under the key
+/// `NSNetServicesErrorDomain`.
+#[allow(unused_variables)]
+pub trait ServiceDelegate {
+    /// The service is about to be published.
+    #[doc(alias = "netServiceWillPublish:")]
+    fn will_publish(&mut self, service: &NSNetService) {}
+
+    /// The service was published.
+    #[doc(alias = "netServiceDidPublish:")]
+    fn did_publish(&mut self, service: &NSNetService) {}
+
+    /// The service could not be published.
+    #[doc(alias = "netService:didNotPublish:")]
+    fn did_not_publish(
+        &mut self,
+        service: &NSNetService,
+        error: &NSDictionary<NSString, NSNumber>,
+    ) {
+    }
+
+    /// The service is about to be resolved.
+    #[doc(alias = "netServiceWillResolve:")]
+    fn will_resolve(&mut self, service: &NSNetService) {}
+
+    /// An address of the service was resolved.
+    ///
+    /// This may be called several times, as more addresses are found. Use
+    /// [`NSNetService::socket_addrs`] to get the addresses.
+    #[doc(alias = "netServiceDidResolveAddress:")]
+    fn did_resolve_address(&mut self, service: &NSNetService) {}
+
+    /// The service could not be resolved.
+    #[doc(alias = "netService:didNotResolve:")]
+    fn did_not_resolve(
+        &mut self,
+        service: &NSNetService,
+        error: &NSDictionary<NSString, NSNumber>,
+    ) {
+    }
+
+    /// A publish or resolve request was stopped.
+    #[doc(alias = "netServiceDidStop:")]
+    fn did_stop(&mut self, service: &NSNetService) {}
+
+    /// The TXT record of the service was updated.
+    ///
+    /// Use [`NSNetService::txt_record`] to decode the data.
+    #[doc(alias = "netService:didUpdateTXTRecordData:")]
+    fn did_update_txt_record_data(&mut self, service: &NSNetService, data: &NSData) {}
+}
+
+/// Handler for the events of an [`NSNetServiceBrowser`].
+///
+/// All methods have a default implementation that does nothing.
+///
+/// When several domains or services are found or removed at once,
+/// `more_coming` is `true` for all but the last of them, which allows
+/// delaying updates to the user interface until the batch is complete.
+#[allow(unused_variables)]
+pub trait BrowserDelegate {
+    /// The browser is about to start searching.
+    #[doc(alias = "netServiceBrowserWillSearch:")]
+    fn will_search(&mut self, browser: &NSNetServiceBrowser) {}
+
+    /// The browser stopped searching.
+    #[doc(alias = "netServiceBrowserDidStopSearch:")]
+    fn did_stop_search(&mut self, browser: &NSNetServiceBrowser) {}
+
+    /// The browser could not search.
+    #[doc(alias = "netServiceBrowser:didNotSearch:")]
+    fn did_not_search(
+        &mut self,
+        browser: &NSNetServiceBrowser,
+        error: &NSDictionary<NSString, NSNumber>,
+    ) {
+    }
+
+    /// A domain was found.
+    #[doc(alias = "netServiceBrowser:didFindDomain:moreComing:")]
+    fn did_find_domain(
+        &mut self,
+        browser: &NSNetServiceBrowser,
+        domain: &NSString,
+        more_coming: bool,
+    ) {
+    }
+
+    /// A service was found.
+    ///
+    /// The service must be resolved before its addresses are known.
+    #[doc(alias = "netServiceBrowser:didFindService:moreComing:")]
+    fn did_find_service(
+        &mut self,
+        browser: &NSNetServiceBrowser,
+        service: &NSNetService,
+        more_coming: bool,
+    ) {
+    }
+
+    /// A domain disappeared.
+    #[doc(alias = "netServiceBrowser:didRemoveDomain:moreComing:")]
+    fn did_remove_domain(
+        &mut self,
+        browser: &NSNetServiceBrowser,
+        domain: &NSString,
+        more_coming: bool,
+    ) {
+    }
+
+    /// A service disappeared.
+    #[doc(alias = "netServiceBrowser:didRemoveService:moreComing:")]
+    fn did_remove_service(
+        &mut self,
+        browser: &NSNetServiceBrowser,
+        service: &NSNetService,
+        more_coming: bool,
+    ) {
+    }
+}
+
+define_class!(
+    // SAFETY:
+    // - The superclass NSObject does not have any subclassing requirements.
+    // - `RustServiceDelegate` does not implement `Drop`.
+    #[unsafe(super(NSObject))]
+    #[name = "Objc2NetServiceDelegate"]
+    #[ivars = RefCell<Box<dyn ServiceDelegate>>]
+    struct RustServiceDelegate;
+
+    unsafe impl NSObjectProtocol for RustServiceDelegate {}
+
+    unsafe impl NSNetServiceDelegate for RustServiceDelegate {
+        #[method(netServiceWillPublish:)]
+        fn net_service_will_publish(&self, service: &NSNetService) {
+            self.with(|delegate| delegate.will_publish(service));
+        }
+
+        #[method(netServiceDidPublish:)]
+        fn net_service_did_publish(&self, service: &NSNetService) {
+            self.with(|delegate| delegate.did_publish(service));
+        }
+
+        #[method(netService:didNotPublish:)]
+        fn net_service_did_not_publish(
+            &self,
+            service: &NSNetService,
+            error: &NSDictionary<NSString, NSNumber>,
+        ) {
+            self.with(|delegate| delegate.did_not_publish(service, error));
+        }
+
+        #[method(netServiceWillResolve:)]
+        fn net_service_will_resolve(&self, service: &NSNetService) {
+            self.with(|delegate| delegate.will_resolve(service));
+        }
+
+        #[method(netServiceDidResolveAddress:)]
+        fn net_service_did_resolve_address(&self, service: &NSNetService) {
+            self.with(|delegate| delegate.did_resolve_address(service));
+        }
+
+        #[method(netService:didNotResolve:)]
+        fn net_service_did_not_resolve(
+            &self,
+            service: &NSNetService,
+            error: &NSDictionary<NSString, NSNumber>,
+        ) {
+            self.with(|delegate| delegate.did_not_resolve(service, error));
+        }
+
+        #[method(netServiceDidStop:)]
+        fn net_service_did_stop(&self, service: &NSNetService) {
+            self.with(|delegate| delegate.did_stop(service));
+        }
+
+        #[method(netService:didUpdateTXTRecordData:)]
+        fn net_service_did_update_txt_record_data(&self, service: &NSNetService, data: &NSData) {
+            self.with(|delegate| delegate.did_update_txt_record_data(service, data));
+        }
+    }
+);
+
+impl RustServiceDelegate {
+    fn new(delegate: Box<dyn ServiceDelegate>) -> Retained<Self> {
+        let this = Self::alloc().set_ivars(RefCell::new(delegate));
+        unsafe { msg_send_id![super(this), init] }
+    }
+
+    fn with(&self, f: impl FnOnce(&mut dyn ServiceDelegate)) {
+        // Ignore events that are reported re-entrantly, e.g. `did_stop` when
+        // calling `stop` from inside another event.
+        if let Ok(mut delegate) = self.ivars().try_borrow_mut() {
+            f(&mut **delegate);
+        }
+    }
+}
+
+define_class!(
+    // SAFETY:
+    // - The superclass NSObject does not have any subclassing requirements.
+    // - `RustBrowserDelegate` does not implement `Drop`.
+    #[unsafe(super(NSObject))]
+    #[name = "Objc2NetServiceBrowserDelegate"]
+    #[ivars = RefCell<Box<dyn BrowserDelegate>>]
+    struct RustBrowserDelegate;
+
+    unsafe impl NSObjectProtocol for RustBrowserDelegate {}
+
+    unsafe impl NSNetServiceBrowserDelegate for RustBrowserDelegate {
+        #[method(netServiceBrowserWillSearch:)]
+        fn net_service_browser_will_search(&self, browser: &NSNetServiceBrowser) {
+            self.with(|delegate| delegate.will_search(browser));
+        }
+
+        #[method(netServiceBrowserDidStopSearch:)]
+        fn net_service_browser_did_stop_search(&self, browser: &NSNetServiceBrowser) {
+            self.with(|delegate| delegate.did_stop_search(browser));
+        }
+
+        #[method(netServiceBrowser:didNotSearch:)]
+        fn net_service_browser_did_not_search(
+            &self,
+            browser: &NSNetServiceBrowser,
+            error: &NSDictionary<NSString, NSNumber>,
+        ) {
+            self.with(|delegate| delegate.did_not_search(browser, error));
+        }
+
+        #[method(netServiceBrowser:didFindDomain:moreComing:)]
+        fn net_service_browser_did_find_domain(
+            &self,
+            browser: &NSNetServiceBrowser,
+            domain: &NSString,
+            more_coming: bool,
+        ) {
+            self.with(|delegate| delegate.did_find_domain(browser, domain, more_coming));
+        }
+
+        #[method(netServiceBrowser:didFindService:moreComing:)]
+        fn net_service_browser_did_find_service(
+            &self,
+            browser: &NSNetServiceBrowser,
+            service: &NSNetService,
+            more_coming: bool,
+        ) {
+            self.with(|delegate| delegate.did_find_service(browser, service, more_coming));
+        }
+
+        #[method(netServiceBrowser:didRemoveDomain:moreComing:)]
+        fn net_service_browser_did_remove_domain(
+            &self,
+            browser: &NSNetServiceBrowser,
+            domain: &NSString,
+            more_coming: bool,
+        ) {
+            self.with(|delegate| delegate.did_remove_domain(browser, domain, more_coming));
+        }
+
+        #[method(netServiceBrowser:didRemoveService:moreComing:)]
+        fn net_service_browser_did_remove_service(
+            &self,
+            browser: &NSNetServiceBrowser,
+            service: &NSNetService,
+            more_coming: bool,
+        ) {
+            self.with(|delegate| delegate.did_remove_service(browser, service, more_coming));
+        }
+    }
+);
+
+impl RustBrowserDelegate {
+    fn new(delegate: Box<dyn BrowserDelegate>) -> Retained<Self> {
+        let this = Self::alloc().set_ivars(RefCell::new(delegate));
+        unsafe { msg_send_id![super(this), init] }
+    }
+
+    fn with(&self, f: impl FnOnce(&mut dyn BrowserDelegate)) {
+        // Ignore events that are reported re-entrantly, e.g. when calling
+        // `stop` from inside another event.
+        if let Ok(mut delegate) = self.ivars().try_borrow_mut() {
+            f(&mut **delegate);
+        }
+    }
+}
+
+impl NSNetService {
+    /// Set the delegate that receives the events of the service.
+    ///
+    /// This replaces the service's delegate. The delegate is kept alive for
+    /// as long as the service is.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2::AllocAnyThread;
+    /// use objc2_foundation::net_service::ServiceDelegate;
+    /// use objc2_foundation::{ns_string, NSNetService};
+    ///
+    /// struct Published;
+    ///
+    /// impl ServiceDelegate for Published {
+    ///     fn did_publish(&mut self, service: &NSNetService) {
+    ///         println!("published {}", service.name());
+    ///     }
+    /// }
+    ///
+    /// let service = NSNetService::initWithDomain_type_name_port(
+    ///     NSNetService::alloc(),
+    ///     ns_string!("local."),
+    ///     ns_string!("_example._tcp."),
+    ///     ns_string!("My Service"),
+    ///     8080,
+    /// );
+    /// service.set_rust_delegate(Published);
+    /// service.publish();
+    /// ```
+    #[doc(alias = "setDelegate:")]
+    pub fn set_rust_delegate(&self, delegate: impl ServiceDelegate + 'static) {
+        let delegate = RustServiceDelegate::new(Box::new(delegate));
+        // The service's `delegate` property is `assign`, so we make the
+        // service keep the delegate alive instead.
+        retain_delegate(self, &delegate);
+        // SAFETY: The delegate lives for as long as the service.
+        unsafe { self.setDelegate(Some(ProtocolObject::from_ref(&*delegate))) };
+    }
+
+    /// The key-value pairs of the service's TXT record, if any.
+    #[doc(alias = "TXTRecordData")]
+    #[doc(alias = "dictionaryFromTXTRecordData:")]
+    pub fn txt_record(&self) -> Option<Retained<NSDictionary<NSString, NSData>>> {
+        let data = self.TXTRecordData()?;
+        Some(Self::dictionaryFromTXTRecordData(&data))
+    }
+
+    /// Set the key-value pairs of the service's TXT record.
+    ///
+    /// Returns `false` if the record could not be set.
+    #[doc(alias = "setTXTRecordData:")]
+    #[doc(alias = "dataFromTXTRecordDictionary:")]
+    pub fn set_txt_record(&self, record: &NSDictionary<NSString, NSData>) -> bool {
+        self.setTXTRecordData(Some(&Self::dataFromTXTRecordDictionary(record)))
+    }
+
+    /// The resolved IPv4 and IPv6 addresses of the service.
+    ///
+    /// This is empty until the service has been resolved.
+    #[cfg(feature = "std")]
+    #[doc(alias = "addresses")]
+    pub fn socket_addrs(&self) -> alloc::vec::Vec<SocketAddr> {
+        self.addresses()
+            .map(|addresses| {
+                addresses
+                    .iter()
+                    .filter_map(|address| socket_addr_from_sockaddr(&address.to_vec()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+// Values of `AF_INET` and `AF_INET6` on Apple platforms.
+#[cfg(feature = "std")]
+const AF_INET: u8 = 2;
+#[cfg(feature = "std")]
+const AF_INET6: u8 = 30;
+
+/// Parse a `sockaddr_in` or `sockaddr_in6`, as laid out on Apple platforms
+/// (which have a `sa_len` field before `sa_family`).
+#[cfg(feature = "std")]
+pub(crate) fn socket_addr_from_sockaddr(bytes: &[u8]) -> Option<SocketAddr> {
+    let family = *bytes.get(1)?;
+    let port = u16::from_be_bytes(bytes.get(2..4)?.try_into().ok()?);
+    match family {
+        AF_INET => {
+            let ip: [u8; 4] = bytes.get(4..8)?.try_into().ok()?;
+            Some(SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::from(ip), port)))
+        }
+        AF_INET6 => {
+            let flowinfo = u32::from_be_bytes(bytes.get(4..8)?.try_into().ok()?);
+            let ip: [u8; 16] = bytes.get(8..24)?.try_into().ok()?;
+            let scope_id = u32::from_ne_bytes(bytes.get(24..28)?.try_into().ok()?);
+            Some(SocketAddr::V6(SocketAddrV6::new(
+                Ipv6Addr::from(ip),
+                port,
+                flowinfo,
+                scope_id,
+            )))
+        }
+        _ => None,
+    }
+}
+
+impl NSNetServiceBrowser {
+    /// Set the delegate that receives the events of the browser.
+    ///
+    /// This replaces the browser's delegate. The delegate is kept alive for
+    /// as long as the browser is.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2_foundation::net_service::BrowserDelegate;
+    /// use objc2_foundation::{ns_string, NSNetService, NSNetServiceBrowser, NSRunLoop};
+    ///
+    /// struct Found;
+    ///
+    /// impl BrowserDelegate for Found {
+    ///     fn did_find_service(
+    ///         &mut self,
+    ///         _browser: &NSNetServiceBrowser,
+    ///         service: &NSNetService,
+    ///         _more_coming: bool,
+    ///     ) {
+    ///         println!("found {}", service.name());
+    ///     }
+    /// }
+    ///
+    /// let browser = NSNetServiceBrowser::new();
+    /// browser.set_rust_delegate(Found);
+    /// browser.searchForServicesOfType_inDomain(ns_string!("_example._tcp."), ns_string!("local."));
+    /// NSRunLoop::currentRunLoop().run();
+    /// ```
+    #[doc(alias = "setDelegate:")]
+    pub fn set_rust_delegate(&self, delegate: impl BrowserDelegate + 'static) {
+        let delegate = RustBrowserDelegate::new(Box::new(delegate));
+        // Same as for `NSNetService`, the browser's `delegate` property is
+        // `assign`.
+        retain_delegate(self, &delegate);
+        // SAFETY: The delegate lives for as long as the browser.
+        unsafe { self.setDelegate(Some(ProtocolObject::from_ref(&*delegate))) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(target_vendor = "apple")]
+    fn properties() {
+        use alloc::string::ToString;
+
+        use super::*;
+        use crate::ns_string;
+
+        let service = NSNetService::initWithDomain_type_name_port(
+            NSNetService::alloc(),
+            ns_string!("local."),
+            ns_string!("_objc2-test._tcp."),
+            ns_string!("objc2 test"),
+            1234,
+        );
+        assert_eq!(service.domain().to_string(), "local.");
+        assert_eq!(service.r#type().to_string(), "_objc2-test._tcp.");
+        assert_eq!(service.name().to_string(), "objc2 test");
+        assert_eq!(service.port(), 1234);
+        #[cfg(feature = "std")]
+        assert!(service.socket_addrs().is_empty());
+    }
+
+    #[test]
+    #[cfg(target_vendor = "apple")]
+    fn txt_record() {
+        use super::*;
+        use crate::ns_string;
+
+        let service = NSNetService::initWithDomain_type_name_port(
+            NSNetService::alloc(),
+            ns_string!("local."),
+            ns_string!("_objc2-test._tcp."),
+            ns_string!("objc2 test"),
+            1234,
+        );
+        assert!(service.txt_record().is_none());
+
+        let record = NSDictionary::from_slices(
+            &[ns_string!("version"), ns_string!("path")],
+            &[&*NSData::with_bytes(b"1"), &*NSData::with_bytes(b"/")],
+        );
+        assert!(service.set_txt_record(&record));
+
+        let decoded = service.txt_record().unwrap();
+        assert_eq!(decoded.len(), 2);
+        assert_eq!(
+            decoded
+                .objectForKey(ns_string!("version"))
+                .unwrap()
+                .to_vec(),
+            b"1"
+        );
+        assert_eq!(
+            decoded.objectForKey(ns_string!("path")).unwrap().to_vec(),
+            b"/"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn socket_addr_from_sockaddr() {
+        use super::*;
+
+        // sockaddr_in for 192.168.1.2:8080.
+        let v4 = [16, 2, 0x1f, 0x90, 192, 168, 1, 2, 0, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(
+            socket_addr_from_sockaddr(&v4),
+            Some("192.168.1.2:8080".parse::<SocketAddr>().unwrap())
+        );
+
+        // sockaddr_in6 for [fe80::1%4]:80.
+        let mut v6 = [0; 28];
+        v6[0] = 28;
+        v6[1] = 30;
+        v6[2..4].copy_from_slice(&80u16.to_be_bytes());
+        v6[8] = 0xfe;
+        v6[9] = 0x80;
+        v6[23] = 1;
+        v6[24..28].copy_from_slice(&4u32.to_ne_bytes());
+        assert_eq!(
+            socket_addr_from_sockaddr(&v6),
+            Some("[fe80::1%4]:80".parse::<SocketAddr>().unwrap())
+        );
+
+        // Unknown family, and truncated data.
+        assert_eq!(socket_addr_from_sockaddr(&[16, 1, 0, 0]), None);
+        assert_eq!(socket_addr_from_sockaddr(&v4[..6]), None);
+    }
+}
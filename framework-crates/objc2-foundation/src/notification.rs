@@ -0,0 +1,339 @@
+//! Utilities for the `NSNotificationCenter` and
+//! `NSDistributedNotificationCenter` classes.
+//!
+//! Use [`NSNotificationCenter::observe`] to receive notifications in a Rust
+//! closure. The returned [`Observer`] unregisters the closure when dropped.
+//!
+//! Alternatively, use [`NSNotificationCenter::notifications`] to receive
+//! them asynchronously as a [`Notifications`] stream.
+#[cfg(feature = "NSDistributedNotificationCenter")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use alloc::collections::VecDeque;
+#[cfg(feature = "std")]
+use alloc::sync::Arc;
+use core::fmt;
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+#[cfg(feature = "std")]
+use core::task::{Context, Poll, Waker};
+#[cfg(feature = "std")]
+use std::sync::Mutex;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+#[cfg(feature = "NSDistributedNotificationCenter")]
+use objc2::runtime::NSObject;
+use objc2::Message;
+#[cfg(feature = "NSDistributedNotificationCenter")]
+use objc2::{define_class, msg_send_id, sel, AllocAnyThread, DefinedClass};
+
+#[cfg(feature = "std")]
+use crate::SendWrapper;
+#[cfg(feature = "NSDistributedNotificationCenter")]
+use crate::{NSDistributedNotificationCenter, NSNotificationSuspensionBehavior, NSString};
+use crate::{NSNotification, NSNotificationCenter, NSNotificationName};
+
+impl NSNotificationCenter {
+    /// Register a closure to be called for notifications matching the given
+    /// name and sender.
+    ///
+    /// If `name` is `None`, the closure is called for notifications with any
+    /// name, and if `object` is `None`, it is called for notifications from
+    /// any sender.
+    ///
+    /// The closure is called synchronously on the thread that posted the
+    /// notification.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSNotification, NSNotificationCenter};
+    ///
+    /// let center = NSNotificationCenter::defaultCenter();
+    /// let observer = center.observe(Some(ns_string!("MyNotification")), None, |notification| {
+    ///     println!("received {}", notification.name());
+    /// });
+    /// center.postNotificationName_object(ns_string!("MyNotification"), None);
+    /// drop(observer);
+    /// ```
+    #[doc(alias = "addObserverForName:object:queue:usingBlock:")]
+    pub fn observe(
+        &self,
+        name: Option<&NSNotificationName>,
+        object: Option<&AnyObject>,
+        f: impl Fn(&NSNotification) + Send + Sync + 'static,
+    ) -> Observer {
+        let block = block2::RcBlock::new(move |notification: NonNull<NSNotification>| {
+            // SAFETY: The notification is valid for the duration of the call.
+            f(unsafe { notification.as_ref() })
+        });
+        // SAFETY: The block is `Send + Sync` and `'static`, since
+        // notifications may be posted from any thread.
+        let token =
+            unsafe { self.addObserverForName_object_queue_usingBlock(name, object, None, &block) };
+        // SAFETY: Protocol objects are always valid objects.
+        let token = unsafe { Retained::cast_unchecked::<AnyObject>(token) };
+        Observer {
+            center: self.retain(),
+            token,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Default)]
+struct Shared {
+    queue: VecDeque<SendWrapper<Retained<NSNotification>>>,
+    waker: Option<Waker>,
+}
+
+#[cfg(feature = "std")]
+impl NSNotificationCenter {
+    /// Receive notifications matching the given name and sender as a
+    /// stream.
+    ///
+    /// Notifications are buffered until they are polled, so none are lost
+    /// if they are posted faster than they are handled. The stream does not
+    /// depend on any particular executor.
+    ///
+    /// Notifications, and the objects and user info they carry, are not
+    /// thread-safe, so the stream is bound to the thread it was created on,
+    /// and only receives notifications posted on that thread. Use
+    /// [`observe`](Self::observe) to handle notifications posted on other
+    /// threads.
+    ///
+    /// See [`observe`](Self::observe) for the meaning of the parameters.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// With the `"futures-core"` feature enabled, the result can be used as
+    /// a stream. It can also be polled manually.
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use std::task::{Context, Poll, Wake};
+    /// use objc2_foundation::{ns_string, NSNotificationCenter};
+    ///
+    /// struct NoopWaker;
+    ///
+    /// impl Wake for NoopWaker {
+    ///     fn wake(self: Arc<Self>) {}
+    /// }
+    ///
+    /// let center = NSNotificationCenter::defaultCenter();
+    /// let mut notifications = center.notifications(Some(ns_string!("MyNotification")), None);
+    /// center.postNotificationName_object(ns_string!("MyNotification"), None);
+    ///
+    /// let waker = Arc::new(NoopWaker).into();
+    /// let mut cx = Context::from_waker(&waker);
+    /// match notifications.poll_notification(&mut cx) {
+    ///     Poll::Ready(Some(notification)) => println!("received {}", notification.name()),
+    ///     _ => unreachable!("the notification was posted above"),
+    /// }
+    /// ```
+    #[doc(alias = "addObserverForName:object:queue:usingBlock:")]
+    pub fn notifications(
+        &self,
+        name: Option<&NSNotificationName>,
+        object: Option<&AnyObject>,
+    ) -> Notifications {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let observer_shared = Arc::clone(&shared);
+        let thread = std::thread::current().id();
+        let observer = self.observe(name, object, move |notification| {
+            // The stream is bound to this thread, so notifications posted on
+            // other threads are never handed to it.
+            if std::thread::current().id() != thread {
+                return;
+            }
+            let mut shared = observer_shared.lock().unwrap();
+            shared
+                .queue
+                .push_back(SendWrapper::new(notification.retain()));
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        });
+        Notifications {
+            observer,
+            shared,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// A stream of notifications.
+///
+/// The observer is removed from the notification center when this is
+/// dropped.
+///
+/// This is bound to the thread that created it, and only receives
+/// notifications posted on that thread.
+///
+/// Created with [`NSNotificationCenter::notifications`].
+#[cfg(feature = "std")]
+#[must_use = "streams do nothing unless polled, and dropping the stream removes the observer"]
+pub struct Notifications {
+    observer: Observer,
+    shared: Arc<Mutex<Shared>>,
+    /// The queued notifications must be accessed and dropped on the thread
+    /// they were posted on.
+    _not_send: PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl Notifications {
+    /// The notification center that the stream receives notifications from.
+    pub fn center(&self) -> &NSNotificationCenter {
+        self.observer.center()
+    }
+
+    /// Poll for the next notification.
+    ///
+    /// The stream never ends, so this never returns `Poll::Ready(None)`.
+    pub fn poll_notification(
+        &mut self,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Retained<NSNotification>>> {
+        let mut shared = self.shared.lock().unwrap();
+        match shared.queue.pop_front() {
+            // Only notifications posted on this thread are queued, and
+            // `Notifications` is not `Send`, so this does not panic.
+            Some(notification) => Poll::Ready(Some(notification.into_inner())),
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for Notifications {
+    fn drop(&mut self) {
+        // The observer closure may outlive the stream, so drop the queued
+        // notifications here, on the thread they were posted on.
+        self.shared.lock().unwrap().queue.clear();
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(feature = "futures-core")]
+impl futures_core::Stream for Notifications {
+    type Item = Retained<NSNotification>;
+
+    fn poll_next(
+        self: core::pin::Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Retained<NSNotification>>> {
+        self.get_mut().poll_notification(cx)
+    }
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for Notifications {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Notifications")
+            .field("observer", &self.observer)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "NSDistributedNotificationCenter")]
+type Handler = Box<dyn Fn(&NSNotification) + Send + Sync + 'static>;
+
+#[cfg(feature = "NSDistributedNotificationCenter")]
+define_class!(
+    // SAFETY:
+    // - The superclass NSObject does not have any subclassing requirements.
+    // - `DistributedObserver` does not implement `Drop`.
+    #[unsafe(super(NSObject))]
+    #[name = "Objc2DistributedNotificationObserver"]
+    #[ivars = Handler]
+    struct DistributedObserver;
+
+    unsafe impl DistributedObserver {
+        #[method(handleNotification:)]
+        fn handle_notification(&self, notification: &NSNotification) {
+            (self.ivars())(notification);
+        }
+    }
+);
+
+#[cfg(feature = "NSDistributedNotificationCenter")]
+impl NSDistributedNotificationCenter {
+    /// Register a closure to be called for distributed notifications
+    /// matching the given name and sender, with the given behavior for when
+    /// the center is suspended.
+    ///
+    /// Unlike [`NSNotificationCenter::observe`], which always uses
+    /// [`NSNotificationSuspensionBehavior::Coalesce`], this allows e.g.
+    /// holding notifications until the center is resumed.
+    ///
+    /// Distributed notifications are delivered through the run loop of the
+    /// thread that registered the observer, so that run loop must be
+    /// running for the closure to be called.
+    #[doc(alias = "addObserver:selector:name:object:suspensionBehavior:")]
+    pub fn observe_with_suspension_behavior(
+        &self,
+        name: Option<&NSNotificationName>,
+        object: Option<&NSString>,
+        suspension_behavior: NSNotificationSuspensionBehavior,
+        f: impl Fn(&NSNotification) + Send + Sync + 'static,
+    ) -> Observer {
+        let handler: Handler = Box::new(f);
+        let observer = DistributedObserver::alloc().set_ivars(handler);
+        let observer: Retained<DistributedObserver> =
+            unsafe { msg_send_id![super(observer), init] };
+        // SAFETY: The observer responds to `handleNotification:`, and is
+        // kept alive by the returned `Observer` until it has been removed.
+        unsafe {
+            self.addObserver_selector_name_object_suspensionBehavior(
+                &observer,
+                sel!(handleNotification:),
+                name,
+                object,
+                suspension_behavior,
+            )
+        };
+        Observer {
+            center: self.retain().into_super(),
+            token: observer.into_super().into_super(),
+        }
+    }
+}
+
+/// A closure registered with [`NSNotificationCenter::observe`].
+///
+/// The closure is removed from the notification center when this is
+/// dropped.
+#[must_use = "the observer is removed from the notification center when dropped"]
+pub struct Observer {
+    center: Retained<NSNotificationCenter>,
+    token: Retained<AnyObject>,
+}
+
+impl Observer {
+    /// The notification center that the observer is registered with.
+    pub fn center(&self) -> &NSNotificationCenter {
+        &self.center
+    }
+}
+
+impl Drop for Observer {
+    fn drop(&mut self) {
+        self.center.removeObserver(&self.token);
+    }
+}
+
+impl fmt::Debug for Observer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Observer")
+            .field("center", &self.center)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,84 @@
+//! Utilities for handling `NSNull`, the singleton object used to represent
+//! "no value" in collections that cannot store `nil`.
+//!
+//! This is commonly encountered when deserializing JSON with
+//! `NSJSONSerialization`, where `null` is represented by `NSNull`.
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::DowncastTarget;
+
+use crate::NSNull;
+
+/// Nullable downcasting.
+impl NSNull {
+    /// Whether the given object is the `NSNull` singleton.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{NSNull, NSObject};
+    ///
+    /// assert!(NSNull::is_null(&NSNull::null()));
+    /// assert!(!NSNull::is_null(&NSObject::new()));
+    /// ```
+    #[inline]
+    pub fn is_null(obj: &AnyObject) -> bool {
+        obj.downcast_ref::<Self>().is_some()
+    }
+
+    /// Attempt to downcast the object to a class of type `T`, mapping
+    /// `NSNull` to `None`.
+    ///
+    /// # Errors
+    ///
+    /// If the object is neither `NSNull` nor of type `T`, the object is
+    /// returned back as the [`Err`] type.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// Handle `null` elements in an array.
+    ///
+    /// ```
+    /// use objc2::rc::Retained;
+    /// use objc2::runtime::AnyObject;
+    /// use objc2_foundation::{NSArray, NSNull, NSString};
+    ///
+    /// let array = NSArray::<AnyObject>::from_retained_slice(&[
+    ///     Retained::into_super(Retained::into_super(NSString::from_str("a"))),
+    ///     Retained::into_super(Retained::into_super(NSNull::null())),
+    /// ]);
+    ///
+    /// let elements: Vec<Option<Retained<NSString>>> = array
+    ///     .iter()
+    ///     .map(|obj| NSNull::downcast_nullable(obj).unwrap())
+    ///     .collect();
+    /// assert_eq!(elements[0].as_deref().unwrap().to_string(), "a");
+    /// assert!(elements[1].is_none());
+    /// ```
+    pub fn downcast_nullable<T: DowncastTarget>(
+        obj: Retained<AnyObject>,
+    ) -> Result<Option<Retained<T>>, Retained<AnyObject>> {
+        if Self::is_null(&obj) {
+            Ok(None)
+        } else {
+            obj.downcast().map(Some)
+        }
+    }
+
+    /// Attempt to downcast a reference to the object to a class of type `T`,
+    /// mapping `NSNull` to `None`.
+    ///
+    /// See [`downcast_nullable`] for details.
+    ///
+    /// [`downcast_nullable`]: Self::downcast_nullable
+    pub fn downcast_nullable_ref<T: DowncastTarget>(
+        obj: &AnyObject,
+    ) -> Result<Option<&T>, &AnyObject> {
+        if Self::is_null(obj) {
+            Ok(None)
+        } else {
+            obj.downcast_ref().map(Some).ok_or(obj)
+        }
+    }
+}
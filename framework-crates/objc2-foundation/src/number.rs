@@ -45,6 +45,15 @@ macro_rules! def_new_fn {
 }
 
 /// Creation methods.
+///
+/// These use the `numberWith...:` class methods, which allows Foundation to
+/// return a shared or tagged pointer instance instead of allocating. On
+/// 64-bit Apple platforms, this is the case for booleans and for most
+/// integers.
+///
+/// Note that `From<i32>` and the like cannot be implemented for
+/// `Retained<NSNumber>` because of the orphan rules, use these methods
+/// instead.
 impl NSNumber {
     def_new_fn! {
         (new_bool(bool); numberWithBool),
@@ -209,6 +218,14 @@ impl NSNumber {
             _ => unreachable!("invalid encoding for NSNumber"),
         }
     }
+
+    /// Whether this is one of the shared `kCFBooleanTrue` and
+    /// `kCFBooleanFalse` instances, which is how booleans are represented.
+    pub(crate) fn is_bool(&self) -> bool {
+        [true, false]
+            .into_iter()
+            .any(|b| core::ptr::eq(self, &*Self::new_bool(b)))
+    }
 }
 
 impl hash::Hash for NSNumber {
@@ -0,0 +1,34 @@
+//! Utilities for the `NSNumberFormatter` class.
+use objc2::rc::Retained;
+
+use crate::{NSNumberFormatter, NSNumberFormatterStyle};
+
+/// Convenience creation methods.
+impl NSNumberFormatter {
+    /// Create a new number formatter with the given style, using the user's
+    /// current locale.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// Format a currency amount for a specific locale.
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSLocale, NSNumber, NSNumberFormatter, NSNumberFormatterStyle};
+    ///
+    /// let formatter = NSNumberFormatter::with_style(NSNumberFormatterStyle::CurrencyStyle);
+    /// formatter.setLocale(Some(&NSLocale::localeWithLocaleIdentifier(ns_string!("en_US"))));
+    ///
+    /// let string = formatter.stringFromNumber(&NSNumber::new_f64(1234.5)).unwrap();
+    /// assert_eq!(string.to_string(), "$1,234.50");
+    ///
+    /// let number = formatter.numberFromString(ns_string!("$12.25")).unwrap();
+    /// assert_eq!(number.as_f64(), 12.25);
+    /// ```
+    #[doc(alias = "setNumberStyle:")]
+    pub fn with_style(style: NSNumberFormatterStyle) -> Retained<Self> {
+        let formatter = Self::new();
+        formatter.setNumberStyle(style);
+        formatter
+    }
+}
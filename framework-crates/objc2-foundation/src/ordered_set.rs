@@ -0,0 +1,296 @@
+//! Utilities for the `NSOrderedSet` and `NSMutableOrderedSet` classes.
+use alloc::vec::Vec;
+#[cfg(feature = "NSEnumerator")]
+use core::fmt;
+
+use objc2::rc::{Retained, RetainedFromIterator};
+use objc2::{AllocAnyThread, Message};
+
+#[cfg(feature = "NSEnumerator")]
+use crate::iter;
+use crate::{util, NSMutableOrderedSet, NSOrderedSet};
+
+/// Convenience creation methods.
+impl<ObjectType: Message> NSOrderedSet<ObjectType> {
+    /// Create a new ordered set from a slice of objects.
+    ///
+    /// Duplicate objects are only included once, at the position where they
+    /// first occurred.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSOrderedSet};
+    ///
+    /// let set = NSOrderedSet::from_slice(&[ns_string!("a"), ns_string!("b"), ns_string!("a")]);
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    #[doc(alias = "initWithObjects:count:")]
+    pub fn from_slice(slice: &[&ObjectType]) -> Retained<Self> {
+        let len = slice.len();
+        let ptr = util::ref_ptr_cast_const(slice.as_ptr());
+        // SAFETY: Same as `NSArray::from_slice`.
+        unsafe { Self::initWithObjects_count(Self::alloc(), ptr, len) }
+    }
+
+    /// Create a new ordered set from a slice of retained objects.
+    #[doc(alias = "initWithObjects:count:")]
+    pub fn from_retained_slice(slice: &[Retained<ObjectType>]) -> Retained<Self> {
+        let len = slice.len();
+        let ptr = util::retained_ptr_cast_const(slice.as_ptr());
+        // SAFETY: Same as `NSArray::from_retained_slice`.
+        unsafe { Self::initWithObjects_count(Self::alloc(), ptr, len) }
+    }
+}
+
+/// Convenience creation methods.
+impl<ObjectType: Message> NSMutableOrderedSet<ObjectType> {
+    #[doc(alias = "initWithObjects:count:")]
+    pub fn from_slice(slice: &[&ObjectType]) -> Retained<Self> {
+        let len = slice.len();
+        let ptr = util::ref_ptr_cast_const(slice.as_ptr());
+        // SAFETY: Same as `NSArray::from_slice`.
+        unsafe { Self::initWithObjects_count(Self::alloc(), ptr, len) }
+    }
+
+    #[doc(alias = "initWithObjects:count:")]
+    pub fn from_retained_slice(slice: &[Retained<ObjectType>]) -> Retained<Self> {
+        let len = slice.len();
+        let ptr = util::retained_ptr_cast_const(slice.as_ptr());
+        // SAFETY: Same as `NSArray::from_retained_slice`.
+        unsafe { Self::initWithObjects_count(Self::alloc(), ptr, len) }
+    }
+}
+
+/// Various accessor methods.
+impl<ObjectType: Message> NSOrderedSet<ObjectType> {
+    /// The amount of elements in the ordered set.
+    #[doc(alias = "count")]
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.count()
+    }
+
+    /// Whether the ordered set is empty or not.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the object at the given index, or `None` if the index is out of
+    /// bounds.
+    #[doc(alias = "objectAtIndex:")]
+    pub fn get(&self, index: usize) -> Option<Retained<ObjectType>> {
+        // TODO: Replace this check with catching the thrown NSRangeException
+        if index < self.len() {
+            Some(self.objectAtIndex(index))
+        } else {
+            None
+        }
+    }
+
+    /// The index of the given object in the ordered set, if it is present.
+    #[doc(alias = "indexOfObject:")]
+    pub fn position(&self, object: &ObjectType) -> Option<usize> {
+        let index = self.indexOfObject(object);
+        if index == util::NOT_FOUND {
+            None
+        } else {
+            Some(index)
+        }
+    }
+
+    /// Convert the ordered set to a `Vec` of its objects, in order.
+    #[cfg(feature = "NSEnumerator")]
+    pub fn to_vec(&self) -> Vec<Retained<ObjectType>> {
+        self.iter().collect()
+    }
+
+    /// Iterate over the ordered set's elements in order.
+    #[cfg(feature = "NSEnumerator")]
+    #[doc(alias = "objectEnumerator")]
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, ObjectType> {
+        Iter(iter::Iter::new(self))
+    }
+}
+
+/// Convenience mutation methods.
+impl<ObjectType: Message> NSMutableOrderedSet<ObjectType> {
+    /// Insert an object into the ordered set at the given index.
+    ///
+    /// If the object is already present in the set, this does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[doc(alias = "insertObject:atIndex:")]
+    pub fn insert(&self, index: usize, obj: &ObjectType) {
+        // TODO: Replace this check with catching the thrown NSRangeException
+        let len = self.len();
+        if index <= len {
+            self.insertObject_atIndex(obj, index)
+        } else {
+            panic!(
+                "insertion index (is {}) should be <= len (is {})",
+                index, len
+            );
+        }
+    }
+
+    /// Move the object at index `from` so that it ends up at index `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    pub fn move_index(&self, from: usize, to: usize) {
+        // TODO: Replace this check with catching the thrown NSRangeException
+        let len = self.len();
+        if from >= len || to >= len {
+            panic!(
+                "move indices (are {} and {}) should be < len (is {})",
+                from, to, len
+            );
+        }
+        let obj = self.objectAtIndex(from);
+        self.removeObjectAtIndex(from);
+        self.insertObject_atIndex(&obj, to);
+    }
+}
+
+#[cfg(feature = "NSEnumerator")]
+unsafe impl<ObjectType: Message> iter::FastEnumerationHelper for NSOrderedSet<ObjectType> {
+    type Item = ObjectType;
+
+    #[inline]
+    fn maybe_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+#[cfg(feature = "NSEnumerator")]
+unsafe impl<ObjectType: Message> iter::FastEnumerationHelper for NSMutableOrderedSet<ObjectType> {
+    type Item = ObjectType;
+
+    #[inline]
+    fn maybe_len(&self) -> Option<usize> {
+        Some(self.len())
+    }
+}
+
+/// An iterator over the items of an ordered set.
+#[derive(Debug)]
+#[cfg(feature = "NSEnumerator")]
+pub struct Iter<'a, ObjectType: Message>(iter::Iter<'a, NSOrderedSet<ObjectType>>);
+
+#[cfg(feature = "NSEnumerator")]
+__impl_iter! {
+    impl<'a, ObjectType: Message> Iterator<Item = Retained<ObjectType>> for Iter<'a, ObjectType> { ... }
+}
+
+/// A retained iterator over the items of an ordered set.
+#[derive(Debug)]
+#[cfg(feature = "NSEnumerator")]
+pub struct IntoIter<ObjectType: Message>(iter::IntoIter<NSOrderedSet<ObjectType>>);
+
+#[cfg(feature = "NSEnumerator")]
+__impl_iter! {
+    impl<ObjectType: Message> Iterator<Item = Retained<ObjectType>> for IntoIter<ObjectType> { ... }
+}
+
+#[cfg(feature = "NSEnumerator")]
+__impl_into_iter! {
+    impl<ObjectType: Message> IntoIterator for &NSOrderedSet<ObjectType> {
+        type IntoIter = Iter<'_, ObjectType>;
+    }
+
+    impl<ObjectType: Message> IntoIterator for &NSMutableOrderedSet<ObjectType> {
+        type IntoIter = Iter<'_, ObjectType>;
+    }
+
+    impl<ObjectType: Message> IntoIterator for Retained<NSOrderedSet<ObjectType>> {
+        #[uses(new)]
+        type IntoIter = IntoIter<ObjectType>;
+    }
+
+    impl<ObjectType: Message> IntoIterator for Retained<NSMutableOrderedSet<ObjectType>> {
+        #[uses(new_mutable)]
+        type IntoIter = IntoIter<ObjectType>;
+    }
+}
+
+#[cfg(feature = "NSEnumerator")]
+impl<ObjectType: fmt::Debug + Message> fmt::Debug for NSOrderedSet<ObjectType> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self).finish()
+    }
+}
+
+#[cfg(feature = "NSEnumerator")]
+impl<ObjectType: fmt::Debug + Message> fmt::Debug for NSMutableOrderedSet<ObjectType> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+impl<ObjectType: Message> Extend<Retained<ObjectType>> for &NSMutableOrderedSet<ObjectType> {
+    fn extend<I: IntoIterator<Item = Retained<ObjectType>>>(&mut self, iter: I) {
+        iter.into_iter().for_each(move |item| self.addObject(&item));
+    }
+}
+
+impl<'a, ObjectType: Message> Extend<&'a ObjectType> for &NSMutableOrderedSet<ObjectType> {
+    fn extend<I: IntoIterator<Item = &'a ObjectType>>(&mut self, iter: I) {
+        iter.into_iter().for_each(move |item| self.addObject(item));
+    }
+}
+
+impl<'a, ObjectType: Message + 'a> RetainedFromIterator<&'a ObjectType>
+    for NSOrderedSet<ObjectType>
+{
+    fn retained_from_iter<I: IntoIterator<Item = &'a ObjectType>>(iter: I) -> Retained<Self> {
+        let vec = Vec::from_iter(iter);
+        Self::from_slice(&vec)
+    }
+}
+
+impl<ObjectType: Message> RetainedFromIterator<Retained<ObjectType>> for NSOrderedSet<ObjectType> {
+    fn retained_from_iter<I: IntoIterator<Item = Retained<ObjectType>>>(iter: I) -> Retained<Self> {
+        let vec = Vec::from_iter(iter);
+        Self::from_retained_slice(&vec)
+    }
+}
+
+impl<'a, ObjectType: Message + 'a> RetainedFromIterator<&'a ObjectType>
+    for NSMutableOrderedSet<ObjectType>
+{
+    fn retained_from_iter<I: IntoIterator<Item = &'a ObjectType>>(iter: I) -> Retained<Self> {
+        let vec = Vec::from_iter(iter);
+        Self::from_slice(&vec)
+    }
+}
+
+impl<ObjectType: Message> RetainedFromIterator<Retained<ObjectType>>
+    for NSMutableOrderedSet<ObjectType>
+{
+    fn retained_from_iter<I: IntoIterator<Item = Retained<ObjectType>>>(iter: I) -> Retained<Self> {
+        let vec = Vec::from_iter(iter);
+        Self::from_retained_slice(&vec)
+    }
+}
+
+impl<ObjectType: Message> PartialEq<NSOrderedSet<ObjectType>> for NSMutableOrderedSet<ObjectType> {
+    #[inline]
+    fn eq(&self, other: &NSOrderedSet<ObjectType>) -> bool {
+        PartialEq::eq(&**self, other)
+    }
+}
+
+impl<ObjectType: Message> PartialEq<NSMutableOrderedSet<ObjectType>> for NSOrderedSet<ObjectType> {
+    #[inline]
+    fn eq(&self, other: &NSMutableOrderedSet<ObjectType>) -> bool {
+        PartialEq::eq(self, &**other)
+    }
+}
@@ -0,0 +1,717 @@
+//! A [`serde`] data format for property lists.
+//!
+//! Values are converted to and from Foundation objects, which are then read
+//! and written with `NSPropertyListSerialization`. This means that parsing
+//! has exactly the same semantics as in Objective-C code, and that all the
+//! formats supported by the system can be read.
+//!
+//! Rust values are represented as follows:
+//! - Booleans and numbers as `<true/>`, `<false/>`, `<integer>` and `<real>`.
+//! - Strings and characters as `<string>`.
+//! - Bytes as `<data>`.
+//! - Sequences and tuples as `<array>`.
+//! - Maps and structs as `<dict>`. Keys must be strings.
+//! - Unit variants as `<string>`, and other enum variants as a `<dict>` with
+//!   a single entry from the variant name to the contents.
+//!
+//! Property lists have no null value, so `None` and `()` are skipped when
+//! they appear in maps and structs, and are an error elsewhere. When
+//! deserializing, `<date>` values are read as the number of seconds since
+//! the Unix epoch.
+//!
+//!
+//! # Examples
+//!
+//! ```
+//! use objc2_foundation::{plist, NSPropertyListFormat};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Preferences {
+//!     name: String,
+//!     volume: f64,
+//!     recent: Vec<String>,
+//!     theme: Option<String>,
+//! }
+//!
+//! let preferences = Preferences {
+//!     name: "Example".into(),
+//!     volume: 0.5,
+//!     recent: vec!["a.txt".into()],
+//!     theme: None,
+//! };
+//! let bytes = plist::to_vec(&preferences, NSPropertyListFormat::XMLFormat_v1_0).unwrap();
+//! assert_eq!(plist::from_slice::<Preferences>(&bytes).unwrap(), preferences);
+//! ```
+use alloc::string::{String, ToString};
+use alloc::vec::{self, Vec};
+use core::fmt;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::Message;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IntoDeserializer, Visitor};
+use serde::ser::{self, Serialize};
+
+use crate::{
+    NSArray, NSData, NSDate, NSDictionary, NSError, NSNumber, NSPropertyListFormat,
+    NSPropertyListMutabilityOptions, NSPropertyListSerialization, NSString,
+};
+
+/// Serialize the value into a property list of the given format.
+///
+/// Returns an error if the value cannot be represented in a property list,
+/// or if the format is [`NSPropertyListFormat::OpenStepFormat`], which is
+/// only supported for reading.
+pub fn to_data<T>(value: &T, format: NSPropertyListFormat) -> Result<Retained<NSData>, Error>
+where
+    T: ?Sized + Serialize,
+{
+    let object = to_object(value)?;
+    NSPropertyListSerialization::to_data(&object, format).map_err(Error::Foundation)
+}
+
+/// Serialize the value into the bytes of a property list of the given
+/// format.
+///
+/// See [`to_data`] for details.
+pub fn to_vec<T>(value: &T, format: NSPropertyListFormat) -> Result<Vec<u8>, Error>
+where
+    T: ?Sized + Serialize,
+{
+    Ok(to_data(value, format)?.to_vec())
+}
+
+/// Deserialize a value from a property list in any of the supported
+/// formats.
+pub fn from_slice<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error> {
+    let (object, _format) =
+        NSPropertyListSerialization::from_slice(bytes, NSPropertyListMutabilityOptions::Immutable)
+            .map_err(Error::Foundation)?;
+    from_object(&object)
+}
+
+/// Convert the value into a graph of property list objects.
+pub fn to_object<T: ?Sized + Serialize>(value: &T) -> Result<Retained<AnyObject>, Error> {
+    value
+        .serialize(ObjectSerializer)?
+        .ok_or_else(|| Error::Message("property lists cannot contain missing values".into()))
+}
+
+/// Convert a graph of property list objects into the value.
+pub fn from_object<T: DeserializeOwned>(object: &AnyObject) -> Result<T, Error> {
+    T::deserialize(Deserializer::new(object))
+}
+
+/// An error that occurred while converting to or from a property list.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Foundation failed to read or write the property list.
+    Foundation(Retained<NSError>),
+    /// The value cannot be represented as a property list, or the property
+    /// list does not match the expected type.
+    Message(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Foundation(error) => fmt::Display::fmt(error, f),
+            Self::Message(message) => f.write_str(message),
+        }
+    }
+}
+
+impl ser::StdError for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Message(msg.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::Message(msg.to_string())
+    }
+}
+
+fn upcast<T: Message>(object: Retained<T>) -> Option<Retained<AnyObject>> {
+    // SAFETY: All objects can be used as `AnyObject`.
+    Some(unsafe { Retained::cast_unchecked(object) })
+}
+
+fn single_entry(key: &str, object: Retained<AnyObject>) -> Option<Retained<AnyObject>> {
+    let key = NSString::from_str(key);
+    upcast(NSDictionary::<NSString, AnyObject>::from_retained_objects(
+        &[&*key],
+        &[object],
+    ))
+}
+
+/// Serializes into an object, or `None` if the value is missing.
+struct ObjectSerializer;
+
+impl ser::Serializer for ObjectSerializer {
+    type Ok = Option<Retained<AnyObject>>;
+    type Error = Error;
+
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = VariantSerializer<SeqSerializer>;
+    type SerializeMap = MapSerializer;
+    type SerializeStruct = MapSerializer;
+    type SerializeStructVariant = VariantSerializer<MapSerializer>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Error> {
+        Ok(upcast(NSNumber::new_bool(v)))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Error> {
+        self.serialize_i64(v.into())
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Error> {
+        Ok(upcast(NSNumber::new_i64(v)))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Error> {
+        self.serialize_u64(v.into())
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Error> {
+        Ok(upcast(NSNumber::new_u64(v)))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Error> {
+        self.serialize_f64(v.into())
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Error> {
+        Ok(upcast(NSNumber::new_f64(v)))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Error> {
+        self.serialize_str(v.encode_utf8(&mut [0; 4]))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Error> {
+        Ok(upcast(NSString::from_str(v)))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Self::Ok, Error> {
+        Ok(upcast(NSData::with_bytes(v)))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Error> {
+        match value.serialize(ObjectSerializer)? {
+            Some(object) => Ok(single_entry(variant, object)),
+            None => self.serialize_str(variant),
+        }
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        Ok(SeqSerializer {
+            objects: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        Ok(VariantSerializer {
+            variant,
+            inner: self.serialize_seq(Some(len))?,
+        })
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        let len = len.unwrap_or(0);
+        Ok(MapSerializer {
+            keys: Vec::with_capacity(len),
+            objects: Vec::with_capacity(len),
+            next_key: None,
+        })
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        self.serialize_map(Some(len))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        Ok(VariantSerializer {
+            variant,
+            inner: self.serialize_map(Some(len))?,
+        })
+    }
+}
+
+struct SeqSerializer {
+    objects: Vec<Retained<AnyObject>>,
+}
+
+impl SeqSerializer {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let object = value.serialize(ObjectSerializer)?.ok_or_else(|| {
+            Error::Message("property list arrays cannot contain missing values".into())
+        })?;
+        self.objects.push(object);
+        Ok(())
+    }
+
+    fn finish(self) -> Option<Retained<AnyObject>> {
+        upcast(NSArray::from_retained_slice(&self.objects))
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Option<Retained<AnyObject>>;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Option<Retained<AnyObject>>;
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Option<Retained<AnyObject>>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.finish())
+    }
+}
+
+struct MapSerializer {
+    keys: Vec<Retained<NSString>>,
+    objects: Vec<Retained<AnyObject>>,
+    next_key: Option<Retained<NSString>>,
+}
+
+impl MapSerializer {
+    fn insert<T: ?Sized + Serialize>(
+        &mut self,
+        key: Retained<NSString>,
+        value: &T,
+    ) -> Result<(), Error> {
+        // Skip missing values, since property lists cannot represent them.
+        if let Some(object) = value.serialize(ObjectSerializer)? {
+            self.keys.push(key);
+            self.objects.push(object);
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Option<Retained<AnyObject>> {
+        let keys: Vec<&NSString> = self.keys.iter().map(|key| &**key).collect();
+        upcast(NSDictionary::<NSString, AnyObject>::from_retained_objects(
+            &keys,
+            &self.objects,
+        ))
+    }
+}
+
+impl ser::SerializeMap for MapSerializer {
+    type Ok = Option<Retained<AnyObject>>;
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        let key = key
+            .serialize(ObjectSerializer)?
+            .and_then(|key| key.downcast::<NSString>().ok())
+            .ok_or_else(|| Error::Message("property list keys must be strings".into()))?;
+        self.next_key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self
+            .next_key
+            .take()
+            .expect("serialize_value called before serialize_key");
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStruct for MapSerializer {
+    type Ok = Option<Retained<AnyObject>>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.insert(NSString::from_str(key), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        Ok(self.finish())
+    }
+}
+
+/// Wraps the contents of an enum variant in a single-entry dictionary.
+struct VariantSerializer<S> {
+    variant: &'static str,
+    inner: S,
+}
+
+impl ser::SerializeTupleVariant for VariantSerializer<SeqSerializer> {
+    type Ok = Option<Retained<AnyObject>>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.inner.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        let object = self.inner.finish().expect("arrays are never missing");
+        Ok(single_entry(self.variant, object))
+    }
+}
+
+impl ser::SerializeStructVariant for VariantSerializer<MapSerializer> {
+    type Ok = Option<Retained<AnyObject>>;
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.inner.insert(NSString::from_str(key), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Error> {
+        let object = self.inner.finish().expect("dictionaries are never missing");
+        Ok(single_entry(self.variant, object))
+    }
+}
+
+/// A deserializer that reads from a graph of property list objects.
+#[derive(Clone, Copy, Debug)]
+pub struct Deserializer<'a> {
+    object: &'a AnyObject,
+}
+
+impl<'a> Deserializer<'a> {
+    /// Create a deserializer for the given object.
+    pub fn new(object: &'a AnyObject) -> Self {
+        Self { object }
+    }
+
+    fn unsupported(self) -> Error {
+        Error::Message(alloc::format!(
+            "unsupported property list object of class {}",
+            self.object.class().name().to_string_lossy(),
+        ))
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        use objc2::encode::Encoding;
+
+        let object = self.object;
+        if let Some(string) = object.downcast_ref::<NSString>() {
+            visitor.visit_string(string.to_string())
+        } else if let Some(number) = object.downcast_ref::<NSNumber>() {
+            if number.is_bool() {
+                return visitor.visit_bool(number.as_bool());
+            }
+            match number.encoding() {
+                Encoding::Char
+                | Encoding::Short
+                | Encoding::Int
+                | Encoding::Long
+                | Encoding::LongLong => visitor.visit_i64(number.as_i64()),
+                Encoding::UChar
+                | Encoding::UShort
+                | Encoding::UInt
+                | Encoding::ULong
+                | Encoding::ULongLong => visitor.visit_u64(number.as_u64()),
+                _ => visitor.visit_f64(number.as_f64()),
+            }
+        } else if let Some(data) = object.downcast_ref::<NSData>() {
+            visitor.visit_byte_buf(data.to_vec())
+        } else if let Some(date) = object.downcast_ref::<NSDate>() {
+            visitor.visit_f64(date.timeIntervalSince1970())
+        } else if let Some(array) = object.downcast_ref::<NSArray>() {
+            visitor.visit_seq(SeqDeserializer {
+                objects: array.to_vec().into_iter(),
+            })
+        } else if let Some(dictionary) = object.downcast_ref::<NSDictionary>() {
+            let (keys, objects) = dictionary.to_vecs();
+            visitor.visit_map(MapDeserializer {
+                entries: keys.into_iter().zip(objects),
+                next_object: None,
+            })
+        } else {
+            Err(self.unsupported())
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        // Missing values are not stored at all, so if we get here, the value
+        // is present.
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        if let Some(string) = self.object.downcast_ref::<NSString>() {
+            return visitor.visit_enum(string.to_string().into_deserializer());
+        }
+        if let Some(dictionary) = self.object.downcast_ref::<NSDictionary>() {
+            let (keys, objects) = dictionary.to_vecs();
+            if let ([variant], [object]) = (&*keys, &*objects) {
+                return visitor.visit_enum(EnumDeserializer {
+                    variant: variant.clone(),
+                    object: object.clone(),
+                });
+            }
+        }
+        Err(de::Error::invalid_type(
+            de::Unexpected::Other("property list object"),
+            &"a string or a dictionary with a single entry",
+        ))
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct SeqDeserializer {
+    objects: vec::IntoIter<Retained<AnyObject>>,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqDeserializer {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        self.objects
+            .next()
+            .map(|object| seed.deserialize(Deserializer::new(&object)))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.objects.len())
+    }
+}
+
+struct MapDeserializer<I> {
+    entries: I,
+    next_object: Option<Retained<AnyObject>>,
+}
+
+impl<'de, I> de::MapAccess<'de> for MapDeserializer<I>
+where
+    I: Iterator<Item = (Retained<AnyObject>, Retained<AnyObject>)>,
+{
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.entries.next() {
+            Some((key, object)) => {
+                self.next_object = Some(object);
+                seed.deserialize(Deserializer::new(&key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let object = self
+            .next_object
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        seed.deserialize(Deserializer::new(&object))
+    }
+}
+
+struct EnumDeserializer {
+    variant: Retained<AnyObject>,
+    object: Retained<AnyObject>,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumDeserializer {
+    type Error = Error;
+    type Variant = VariantDeserializer;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Error> {
+        let variant = seed.deserialize(Deserializer::new(&self.variant))?;
+        Ok((variant, VariantDeserializer(self.object)))
+    }
+}
+
+struct VariantDeserializer(Retained<AnyObject>);
+
+impl<'de> de::VariantAccess<'de> for VariantDeserializer {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(Deserializer::new(&self.0))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_seq(Deserializer::new(&self.0), visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_map(Deserializer::new(&self.0), visitor)
+    }
+}
@@ -0,0 +1,64 @@
+//! Utilities for the `NSPointerArray` class.
+use core::ffi::c_void;
+
+use objc2::rc::Retained;
+use objc2::Message;
+
+use crate::NSPointerArray;
+
+/// Object access.
+///
+/// These methods are only valid on pointer arrays that hold objects, such
+/// as those created with `strongObjectsPointerArray` or
+/// `weakObjectsPointerArray`.
+impl NSPointerArray {
+    /// Get the object at the given index, or `None` if the entry is NULL.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// The pointer array must use the object personality, and the entry at
+    /// the given index must be either NULL or an object of type `T`.
+    ///
+    /// If the array holds weak references, the object must not be
+    /// deallocated concurrently on another thread.
+    #[doc(alias = "pointerAtIndex:")]
+    pub unsafe fn object_at<T: Message>(&self, index: usize) -> Option<Retained<T>> {
+        assert!(index < self.count(), "index out of bounds");
+        let ptr: *mut T = self.pointerAtIndex(index).cast();
+        // SAFETY: Upheld by the caller.
+        unsafe { Retained::retain(ptr) }
+    }
+
+    /// Append an object, or a NULL entry.
+    ///
+    /// # Safety
+    ///
+    /// The pointer array must use the object personality.
+    #[doc(alias = "addPointer:")]
+    pub unsafe fn push_object<T: Message>(&self, object: Option<&T>) {
+        let ptr = object.map_or(core::ptr::null(), |object| object as *const T);
+        // SAFETY: Upheld by the caller.
+        unsafe { self.addPointer(ptr as *mut c_void) }
+    }
+
+    /// Replace the entry at the given index with an object, or with NULL.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    ///
+    /// # Safety
+    ///
+    /// The pointer array must use the object personality.
+    #[doc(alias = "replacePointerAtIndex:withPointer:")]
+    pub unsafe fn replace_object<T: Message>(&self, index: usize, object: Option<&T>) {
+        assert!(index < self.count(), "index out of bounds");
+        let ptr = object.map_or(core::ptr::null(), |object| object as *const T);
+        // SAFETY: Upheld by the caller.
+        unsafe { self.replacePointerAtIndex_withPointer(index, ptr as *mut c_void) }
+    }
+}
@@ -0,0 +1,120 @@
+//! Utilities for the `NSPredicate` and `NSCompoundPredicate` classes.
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+
+use crate::{NSArray, NSPredicate, NSString};
+
+/// Creation methods.
+impl NSPredicate {
+    /// Create a predicate from a format string, substituting the given
+    /// arguments for the `%@` and `%K` placeholders.
+    ///
+    /// See Apple's [Predicate Format String Syntax][syntax] documentation.
+    ///
+    /// [syntax]: https://developer.apple.com/library/archive/documentation/Cocoa/Conceptual/Predicates/Articles/pSyntax.html
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Throws an exception if the format string is invalid, or if the
+    /// number of arguments doesn't match the number of placeholders.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The format string can contain `FUNCTION(...)` expressions and key
+    /// paths, which send arbitrary messages to the evaluated objects when
+    /// the predicate is evaluated. The format and the arguments must be
+    /// trusted, and the methods that are called must be safe to call on the
+    /// objects that the predicate is evaluated with.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSPredicate};
+    ///
+    /// // SAFETY: `BEGINSWITH` on strings only calls string comparison methods.
+    /// unsafe {
+    ///     let predicate =
+    ///         NSPredicate::from_format(ns_string!("SELF BEGINSWITH %@"), &[ns_string!("a")]);
+    ///     assert!(predicate.evaluateWithObject(Some(ns_string!("abc"))));
+    ///     assert!(!predicate.evaluateWithObject(Some(ns_string!("bcd"))));
+    /// }
+    /// ```
+    #[doc(alias = "predicateWithFormat:argumentArray:")]
+    pub unsafe fn from_format(format: &NSString, arguments: &[&AnyObject]) -> Retained<Self> {
+        let arguments = NSArray::from_slice(arguments);
+        // SAFETY: Upheld by caller.
+        unsafe { Self::predicateWithFormat_argumentArray(format, Some(&arguments)) }
+    }
+
+    /// Create a predicate that evaluates the given closure.
+    ///
+    /// The closure receives the object being evaluated, which may be `None`.
+    ///
+    /// Note that block-based predicates cannot be used with Core Data
+    /// fetch requests or Spotlight queries, since they cannot be converted
+    /// to a format string.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSArray, NSPredicate, NSString};
+    ///
+    /// let predicate = NSPredicate::from_fn(|object| {
+    ///     object
+    ///         .and_then(|object| object.downcast_ref::<NSString>())
+    ///         .is_some_and(|string| string.len() > 1)
+    /// });
+    /// let array = NSArray::from_slice(&[ns_string!("a"), ns_string!("bb")]);
+    /// // SAFETY: The predicate only calls the closure.
+    /// let filtered = unsafe { array.filteredArrayUsingPredicate(&predicate) };
+    /// assert_eq!(filtered.len(), 1);
+    /// ```
+    #[cfg(feature = "block2")]
+    #[cfg(feature = "NSDictionary")]
+    #[doc(alias = "predicateWithBlock:")]
+    pub fn from_fn(
+        f: impl Fn(Option<&AnyObject>) -> bool + Send + Sync + 'static,
+    ) -> Retained<Self> {
+        let block = block2::RcBlock::new(
+            move |object: *mut AnyObject,
+                  _bindings: *mut crate::NSDictionary<NSString, AnyObject>|
+                  -> objc2::runtime::Bool {
+                // SAFETY: The object is either NULL or a valid object.
+                objc2::runtime::Bool::new(f(unsafe { object.as_ref() }))
+            },
+        );
+        // SAFETY: The block is `Send + Sync` and `'static`, since the
+        // predicate may be evaluated on any thread.
+        unsafe { Self::predicateWithBlock(&block) }
+    }
+}
+
+/// Creation methods.
+#[cfg(feature = "NSCompoundPredicate")]
+impl crate::NSCompoundPredicate {
+    /// A predicate that is true if all the given predicates are true.
+    ///
+    /// This is true if there are no predicates.
+    #[doc(alias = "andPredicateWithSubpredicates:")]
+    pub fn and(predicates: &[&NSPredicate]) -> Retained<Self> {
+        Self::andPredicateWithSubpredicates(&NSArray::from_slice(predicates))
+    }
+
+    /// A predicate that is true if any of the given predicates are true.
+    ///
+    /// This is false if there are no predicates.
+    #[doc(alias = "orPredicateWithSubpredicates:")]
+    pub fn or(predicates: &[&NSPredicate]) -> Retained<Self> {
+        Self::orPredicateWithSubpredicates(&NSArray::from_slice(predicates))
+    }
+
+    /// A predicate that is true if the given predicate is false.
+    #[doc(alias = "notPredicateWithSubpredicate:")]
+    pub fn not(predicate: &NSPredicate) -> Retained<Self> {
+        Self::notPredicateWithSubpredicate(predicate)
+    }
+}
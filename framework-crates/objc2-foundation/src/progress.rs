@@ -0,0 +1,96 @@
+//! Utilities for the `NSProgress` class.
+use objc2::rc::Retained;
+
+use crate::NSProgress;
+
+/// Convenience methods.
+impl NSProgress {
+    /// Create a new progress object with the given total unit count, that is
+    /// not part of any progress tree.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSProgress;
+    ///
+    /// let progress = NSProgress::discrete(10);
+    /// progress.increment(4);
+    /// assert_eq!(progress.fractionCompleted(), 0.4);
+    /// ```
+    #[doc(alias = "discreteProgressWithTotalUnitCount:")]
+    pub fn discrete(total_unit_count: i64) -> Retained<Self> {
+        Self::discreteProgressWithTotalUnitCount(total_unit_count)
+    }
+
+    /// Increment the completed unit count by the given amount.
+    #[doc(alias = "setCompletedUnitCount:")]
+    pub fn increment(&self, unit_count: i64) {
+        self.setCompletedUnitCount(self.completedUnitCount() + unit_count);
+    }
+
+    /// Make this the current progress object while running the closure, such
+    /// that progress objects created inside it become children of this one,
+    /// with the given pending unit count.
+    ///
+    /// The progress object is resigned as current afterwards, even if the
+    /// closure panics.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSProgress;
+    ///
+    /// let parent = NSProgress::progressWithTotalUnitCount(2);
+    /// let child = parent.with_current(1, || NSProgress::progressWithTotalUnitCount(5));
+    /// child.setCompletedUnitCount(5);
+    /// assert_eq!(parent.fractionCompleted(), 0.5);
+    /// ```
+    #[doc(alias = "becomeCurrentWithPendingUnitCount:")]
+    #[doc(alias = "resignCurrent")]
+    pub fn with_current<R>(&self, pending_unit_count: i64, f: impl FnOnce() -> R) -> R {
+        struct ResignOnDrop<'a>(&'a NSProgress);
+
+        impl Drop for ResignOnDrop<'_> {
+            fn drop(&mut self) {
+                self.0.resignCurrent();
+            }
+        }
+
+        self.becomeCurrentWithPendingUnitCount(pending_unit_count);
+        let _guard = ResignOnDrop(self);
+        f()
+    }
+}
+
+/// Handlers.
+///
+/// The handlers may be invoked on any thread, and are therefore required to
+/// be `Send + Sync`.
+#[cfg(feature = "block2")]
+impl NSProgress {
+    /// Set the closure to run when the progress is cancelled.
+    #[doc(alias = "setCancellationHandler:")]
+    pub fn set_cancellation_handler(&self, handler: impl Fn() + Send + Sync + 'static) {
+        let block = block2::RcBlock::new(handler);
+        // SAFETY: The block is `Send + Sync` and `'static`.
+        unsafe { self.setCancellationHandler(Some(&block)) };
+    }
+
+    /// Set the closure to run when the progress is paused.
+    #[doc(alias = "setPausingHandler:")]
+    pub fn set_pausing_handler(&self, handler: impl Fn() + Send + Sync + 'static) {
+        let block = block2::RcBlock::new(handler);
+        // SAFETY: The block is `Send + Sync` and `'static`.
+        unsafe { self.setPausingHandler(Some(&block)) };
+    }
+
+    /// Set the closure to run when the progress is resumed.
+    #[doc(alias = "setResumingHandler:")]
+    pub fn set_resuming_handler(&self, handler: impl Fn() + Send + Sync + 'static) {
+        let block = block2::RcBlock::new(handler);
+        // SAFETY: The block is `Send + Sync` and `'static`.
+        unsafe { self.setResumingHandler(Some(&block)) };
+    }
+}
@@ -0,0 +1,68 @@
+//! Utilities for the `NSPropertyListSerialization` class.
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+
+use crate::{
+    NSData, NSError, NSPropertyListFormat, NSPropertyListMutabilityOptions,
+    NSPropertyListSerialization,
+};
+
+impl NSPropertyListSerialization {
+    /// Parse a property list in any of the supported formats.
+    ///
+    /// Returns the deserialized object graph, along with the format that the
+    /// data was in.
+    ///
+    /// By default, the returned containers and leaves are immutable; use
+    /// [`NSPropertyListMutabilityOptions`] to request mutable ones.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{
+    ///     NSPropertyListFormat, NSPropertyListMutabilityOptions, NSPropertyListSerialization,
+    ///     NSString,
+    /// };
+    ///
+    /// let xml = br#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <plist version="1.0"><string>hello</string></plist>"#;
+    /// let (object, format) = NSPropertyListSerialization::from_slice(
+    ///     xml,
+    ///     NSPropertyListMutabilityOptions::Immutable,
+    /// ).unwrap();
+    /// assert_eq!(format, NSPropertyListFormat::XMLFormat_v1_0);
+    /// assert_eq!(object.downcast_ref::<NSString>().unwrap().to_string(), "hello");
+    /// ```
+    #[doc(alias = "propertyListWithData:options:format:error:")]
+    pub fn from_slice(
+        bytes: &[u8],
+        options: NSPropertyListMutabilityOptions,
+    ) -> Result<(Retained<AnyObject>, NSPropertyListFormat), Retained<NSError>> {
+        let data = NSData::with_bytes(bytes);
+        // Overwritten on success.
+        let mut format = NSPropertyListFormat::BinaryFormat_v1_0;
+        // SAFETY: The format pointer is valid for writes.
+        let object = unsafe {
+            Self::propertyListWithData_options_format_error(&data, options, &mut format)
+        }?;
+        Ok((object, format))
+    }
+
+    /// Serialize an object graph into a property list of the given format.
+    ///
+    /// The output is byte-for-byte identical to what Objective-C code using
+    /// `dataWithPropertyList:format:options:error:` would produce.
+    ///
+    /// Returns an error if the graph contains objects that cannot be
+    /// represented in a property list, or if the format is
+    /// [`NSPropertyListFormat::OpenStepFormat`], which is only supported for
+    /// reading.
+    #[doc(alias = "dataWithPropertyList:format:options:error:")]
+    pub fn to_data(
+        object: &AnyObject,
+        format: NSPropertyListFormat,
+    ) -> Result<Retained<NSData>, Retained<NSError>> {
+        Self::dataWithPropertyList_format_options_error(object, format, 0)
+    }
+}
@@ -1,7 +1,9 @@
+use core::iter;
 use core::ops::Range;
 
 use objc2::encode::{Encode, Encoding, RefEncode};
 
+use crate::util::NOT_FOUND;
 use crate::NSUInteger;
 
 /// TODO.
@@ -83,6 +85,133 @@ impl NSRange {
             .expect("NSRange too large")
     }
 
+    /// Create a range from a Rust range, or `None` if the end of the range
+    /// is before the start.
+    ///
+    /// The [`From`] implementation panics in that case instead (and hence
+    /// [`TryFrom`] is infallible).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSRange;
+    ///
+    /// assert_eq!(NSRange::checked_from(3..5), Some(NSRange::new(3, 2)));
+    /// # #[allow(clippy::reversed_empty_ranges)]
+    /// assert_eq!(NSRange::checked_from(5..3), None);
+    /// ```
+    #[inline]
+    pub fn checked_from(range: Range<usize>) -> Option<Self> {
+        let length = range.end.checked_sub(range.start)?;
+        Some(Self::new(range.start, length))
+    }
+
+    /// Convert the range to a Rust range, clamped to `0..len`.
+    ///
+    /// This is useful for indexing into a collection of length `len`
+    /// without panicking on out-of-bounds ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSRange;
+    ///
+    /// assert_eq!(NSRange::new(3, 2).to_range(10), 3..5);
+    /// assert_eq!(NSRange::new(3, 10).to_range(10), 3..10);
+    /// assert_eq!(NSRange::new(12, 2).to_range(10), 10..10);
+    /// ```
+    #[inline]
+    pub fn to_range(self, len: usize) -> Range<usize> {
+        let start = self.location.min(len);
+        let end = self.location.saturating_add(self.length).min(len);
+        start..end
+    }
+
+    /// Returns `None` if the location is `NSNotFound`, which many
+    /// Foundation methods use to signal that nothing was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSRange;
+    ///
+    /// assert_eq!(NSRange::new(7, 5).found(), Some(NSRange::new(7, 5)));
+    ///
+    /// let not_found = NSRange::new(isize::MAX as usize, 0);
+    /// assert_eq!(not_found.found(), None);
+    /// ```
+    #[inline]
+    #[doc(alias = "NSNotFound")]
+    pub fn found(self) -> Option<Self> {
+        if self.location == NOT_FOUND {
+            None
+        } else {
+            Some(self)
+        }
+    }
+
+    /// Convert a range of UTF-8 bytes in `string` to the corresponding range
+    /// of UTF-16 code units, as used by `NSString`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or if it does not lie on
+    /// character boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSRange;
+    ///
+    /// let string = "a😀b";
+    /// // The emoji is four bytes in UTF-8, but two code units in UTF-16.
+    /// assert_eq!(NSRange::from_utf8_range(string, 5..6), NSRange::new(3, 1));
+    /// ```
+    pub fn from_utf8_range(string: &str, range: Range<usize>) -> Self {
+        let location = string[..range.start].encode_utf16().count();
+        let length = string[range].encode_utf16().count();
+        Self::new(location, length)
+    }
+
+    /// Convert a range of UTF-16 code units in `string` to the
+    /// corresponding range of UTF-8 bytes.
+    ///
+    /// Returns `None` if the range is out of bounds, or if either end of
+    /// the range splits a surrogate pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSRange;
+    ///
+    /// let string = "a😀b";
+    /// assert_eq!(NSRange::new(1, 2).to_utf8_range(string), Some(1..5));
+    /// // Half of the emoji.
+    /// assert_eq!(NSRange::new(1, 1).to_utf8_range(string), None);
+    /// ```
+    pub fn to_utf8_range(self, string: &str) -> Option<Range<usize>> {
+        let end = self.location.checked_add(self.length)?;
+        let mut start_byte = None;
+        let mut utf16_index = 0;
+        let boundaries = string
+            .char_indices()
+            .map(|(byte, c)| (byte, c.len_utf16()))
+            .chain(iter::once((string.len(), 0)));
+        for (byte, len_utf16) in boundaries {
+            if utf16_index == self.location {
+                start_byte = Some(byte);
+            }
+            if utf16_index == end {
+                return Some(start_byte?..byte);
+            }
+            if end < utf16_index {
+                break;
+            }
+            utf16_index += len_utf16;
+        }
+        None
+    }
+
     // TODO: https://developer.apple.com/documentation/foundation/1408420-nsrangefromstring
     // TODO: NSUnionRange
     // TODO: NSIntersectionRange
@@ -154,6 +283,46 @@ mod tests {
         let _ = NSRange::from(10..0);
     }
 
+    #[test]
+    fn test_to_range() {
+        assert_eq!(NSRange::new(0, 0).to_range(0), 0..0);
+        assert_eq!(NSRange::new(usize::MAX, usize::MAX).to_range(5), 5..5);
+        assert_eq!(NSRange::new(2, usize::MAX).to_range(5), 2..5);
+    }
+
+    #[test]
+    fn test_utf8_range() {
+        let string = "æ😀b";
+        let cases: &[(Range<usize>, NSRange)] = &[
+            (0..0, NSRange::new(0, 0)),
+            (0..2, NSRange::new(0, 1)),
+            (2..6, NSRange::new(1, 2)),
+            (6..7, NSRange::new(3, 1)),
+            (0..7, NSRange::new(0, 4)),
+            (7..7, NSRange::new(4, 0)),
+        ];
+
+        for (range, expected) in cases {
+            assert_eq!(NSRange::from_utf8_range(string, range.clone()), *expected);
+            assert_eq!(expected.to_utf8_range(string), Some(range.clone()));
+        }
+
+        // Splitting the surrogate pair.
+        assert_eq!(NSRange::new(2, 0).to_utf8_range(string), None);
+        assert_eq!(NSRange::new(0, 2).to_utf8_range(string), None);
+        assert_eq!(NSRange::new(2, 2).to_utf8_range(string), None);
+        // Out of bounds.
+        assert_eq!(NSRange::new(4, 1).to_utf8_range(string), None);
+        assert_eq!(NSRange::new(5, 0).to_utf8_range(string), None);
+        assert_eq!(NSRange::new(1, usize::MAX).to_utf8_range(string), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_utf8_range_not_char_boundary() {
+        let _ = NSRange::from_utf8_range("æ", 0..1);
+    }
+
     #[test]
     fn test_contains() {
         let range = NSRange::from(10..20);
@@ -0,0 +1,222 @@
+//! Utilities for the `NSRegularExpression`, `NSDataDetector` and
+//! `NSTextCheckingResult` classes.
+//!
+//! Note that the ranges returned by these methods are measured in UTF-16
+//! code units, see [`NSString::len_utf16`].
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use objc2::rc::Retained;
+use objc2::AllocAnyThread;
+
+use crate::{
+    NSDataDetector, NSError, NSMatchingOptions, NSRange, NSRegularExpression,
+    NSRegularExpressionOptions, NSString, NSTextCheckingResult, NSTextCheckingType,
+};
+
+/// Convert a range returned from Foundation to a Rust range, taking care to
+/// map `{NSNotFound, 0}` to `None`.
+fn found_range(range: NSRange) -> Option<Range<usize>> {
+    range.found().map(Range::from)
+}
+
+fn full_range(string: &NSString) -> NSRange {
+    NSRange::new(0, string.len_utf16())
+}
+
+/// Creation methods.
+impl NSRegularExpression {
+    /// Compile a new regular expression with the given options.
+    ///
+    /// The pattern is interpreted using [ICU's regular expression
+    /// syntax][icu], the same as in Objective-C and Swift.
+    ///
+    /// [icu]: https://unicode-org.github.io/icu/userguide/strings/regexp.html
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is invalid.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSRegularExpression, NSRegularExpressionOptions};
+    ///
+    /// let regex = NSRegularExpression::new(
+    ///     ns_string!(r"(?<year>\d{4})-(?<month>\d{2})"),
+    ///     NSRegularExpressionOptions::empty(),
+    /// ).unwrap();
+    /// assert_eq!(regex.numberOfCaptureGroups(), 2);
+    ///
+    /// assert!(NSRegularExpression::new(ns_string!("("), NSRegularExpressionOptions::empty()).is_err());
+    /// ```
+    #[doc(alias = "initWithPattern:options:error:")]
+    pub fn new(
+        pattern: &NSString,
+        options: NSRegularExpressionOptions,
+    ) -> Result<Retained<Self>, Retained<NSError>> {
+        Self::initWithPattern_options_error(Self::alloc(), pattern, options)
+    }
+}
+
+/// Creation methods.
+impl NSDataDetector {
+    /// Create a new data detector that finds the given types of data, such
+    /// as links, dates, addresses and phone numbers.
+    ///
+    /// Since `NSDataDetector` is a subclass of [`NSRegularExpression`], the
+    /// matching methods below can be used to find the data. The detected
+    /// data is available from the result's type-specific properties, such
+    /// as `URL`, `date` or `phoneNumber`.
+    ///
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the types include types that are not supported by
+    /// data detectors, such as [`NSTextCheckingType::Spelling`].
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSDataDetector, NSTextCheckingType};
+    ///
+    /// let detector = NSDataDetector::new(NSTextCheckingType::Link).unwrap();
+    /// let result = detector.first_match(ns_string!("See https://example.com")).unwrap();
+    /// assert_eq!(
+    ///     result.URL().unwrap().absoluteString().unwrap().to_string(),
+    ///     "https://example.com",
+    /// );
+    /// ```
+    #[doc(alias = "initWithTypes:error:")]
+    pub fn new(types: NSTextCheckingType) -> Result<Retained<Self>, Retained<NSError>> {
+        Self::initWithTypes_error(Self::alloc(), types.0)
+    }
+}
+
+/// Matching methods.
+///
+/// These all search the entire string, use the underlying methods directly
+/// if you need to restrict the search to a specific range.
+impl NSRegularExpression {
+    /// Whether the regular expression matches anywhere in the string.
+    #[doc(alias = "rangeOfFirstMatchInString:options:range:")]
+    pub fn is_match(&self, string: &NSString) -> bool {
+        let range = self.rangeOfFirstMatchInString_options_range(
+            string,
+            NSMatchingOptions::empty(),
+            full_range(string),
+        );
+        found_range(range).is_some()
+    }
+
+    /// The first match in the string, if any.
+    #[doc(alias = "firstMatchInString:options:range:")]
+    pub fn first_match(&self, string: &NSString) -> Option<Retained<NSTextCheckingResult>> {
+        self.firstMatchInString_options_range(
+            string,
+            NSMatchingOptions::empty(),
+            full_range(string),
+        )
+    }
+
+    /// The number of matches in the string.
+    #[doc(alias = "numberOfMatchesInString:options:range:")]
+    pub fn count_matches(&self, string: &NSString) -> usize {
+        self.numberOfMatchesInString_options_range(
+            string,
+            NSMatchingOptions::empty(),
+            full_range(string),
+        )
+    }
+
+    /// Iterate over all the non-overlapping matches in the string.
+    ///
+    /// The matches are found up front, so the string may freely be mutated
+    /// while iterating.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSRegularExpression, NSRegularExpressionOptions};
+    ///
+    /// let regex = NSRegularExpression::new(ns_string!(r"\d+"), NSRegularExpressionOptions::empty()).unwrap();
+    /// let ranges: Vec<_> = regex
+    ///     .matches(ns_string!("a1b22c333"))
+    ///     .map(|m| m.capture_range(0).unwrap())
+    ///     .collect();
+    /// assert_eq!(ranges, [1..2, 3..5, 6..9]);
+    /// ```
+    #[cfg(feature = "NSArray")]
+    #[cfg(feature = "NSEnumerator")]
+    #[doc(alias = "matchesInString:options:range:")]
+    pub fn matches(&self, string: &NSString) -> crate::array::IntoIter<NSTextCheckingResult> {
+        self.matchesInString_options_range(string, NSMatchingOptions::empty(), full_range(string))
+            .into_iter()
+    }
+
+    /// Replace all matches in the string with the given template.
+    ///
+    /// The template may refer to capture groups with `$0`, `$1`, etc.; use
+    /// [`escapedTemplateForString`] to insert literal text.
+    ///
+    /// [`escapedTemplateForString`]: Self::escapedTemplateForString
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSRegularExpression, NSRegularExpressionOptions};
+    ///
+    /// let regex = NSRegularExpression::new(ns_string!(r"(\w+)@(\w+)"), NSRegularExpressionOptions::empty()).unwrap();
+    /// let res = regex.replace_all(ns_string!("foo@bar baz@qux"), ns_string!("$2@$1"));
+    /// assert_eq!(res.to_string(), "bar@foo qux@baz");
+    /// ```
+    #[doc(alias = "stringByReplacingMatchesInString:options:range:withTemplate:")]
+    pub fn replace_all(&self, string: &NSString, template: &NSString) -> Retained<NSString> {
+        self.stringByReplacingMatchesInString_options_range_withTemplate(
+            string,
+            NSMatchingOptions::empty(),
+            full_range(string),
+            template,
+        )
+    }
+}
+
+/// Capture group accessors.
+impl NSTextCheckingResult {
+    /// The UTF-16 range of the capture group at the given index.
+    ///
+    /// Index `0` is the range of the entire match.
+    ///
+    /// Returns `None` if the index is out of bounds, or if the group did not
+    /// participate in the match.
+    #[doc(alias = "rangeAtIndex:")]
+    pub fn capture_range(&self, index: usize) -> Option<Range<usize>> {
+        if index < self.numberOfRanges() {
+            found_range(self.rangeAtIndex(index))
+        } else {
+            None
+        }
+    }
+
+    /// The UTF-16 range of the named capture group.
+    ///
+    /// Returns `None` if no group exists with the given name, or if the group
+    /// did not participate in the match.
+    #[doc(alias = "rangeWithName:")]
+    pub fn named_capture_range(&self, name: &NSString) -> Option<Range<usize>> {
+        found_range(self.rangeWithName(name))
+    }
+
+    /// The UTF-16 ranges of all capture groups, including the entire match
+    /// at index `0`.
+    pub fn capture_ranges(&self) -> Vec<Option<Range<usize>>> {
+        (0..self.numberOfRanges())
+            .map(|index| found_range(self.rangeAtIndex(index)))
+            .collect()
+    }
+}
@@ -0,0 +1,129 @@
+//! Utilities for scheduling work on `NSRunLoop` in specific modes.
+//!
+//! A run loop only services the sources, timers and blocks that have been
+//! added to the mode that it is currently running in. Most notably, AppKit
+//! and UIKit run the main run loop in a separate mode while the user is
+//! tracking events (e.g. holding a scroll bar), so anything scheduled only
+//! in `NSDefaultRunLoopMode` stalls until tracking ends. Scheduling in
+//! `NSRunLoopCommonModes` instead makes it fire in all the "common" modes,
+//! which includes both of these.
+#[cfg(feature = "NSArray")]
+use core::cell::Cell;
+#[cfg(feature = "NSTimer")]
+#[cfg(feature = "NSDate")]
+use core::cell::RefCell;
+#[cfg(feature = "NSTimer")]
+#[cfg(feature = "NSDate")]
+use core::ptr::NonNull;
+
+#[cfg(feature = "NSTimer")]
+#[cfg(feature = "NSDate")]
+use objc2::rc::Retained;
+
+#[cfg(feature = "NSArray")]
+use crate::NSArray;
+use crate::{NSRunLoop, NSRunLoopMode};
+#[cfg(feature = "NSTimer")]
+#[cfg(feature = "NSDate")]
+use crate::{NSTimeInterval, NSTimer};
+
+/// Scheduling in specific modes.
+impl NSRunLoop {
+    /// Schedule the closure to run once on this run loop, the next time it
+    /// runs in the given mode.
+    ///
+    /// The closure runs on this run loop's thread, which is why it is
+    /// required to be `Send`.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// Run a closure on the main thread, even while the user is tracking
+    /// events.
+    ///
+    /// ```
+    /// use objc2_foundation::{NSRunLoop, NSRunLoopCommonModes};
+    ///
+    /// let run_loop = NSRunLoop::mainRunLoop();
+    /// run_loop.perform_in_mode(unsafe { NSRunLoopCommonModes }, || {
+    ///     println!("on the main thread");
+    /// });
+    /// ```
+    #[cfg(feature = "NSArray")]
+    #[doc(alias = "performInModes:block:")]
+    pub fn perform_in_mode(&self, mode: &NSRunLoopMode, f: impl FnOnce() + Send + 'static) {
+        self.perform_in_modes(&[mode], f);
+    }
+
+    /// Schedule the closure to run once on this run loop, the next time it
+    /// runs in any of the given modes.
+    ///
+    /// See [`perform_in_mode`](Self::perform_in_mode) for details.
+    #[cfg(feature = "NSArray")]
+    #[doc(alias = "performInModes:block:")]
+    pub fn perform_in_modes(&self, modes: &[&NSRunLoopMode], f: impl FnOnce() + Send + 'static) {
+        let f = Cell::new(Some(f));
+        let block = block2::RcBlock::new(move || {
+            // The block is only called once, but is required to be `Fn`.
+            if let Some(f) = f.take() {
+                f();
+            }
+        });
+        let modes = NSArray::from_slice(modes);
+        // SAFETY: The block is `Send` and `'static`, and is only called on
+        // this run loop's thread.
+        unsafe { self.performInModes_block(&modes, &block) };
+    }
+}
+
+/// Scheduling in specific modes.
+#[cfg(feature = "NSTimer")]
+#[cfg(feature = "NSDate")]
+impl NSTimer {
+    /// Create a timer that calls the closure after the given number of
+    /// seconds, and schedule it on the current run loop in the given mode.
+    ///
+    /// If `repeats` is `true`, the timer keeps firing with the given interval
+    /// until it is invalidated with [`invalidate`](Self::invalidate).
+    ///
+    /// This differs from `scheduledTimerWithTimeInterval_repeats_block`,
+    /// which always schedules the timer in `NSDefaultRunLoopMode`.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// Drive an animation that keeps running during event tracking.
+    ///
+    /// ```
+    /// use objc2_foundation::{NSRunLoopCommonModes, NSTimer};
+    ///
+    /// let mode = unsafe { NSRunLoopCommonModes };
+    /// let mut frame = 0;
+    /// let timer = NSTimer::schedule_in_mode(1.0 / 60.0, true, mode, move |_timer| {
+    ///     frame += 1;
+    /// });
+    /// # timer.invalidate();
+    /// ```
+    #[doc(alias = "timerWithTimeInterval:repeats:block:")]
+    #[doc(alias = "addTimer:forMode:")]
+    pub fn schedule_in_mode(
+        interval: NSTimeInterval,
+        repeats: bool,
+        mode: &NSRunLoopMode,
+        f: impl FnMut(&NSTimer) + 'static,
+    ) -> Retained<Self> {
+        let f = RefCell::new(f);
+        let block = block2::RcBlock::new(move |timer: NonNull<NSTimer>| {
+            // SAFETY: The timer is valid for the duration of the call.
+            let timer = unsafe { timer.as_ref() };
+            // The timer's block is never called re-entrantly.
+            (f.borrow_mut())(timer)
+        });
+        // SAFETY: The timer is added to the current run loop below, and
+        // thus the block is only called on the current thread.
+        let timer = unsafe { Self::timerWithTimeInterval_repeats_block(interval, repeats, &block) };
+        // SAFETY: The timer is valid, and the mode is a valid run loop mode.
+        unsafe { NSRunLoop::currentRunLoop().addTimer_forMode(&timer, mode) };
+        timer
+    }
+}
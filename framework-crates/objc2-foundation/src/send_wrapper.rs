@@ -0,0 +1,245 @@
+use core::fmt;
+use core::mem::{self, ManuallyDrop};
+use core::ops::{Deref, DerefMut};
+use std::thread::{self, ThreadId};
+
+/// Make a type that can only be used on the thread that created it be
+/// `Send` + `Sync`, checking that at runtime.
+///
+/// This allows moving objects that are not [`Send`] through code that
+/// requires it, such as an executor or a channel, as long as they are only
+/// ever accessed from the thread that created them again. Accessing or
+/// dropping the value from a different thread panics.
+///
+/// Prefer [`MainThreadBound`] for values that are only usable on the main
+/// thread, since that is checked at compile-time instead.
+///
+/// Thread-safety of classes is otherwise part of their declaration, see the
+/// [`AllocAnyThread`] and [`MainThreadOnly`] traits.
+///
+/// [`MainThreadBound`]: crate::MainThreadBound
+/// [`AllocAnyThread`]: objc2::AllocAnyThread
+/// [`MainThreadOnly`]: objc2::MainThreadOnly
+///
+///
+/// # Example
+///
+/// ```
+/// use objc2::rc::Retained;
+/// use objc2::runtime::NSObject;
+/// use objc2_foundation::SendWrapper;
+///
+/// let obj = SendWrapper::new(NSObject::new());
+/// let obj = std::thread::spawn(move || {
+///     // `*obj` would panic here.
+///     assert!(!obj.valid());
+///     obj
+/// })
+/// .join()
+/// .unwrap();
+///
+/// // Back on the original thread, the object can be used again.
+/// let obj: Retained<NSObject> = obj.into_inner();
+/// ```
+pub struct SendWrapper<T> {
+    inner: ManuallyDrop<T>,
+    thread: ThreadId,
+}
+
+// SAFETY: The inner value is only accessed and dropped on the thread that
+// created it, which is checked at runtime.
+unsafe impl<T> Send for SendWrapper<T> {}
+
+// SAFETY: Same as above, shared access is also checked.
+unsafe impl<T> Sync for SendWrapper<T> {}
+
+impl<T> SendWrapper<T> {
+    /// Wrap the value, binding it to the current thread.
+    #[inline]
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner: ManuallyDrop::new(inner),
+            thread: thread::current().id(),
+        }
+    }
+
+    /// Whether the value may be accessed from the current thread.
+    #[inline]
+    pub fn valid(&self) -> bool {
+        self.thread == thread::current().id()
+    }
+
+    #[track_caller]
+    fn assert_valid(&self) {
+        assert!(
+            self.valid(),
+            "SendWrapper accessed from a different thread than the one it was created on"
+        );
+    }
+
+    /// Returns a reference to the value.
+    ///
+    /// Returns `None` if called from a different thread than the one that
+    /// created the wrapper.
+    #[inline]
+    pub fn try_get(&self) -> Option<&T> {
+        self.valid().then(|| &*self.inner)
+    }
+
+    /// Returns a mutable reference to the value.
+    ///
+    /// Returns `None` if called from a different thread than the one that
+    /// created the wrapper.
+    #[inline]
+    pub fn try_get_mut(&mut self) -> Option<&mut T> {
+        if self.valid() {
+            Some(&mut self.inner)
+        } else {
+            None
+        }
+    }
+
+    /// Extracts the value from the wrapper.
+    ///
+    /// Returns the wrapper back if called from a different thread than the
+    /// one that created it.
+    #[inline]
+    pub fn try_into_inner(self) -> Result<T, Self> {
+        if self.valid() {
+            let mut this = ManuallyDrop::new(self);
+            // SAFETY: `self` is consumed by this function, and wrapped in
+            // `ManuallyDrop`, so the item's destructor is never run.
+            Ok(unsafe { ManuallyDrop::take(&mut this.inner) })
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Extracts the value from the wrapper.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a different thread than the one that created
+    /// the wrapper.
+    #[inline]
+    #[track_caller]
+    pub fn into_inner(self) -> T {
+        self.assert_valid();
+        match self.try_into_inner() {
+            Ok(inner) => inner,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl<T> Deref for SendWrapper<T> {
+    type Target = T;
+
+    /// Returns a reference to the value.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a different thread than the one that created
+    /// the wrapper.
+    #[inline]
+    #[track_caller]
+    fn deref(&self) -> &T {
+        self.assert_valid();
+        &self.inner
+    }
+}
+
+impl<T> DerefMut for SendWrapper<T> {
+    /// Returns a mutable reference to the value.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from a different thread than the one that created
+    /// the wrapper.
+    #[inline]
+    #[track_caller]
+    fn deref_mut(&mut self) -> &mut T {
+        self.assert_valid();
+        &mut self.inner
+    }
+}
+
+impl<T> Drop for SendWrapper<T> {
+    /// Drops the value.
+    ///
+    /// If this is called from a different thread than the one that created
+    /// the wrapper, this panics, unless the thread is already panicking, in
+    /// which case the value is leaked instead.
+    fn drop(&mut self) {
+        if !mem::needs_drop::<T>() {
+            return;
+        }
+        if self.valid() {
+            // SAFETY: The value is dropped on the thread that it originated
+            // from, and is never used again after this point.
+            unsafe { ManuallyDrop::drop(&mut self.inner) };
+        } else if !thread::panicking() {
+            panic!("SendWrapper dropped on a different thread than the one it was created on");
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SendWrapper<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.try_get() {
+            Some(inner) => f.debug_tuple("SendWrapper").field(inner).finish(),
+            None => f.debug_struct("SendWrapper").finish_non_exhaustive(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use std::rc::Rc;
+
+    static_assertions::assert_impl_all!(SendWrapper<Rc<()>>: Send, Sync);
+
+    #[test]
+    fn test_same_thread() {
+        let mut wrapper = SendWrapper::new(Cell::new(1));
+        assert!(wrapper.valid());
+        wrapper.set(2);
+        *wrapper.try_get_mut().unwrap().get_mut() += 1;
+        assert_eq!(wrapper.into_inner().get(), 3);
+    }
+
+    #[test]
+    fn test_other_thread() {
+        let value = Rc::new(());
+        let wrapper = SendWrapper::new(Rc::clone(&value));
+
+        let wrapper = thread::spawn(move || {
+            assert!(!wrapper.valid());
+            assert!(wrapper.try_get().is_none());
+            let wrapper = wrapper.try_into_inner().unwrap_err();
+            let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| {
+                let _ = &*wrapper;
+            }));
+            assert!(result.is_err());
+            wrapper
+        })
+        .join()
+        .unwrap();
+
+        assert_eq!(Rc::strong_count(&value), 2);
+        drop(wrapper);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_drop_on_other_thread_panics() {
+        let wrapper = SendWrapper::new(Rc::new(()));
+        let result = thread::spawn(move || drop(wrapper)).join();
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,322 @@
+//! Implementations of [`serde`]'s traits for Foundation value types.
+//!
+//! The objects are serialized as their closest Rust equivalent:
+//! - `NSString` as a string.
+//! - `NSNumber` as a boolean, an integer or a floating point number.
+//! - `NSDate` as a floating point number of seconds since the Unix epoch.
+//! - `NSData` as bytes.
+//! - `NSURL` as its absolute string.
+//! - `NSUUID` as its hyphenated string representation.
+//! - `NSArray` as a sequence.
+//! - `NSDictionary` as a map.
+//!
+//! Deserializing is done into [`Retained`], using [`DeserializeRetained`].
+use alloc::string::ToString;
+#[cfg(any(feature = "NSArray", feature = "NSData", feature = "NSDictionary"))]
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(any(feature = "NSArray", feature = "NSDictionary"))]
+use core::marker::PhantomData;
+
+use objc2::rc::{DeserializeRetained, Retained};
+#[cfg(any(feature = "NSArray", feature = "NSDictionary"))]
+use objc2::Message;
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+#[cfg(feature = "NSData")]
+impl Serialize for crate::NSData {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.to_vec())
+    }
+}
+
+#[cfg(feature = "NSData")]
+impl<'de> DeserializeRetained<'de> for crate::NSData {
+    fn deserialize_retained<D>(deserializer: D) -> Result<Retained<Self>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DataVisitor;
+
+        impl<'de> Visitor<'de> for DataVisitor {
+            type Value = Retained<crate::NSData>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("bytes")
+            }
+
+            fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Self::Value, E> {
+                Ok(crate::NSData::with_bytes(bytes))
+            }
+
+            fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Self::Value, E> {
+                Ok(crate::NSData::from_vec(bytes))
+            }
+
+            // Formats without native support for bytes (such as JSON)
+            // serialize them as a sequence of integers.
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(byte) = seq.next_element()? {
+                    bytes.push(byte);
+                }
+                Ok(crate::NSData::from_vec(bytes))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(DataVisitor)
+    }
+}
+
+#[cfg(feature = "NSDate")]
+impl Serialize for crate::NSDate {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.timeIntervalSince1970())
+    }
+}
+
+#[cfg(feature = "NSDate")]
+impl<'de> DeserializeRetained<'de> for crate::NSDate {
+    fn deserialize_retained<D>(deserializer: D) -> Result<Retained<Self>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let interval: f64 = serde::Deserialize::deserialize(deserializer)?;
+        Ok(Self::dateWithTimeIntervalSince1970(interval))
+    }
+}
+
+impl Serialize for crate::NSString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> DeserializeRetained<'de> for crate::NSString {
+    fn deserialize_retained<D>(deserializer: D) -> Result<Retained<Self>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct StringVisitor;
+
+        impl Visitor<'_> for StringVisitor {
+            type Value = Retained<crate::NSString>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string")
+            }
+
+            fn visit_str<E: de::Error>(self, string: &str) -> Result<Self::Value, E> {
+                Ok(crate::NSString::from_str(string))
+            }
+        }
+
+        deserializer.deserialize_str(StringVisitor)
+    }
+}
+
+#[cfg(feature = "NSURL")]
+impl Serialize for crate::NSURL {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.absoluteString() {
+            Some(string) => string.serialize(serializer),
+            None => Err(serde::ser::Error::custom("URL has no absolute string")),
+        }
+    }
+}
+
+#[cfg(feature = "NSURL")]
+impl<'de> DeserializeRetained<'de> for crate::NSURL {
+    fn deserialize_retained<D>(deserializer: D) -> Result<Retained<Self>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = crate::NSString::deserialize_retained(deserializer)?;
+        Self::URLWithString(&string).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Str(&string.to_string()), &"a valid URL")
+        })
+    }
+}
+
+#[cfg(feature = "NSUUID")]
+impl Serialize for crate::NSUUID {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.UUIDString().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "NSUUID")]
+impl<'de> DeserializeRetained<'de> for crate::NSUUID {
+    fn deserialize_retained<D>(deserializer: D) -> Result<Retained<Self>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let string = crate::NSString::deserialize_retained(deserializer)?;
+        Self::from_string(&string).ok_or_else(|| {
+            de::Error::invalid_value(de::Unexpected::Str(&string.to_string()), &"a UUID")
+        })
+    }
+}
+
+#[cfg(feature = "NSValue")]
+impl Serialize for crate::NSNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use objc2::encode::Encoding;
+
+        if self.is_bool() {
+            return serializer.serialize_bool(self.as_bool());
+        }
+        match self.encoding() {
+            Encoding::Char
+            | Encoding::Short
+            | Encoding::Int
+            | Encoding::Long
+            | Encoding::LongLong => serializer.serialize_i64(self.as_i64()),
+            Encoding::UChar
+            | Encoding::UShort
+            | Encoding::UInt
+            | Encoding::ULong
+            | Encoding::ULongLong => serializer.serialize_u64(self.as_u64()),
+            _ => serializer.serialize_f64(self.as_f64()),
+        }
+    }
+}
+
+#[cfg(feature = "NSValue")]
+impl<'de> DeserializeRetained<'de> for crate::NSNumber {
+    fn deserialize_retained<D>(deserializer: D) -> Result<Retained<Self>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct NumberVisitor;
+
+        impl Visitor<'_> for NumberVisitor {
+            type Value = Retained<crate::NSNumber>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a boolean or a number")
+            }
+
+            fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+                Ok(crate::NSNumber::new_bool(value))
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+                Ok(crate::NSNumber::new_i64(value))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                Ok(crate::NSNumber::new_u64(value))
+            }
+
+            fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+                Ok(crate::NSNumber::new_f64(value))
+            }
+        }
+
+        deserializer.deserialize_any(NumberVisitor)
+    }
+}
+
+#[cfg(feature = "NSArray")]
+impl<ObjectType: Message + Serialize> Serialize for crate::NSArray<ObjectType> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.to_vec())
+    }
+}
+
+#[cfg(feature = "NSArray")]
+impl<'de, ObjectType> DeserializeRetained<'de> for crate::NSArray<ObjectType>
+where
+    ObjectType: Message + DeserializeRetained<'de>,
+{
+    fn deserialize_retained<D>(deserializer: D) -> Result<Retained<Self>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ArrayVisitor<ObjectType>(PhantomData<ObjectType>);
+
+        impl<'de, ObjectType> Visitor<'de> for ArrayVisitor<ObjectType>
+        where
+            ObjectType: Message + DeserializeRetained<'de>,
+        {
+            type Value = Retained<crate::NSArray<ObjectType>>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut objects = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+                while let Some(object) = seq.next_element::<Retained<ObjectType>>()? {
+                    objects.push(object);
+                }
+                Ok(crate::NSArray::from_retained_slice(&objects))
+            }
+        }
+
+        deserializer.deserialize_seq(ArrayVisitor(PhantomData))
+    }
+}
+
+#[cfg(feature = "NSDictionary")]
+impl<KeyType, ObjectType> Serialize for crate::NSDictionary<KeyType, ObjectType>
+where
+    KeyType: Message + Serialize,
+    ObjectType: Message + Serialize,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (keys, objects) = self.to_vecs();
+        serializer.collect_map(keys.into_iter().zip(objects))
+    }
+}
+
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSObject")]
+impl<'de, KeyType, ObjectType> DeserializeRetained<'de> for crate::NSDictionary<KeyType, ObjectType>
+where
+    KeyType: Message
+        + crate::NSCopying
+        + crate::CopyingHelper<Result = KeyType>
+        + DeserializeRetained<'de>,
+    ObjectType: Message + DeserializeRetained<'de>,
+{
+    fn deserialize_retained<D>(deserializer: D) -> Result<Retained<Self>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct DictionaryVisitor<KeyType, ObjectType>(PhantomData<(KeyType, ObjectType)>);
+
+        impl<'de, KeyType, ObjectType> Visitor<'de> for DictionaryVisitor<KeyType, ObjectType>
+        where
+            KeyType: Message
+                + crate::NSCopying
+                + crate::CopyingHelper<Result = KeyType>
+                + DeserializeRetained<'de>,
+            ObjectType: Message + DeserializeRetained<'de>,
+        {
+            type Value = Retained<crate::NSDictionary<KeyType, ObjectType>>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let capacity = map.size_hint().unwrap_or(0);
+                let mut keys = Vec::with_capacity(capacity);
+                let mut objects = Vec::with_capacity(capacity);
+                while let Some((key, object)) =
+                    map.next_entry::<Retained<KeyType>, Retained<ObjectType>>()?
+                {
+                    keys.push(key);
+                    objects.push(object);
+                }
+                let keys: Vec<&KeyType> = keys.iter().map(|key| &**key).collect();
+                Ok(crate::NSDictionary::from_retained_objects(&keys, &objects))
+            }
+        }
+
+        deserializer.deserialize_map(DictionaryVisitor(PhantomData))
+    }
+}
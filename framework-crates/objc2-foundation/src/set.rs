@@ -2,6 +2,10 @@
 use alloc::vec::Vec;
 #[cfg(feature = "NSEnumerator")]
 use core::fmt;
+#[cfg(all(feature = "NSEnumerator", feature = "std"))]
+use core::hash::Hash;
+#[cfg(all(feature = "NSEnumerator", feature = "std"))]
+use std::collections::HashSet;
 
 use objc2::rc::{Retained, RetainedFromIterator};
 use objc2::{msg_send, AllocAnyThread, Message};
@@ -193,6 +197,40 @@ impl<ObjectType: Message> NSSet<ObjectType> {
     pub fn to_vec(&self) -> Vec<Retained<ObjectType>> {
         self.iter().collect()
     }
+
+    /// Call the closure with each element in the set, letting Foundation
+    /// spread the calls across several threads.
+    ///
+    /// This returns once the closure has been called for every element.
+    ///
+    /// Since the elements are accessed from other threads, they must be
+    /// [`Sync`]. If the closure panics, the process is aborted.
+    #[cfg(feature = "block2")]
+    #[cfg(feature = "NSObjCRuntime")]
+    #[doc(alias = "enumerateObjectsWithOptions:usingBlock:")]
+    pub fn for_each_concurrent(&self, f: impl Fn(&ObjectType) + Send + Sync)
+    where
+        ObjectType: Sync,
+    {
+        use block2::StackBlock;
+        use core::ptr::NonNull;
+        use objc2::runtime::Bool;
+
+        let block = StackBlock::new(|obj: NonNull<ObjectType>, _stop: NonNull<Bool>| {
+            // SAFETY: The object is valid for the duration of the call.
+            let obj = unsafe { obj.as_ref() };
+            util::AbortOnUnwind::call(|| f(obj));
+        });
+        // SAFETY: The block only accesses the closure and the elements, which
+        // are both `Sync`, and the enumeration completes before the block is
+        // dropped.
+        unsafe {
+            self.enumerateObjectsWithOptions_usingBlock(
+                crate::NSEnumerationOptions::Concurrent,
+                &block,
+            )
+        };
+    }
 }
 
 #[cfg(feature = "NSEnumerator")]
@@ -310,6 +348,32 @@ impl<'a, ObjectType: Message> Extend<&'a ObjectType> for &NSMutableSet<ObjectTyp
     }
 }
 
+#[cfg(feature = "NSEnumerator")]
+#[cfg(feature = "std")]
+impl<ObjectType: Message + Eq + Hash> From<&NSSet<ObjectType>> for HashSet<Retained<ObjectType>> {
+    /// Convert the set to a `HashSet`.
+    ///
+    /// Note that the hashing and equality of the Rust set is determined by
+    /// `ObjectType`'s `Hash` and `Eq` implementations, which may differ from
+    /// the semantics of `NSSet` if these are not implemented in terms of
+    /// `hash` and `isEqual:`.
+    #[inline]
+    fn from(set: &NSSet<ObjectType>) -> Self {
+        set.iter().collect()
+    }
+}
+
+#[cfg(feature = "NSEnumerator")]
+#[cfg(feature = "std")]
+impl<ObjectType: Message + Eq + Hash> From<&NSMutableSet<ObjectType>>
+    for HashSet<Retained<ObjectType>>
+{
+    #[inline]
+    fn from(set: &NSMutableSet<ObjectType>) -> Self {
+        set.iter().collect()
+    }
+}
+
 impl<'a, ObjectType: Message + 'a> RetainedFromIterator<&'a ObjectType> for NSSet<ObjectType> {
     fn retained_from_iter<I: IntoIterator<Item = &'a ObjectType>>(iter: I) -> Retained<Self> {
         let vec = Vec::from_iter(iter);
@@ -341,3 +405,17 @@ impl<ObjectType: Message> RetainedFromIterator<Retained<ObjectType>> for NSMutab
         Self::from_retained_slice(&vec)
     }
 }
+
+impl<ObjectType: Message> PartialEq<NSSet<ObjectType>> for NSMutableSet<ObjectType> {
+    #[inline]
+    fn eq(&self, other: &NSSet<ObjectType>) -> bool {
+        PartialEq::eq(&**self, other)
+    }
+}
+
+impl<ObjectType: Message> PartialEq<NSMutableSet<ObjectType>> for NSSet<ObjectType> {
+    #[inline]
+    fn eq(&self, other: &NSMutableSet<ObjectType>) -> bool {
+        PartialEq::eq(self, &**other)
+    }
+}
@@ -0,0 +1,169 @@
+//! Utilities for the `NSSortDescriptor` class.
+use objc2::rc::Retained;
+use objc2::{sel, Message};
+
+use crate::{NSArray, NSMutableArray, NSSortDescriptor, NSString};
+
+/// Creation methods.
+impl NSSortDescriptor {
+    /// Create a sort descriptor that orders objects by the value at the
+    /// given key path, using `compare:`.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSArray, NSSortDescriptor};
+    ///
+    /// let descriptor = NSSortDescriptor::new(ns_string!("length"), false);
+    /// let array = NSArray::from_slice(&[ns_string!("a"), ns_string!("abc"), ns_string!("ab")]);
+    /// // SAFETY: `length` is safe to call on strings.
+    /// let sorted = unsafe { array.sorted_by_descriptors(&[&descriptor]) };
+    /// assert_eq!(sorted.objectAtIndex(0).to_string(), "abc");
+    /// ```
+    #[doc(alias = "sortDescriptorWithKey:ascending:")]
+    pub fn new(key: &NSString, ascending: bool) -> Retained<Self> {
+        Self::sortDescriptorWithKey_ascending(Some(key), ascending)
+    }
+
+    /// Create a sort descriptor that orders strings at the given key path
+    /// without regard to case, using `caseInsensitiveCompare:`.
+    ///
+    /// Use `"self"` as the key to sort the strings themselves.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Throws an exception when sorting if the values at the key path are
+    /// not strings.
+    #[doc(alias = "sortDescriptorWithKey:ascending:selector:")]
+    pub fn case_insensitive(key: &NSString, ascending: bool) -> Retained<Self> {
+        // SAFETY: `caseInsensitiveCompare:` takes a single string, and
+        // returns `NSComparisonResult`. Values that are not strings will
+        // throw an exception.
+        unsafe {
+            Self::sortDescriptorWithKey_ascending_selector(
+                Some(key),
+                ascending,
+                Some(sel!(caseInsensitiveCompare:)),
+            )
+        }
+    }
+
+    /// Create a sort descriptor that orders strings at the given key path
+    /// like the Finder does, using `localizedStandardCompare:`.
+    ///
+    /// This is usually what you want when sorting strings that are shown to
+    /// the user.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Throws an exception when sorting if the values at the key path are
+    /// not strings.
+    #[doc(alias = "sortDescriptorWithKey:ascending:selector:")]
+    pub fn localized_standard(key: &NSString, ascending: bool) -> Retained<Self> {
+        // SAFETY: Same as in `case_insensitive`.
+        unsafe {
+            Self::sortDescriptorWithKey_ascending_selector(
+                Some(key),
+                ascending,
+                Some(sel!(localizedStandardCompare:)),
+            )
+        }
+    }
+
+    /// Create a sort descriptor that orders the values at the given key path
+    /// using a closure.
+    ///
+    /// Note that sort descriptors with a comparator cannot be archived, and
+    /// cannot be used with Core Data fetch requests.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSArray, NSSortDescriptor, NSString};
+    ///
+    /// // Sort by the last character.
+    /// let descriptor = NSSortDescriptor::with_comparator(ns_string!("self"), true, |a, b| {
+    ///     let a = a.downcast_ref::<NSString>().unwrap().to_string();
+    ///     let b = b.downcast_ref::<NSString>().unwrap().to_string();
+    ///     a.chars().last().cmp(&b.chars().last())
+    /// });
+    /// let array = NSArray::from_slice(&[ns_string!("ab"), ns_string!("ba")]);
+    /// // SAFETY: The `self` key returns the strings themselves.
+    /// let sorted = unsafe { array.sorted_by_descriptors(&[&descriptor]) };
+    /// assert_eq!(sorted.objectAtIndex(0).to_string(), "ba");
+    /// ```
+    #[cfg(feature = "block2")]
+    #[cfg(feature = "NSObjCRuntime")]
+    #[doc(alias = "sortDescriptorWithKey:ascending:comparator:")]
+    pub fn with_comparator<F>(key: &NSString, ascending: bool, compare: F) -> Retained<Self>
+    where
+        F: Fn(&objc2::runtime::AnyObject, &objc2::runtime::AnyObject) -> core::cmp::Ordering
+            + Send
+            + Sync
+            + 'static,
+    {
+        use core::ptr::NonNull;
+        use objc2::runtime::AnyObject;
+
+        let block = block2::RcBlock::new(
+            move |a: NonNull<AnyObject>, b: NonNull<AnyObject>| -> crate::NSComparisonResult {
+                // SAFETY: The objects are valid for the duration of the
+                // comparison.
+                compare(unsafe { a.as_ref() }, unsafe { b.as_ref() }).into()
+            },
+        );
+        // SAFETY: The block is `Send + Sync` and `'static`, since the sort
+        // descriptor may be used to sort on any thread. The method copies
+        // the block, so it is fine to drop our reference afterwards.
+        unsafe {
+            Self::sortDescriptorWithKey_ascending_comparator(
+                Some(key),
+                ascending,
+                block2::RcBlock::as_ptr(&block),
+            )
+        }
+    }
+}
+
+impl<ObjectType: Message> NSArray<ObjectType> {
+    /// Create a new array sorted by the given sort descriptors.
+    ///
+    /// The first descriptor is the primary sort key, and later descriptors
+    /// are used to break ties.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// The descriptors' keys are looked up on each object with
+    /// `valueForKey:`, which may call any method. The keys must refer to
+    /// properties that are safe to access on the objects in the array.
+    #[doc(alias = "sortedArrayUsingDescriptors:")]
+    pub unsafe fn sorted_by_descriptors(
+        &self,
+        descriptors: &[&NSSortDescriptor],
+    ) -> Retained<NSArray<ObjectType>> {
+        // SAFETY: Upheld by caller.
+        unsafe { self.sortedArrayUsingDescriptors(&NSArray::from_slice(descriptors)) }
+    }
+}
+
+impl<ObjectType: Message> NSMutableArray<ObjectType> {
+    /// Sort the array in place by the given sort descriptors.
+    ///
+    /// The first descriptor is the primary sort key, and later descriptors
+    /// are used to break ties.
+    ///
+    ///
+    /// # Safety
+    ///
+    /// See [`NSArray::sorted_by_descriptors`].
+    #[doc(alias = "sortUsingDescriptors:")]
+    pub unsafe fn sort_by_descriptors(&self, descriptors: &[&NSSortDescriptor]) {
+        // SAFETY: Upheld by caller.
+        unsafe { self.sortUsingDescriptors(&NSArray::from_slice(descriptors)) }
+    }
+}
@@ -0,0 +1,98 @@
+//! Utilities for the `NSStream` classes.
+use core::ptr::NonNull;
+use std::io;
+
+use objc2::rc::Retained;
+
+use crate::{NSInputStream, NSOutputStream, NSStream, NSStreamStatus, NSString};
+
+impl NSStream {
+    /// Create a pair of connected socket streams to the given host and port.
+    ///
+    /// The streams must be opened before use.
+    ///
+    /// Returns `None` if the streams could not be created.
+    #[doc(alias = "getStreamsToHostWithName:port:inputStream:outputStream:")]
+    pub fn streams_to_host(
+        hostname: &NSString,
+        port: isize,
+    ) -> Option<(Retained<NSInputStream>, Retained<NSOutputStream>)> {
+        let mut input = None;
+        let mut output = None;
+        // SAFETY: The out-pointers are valid, and are initialized to `None`.
+        unsafe {
+            Self::getStreamsToHostWithName_port_inputStream_outputStream(
+                hostname,
+                port,
+                Some(&mut input),
+                Some(&mut output),
+            )
+        };
+        Some((input?, output?))
+    }
+
+    /// Convert the stream's error into an [`io::Error`].
+    fn last_io_error(&self) -> io::Error {
+        match self.streamError() {
+            Some(error) => error.to_io_error(),
+            None => io::Error::new(io::ErrorKind::Other, "unknown stream error"),
+        }
+    }
+
+    fn check_open(&self) -> io::Result<()> {
+        match self.streamStatus() {
+            NSStreamStatus::NotOpen | NSStreamStatus::Closed => Err(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "stream is not open",
+            )),
+            NSStreamStatus::Error => Err(self.last_io_error()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Reads from the input stream using `read:maxLength:`.
+///
+/// The stream must have been opened with `open` beforehand. Reading blocks
+/// until at least one byte is available, unless the stream has been
+/// scheduled on a run loop.
+impl io::Read for &NSInputStream {
+    #[doc(alias = "read:maxLength:")]
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.check_open()?;
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let ptr = NonNull::new(buf.as_mut_ptr()).unwrap();
+        // SAFETY: The buffer is valid for writes of `buf.len()` bytes.
+        let read = unsafe { self.read_maxLength(ptr, buf.len()) };
+        // A negative value indicates an error, zero indicates end of stream.
+        usize::try_from(read).map_err(|_| self.last_io_error())
+    }
+}
+
+/// Writes to the output stream using `write:maxLength:`.
+///
+/// The stream must have been opened with `open` beforehand. Writing blocks
+/// until at least one byte can be written, unless the stream has been
+/// scheduled on a run loop.
+impl io::Write for &NSOutputStream {
+    #[doc(alias = "write:maxLength:")]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.check_open()?;
+        if buf.is_empty() {
+            return Ok(0);
+        }
+        let ptr = NonNull::new(buf.as_ptr() as *mut u8).unwrap();
+        // SAFETY: The buffer is valid for reads of `buf.len()` bytes.
+        let written = unsafe { self.write_maxLength(ptr, buf.len()) };
+        // A negative value indicates an error, zero that the stream is full.
+        usize::try_from(written).map_err(|_| self.last_io_error())
+    }
+
+    /// Streams are unbuffered, so this does nothing.
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
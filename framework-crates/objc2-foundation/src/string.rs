@@ -7,7 +7,6 @@ use core::panic::RefUnwindSafe;
 use core::panic::UnwindSafe;
 #[cfg(target_vendor = "apple")]
 use core::slice;
-use core::str;
 
 use objc2::msg_send_id;
 use objc2::rc::{autoreleasepool_leaking, Allocated, AutoreleasePool, Retained};
@@ -45,12 +44,14 @@ impl NSString {
         self.len() == 0
     }
 
-    /// Get the [`str`](`prim@str`) representation of this string if it can be
-    /// done efficiently.
+    /// Get the [`str`](`prim@str`) representation of this string without
+    /// copying it, if it can be done efficiently.
     ///
-    /// Returns [`None`] if the internal storage does not allow this to be
-    /// done efficiently. Use `NSString::to_string` if performance is not an
-    /// issue.
+    /// This returns a pointer directly into the string's internal storage,
+    /// which is usually possible for ASCII strings. Returns [`None`] if the
+    /// internal storage does not allow this (e.g. because the string is
+    /// stored as UTF-16), in which case you can fall back to
+    /// [`to_str`](Self::to_str) or `NSString::to_string`.
     ///
     ///
     /// # Safety
@@ -59,30 +60,25 @@ impl NSString {
     /// string.
     ///
     /// Warning: This is very difficult to ensure in generic contexts.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSString;
+    ///
+    /// let string = NSString::from_str("foo");
+    /// // SAFETY: The string is immutable.
+    /// if let Some(s) = unsafe { string.to_str_no_copy() } {
+    ///     assert_eq!(s, "foo");
+    /// }
+    /// ```
     #[doc(alias = "CFStringGetCStringPtr")]
-    #[allow(unused)]
     #[cfg(target_vendor = "apple")]
-    // TODO: Finish this
-    // TODO: Can this be used on NSStrings that are not internally CFString?
-    // (i.e. custom subclasses of NSString)?
-    unsafe fn as_str_wip(&self) -> Option<&str> {
-        use core::ffi::c_char;
-        use core::ptr::NonNull;
-
-        type CFStringEncoding = u32;
-        #[allow(non_upper_case_globals)]
-        // https://developer.apple.com/documentation/corefoundation/cfstringbuiltinencodings/kcfstringencodingutf8?language=objc
-        const kCFStringEncodingUTF8: CFStringEncoding = 0x08000100;
-        extern "C" {
-            // https://developer.apple.com/documentation/corefoundation/1542133-cfstringgetcstringptr?language=objc
-            fn CFStringGetCStringPtr(s: &NSString, encoding: CFStringEncoding) -> *const c_char;
-        }
-        let bytes = unsafe { CFStringGetCStringPtr(self, kCFStringEncodingUTF8) };
-        NonNull::new(bytes as *mut u8).map(|bytes| {
-            let len = self.len();
-            let bytes: &[u8] = unsafe { slice::from_raw_parts(bytes.as_ptr(), len) };
-            str::from_utf8(bytes).unwrap()
-        })
+    pub unsafe fn to_str_no_copy(&self) -> Option<&str> {
+        // SAFETY: This is an instance of `NSString`, and the caller upholds
+        // that it is not mutated while the returned string is alive.
+        unsafe { util::string_as_str_no_copy(self) }
     }
 
     /// Get an [UTF-16] string slice if it can be done efficiently.
@@ -171,6 +167,10 @@ impl NSString {
     ///
     /// Prefer using the [`ns_string!`] macro when possible.
     ///
+    /// This uses `initWithBytes:length:encoding:`, which on Apple platforms
+    /// returns a tagged pointer instance for short ASCII strings, so that
+    /// creating these does not allocate.
+    ///
     /// [`ns_string!`]: crate::ns_string
     #[doc(alias = "initWithBytes")]
     #[doc(alias = "initWithBytes:length:encoding:")]
@@ -0,0 +1,68 @@
+//! Utilities for the `NSTask` class.
+use objc2::rc::Retained;
+
+use crate::{NSArray, NSError, NSString, NSTask, NSURL};
+
+/// Creation methods.
+impl NSTask {
+    /// Create a new task that runs the executable at the given URL with the
+    /// given arguments.
+    ///
+    /// The task is not launched; configure the environment, working
+    /// directory and standard I/O as needed, and then launch it with
+    /// [`run_until_exit`] or `launchAndReturnError:`.
+    ///
+    /// [`run_until_exit`]: Self::run_until_exit
+    pub fn with_executable(executable: &NSURL, arguments: &NSArray<NSString>) -> Retained<Self> {
+        let task = Self::new();
+        task.setExecutableURL(Some(executable));
+        task.setArguments(Some(arguments));
+        task
+    }
+}
+
+/// Running.
+impl NSTask {
+    /// Launch the task, and block the current thread until it exits.
+    ///
+    /// Returns the exit status of the task, or the signal that terminated
+    /// it, see `terminationReason`.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2_foundation::{ns_string, NSArray, NSTask, NSURL};
+    ///
+    /// let task = NSTask::with_executable(
+    ///     &NSURL::from_file_path("/usr/bin/true").unwrap(),
+    ///     &NSArray::new(),
+    /// );
+    /// assert_eq!(task.run_until_exit().unwrap(), 0);
+    /// ```
+    #[doc(alias = "launchAndReturnError:")]
+    #[doc(alias = "waitUntilExit")]
+    pub fn run_until_exit(&self) -> Result<i32, Retained<NSError>> {
+        self.launchAndReturnError()?;
+        self.waitUntilExit();
+        Ok(self.terminationStatus())
+    }
+}
+
+/// Handlers.
+///
+/// The handlers may be invoked on any thread, and are therefore required to
+/// be `Send + Sync`.
+#[cfg(feature = "block2")]
+impl NSTask {
+    /// Set the closure to run when the task exits.
+    #[doc(alias = "setTerminationHandler:")]
+    pub fn set_termination_handler(&self, handler: impl Fn(&NSTask) + Send + Sync + 'static) {
+        let block = block2::RcBlock::new(move |task: core::ptr::NonNull<NSTask>| {
+            // SAFETY: The task is valid for the duration of the handler.
+            handler(unsafe { task.as_ref() })
+        });
+        // SAFETY: The block is `Send + Sync` and `'static`.
+        unsafe { self.setTerminationHandler(Some(&block)) };
+    }
+}
@@ -85,6 +85,18 @@ fn test_get() {
     assert!(empty_array.lastObject().is_none());
 }
 
+#[test]
+fn test_get_checked() {
+    let array = sample_number_array(3);
+    assert_eq!(array.get(0), Some(NSNumber::new_u8(0)));
+    assert_eq!(array.get(2), Some(NSNumber::new_u8(2)));
+    assert_eq!(array.get(3), None);
+    assert_eq!(array.get(usize::MAX), None);
+
+    let empty_array = <NSArray<NSObject>>::new();
+    assert_eq!(empty_array.get(0), None);
+}
+
 #[test]
 fn test_iter() {
     let array = sample_number_array(4);
@@ -138,6 +150,16 @@ fn test_two_iters() {
     for (_, _) in iter1.zip(iter2) {}
 }
 
+#[test]
+fn test_into_vec() {
+    let array = sample_number_array(3);
+    let vec: Vec<Retained<NSNumber>> = (&*array).into();
+    assert_eq!(vec, array.to_vec());
+
+    let array: Retained<NSArray<NSNumber>> = vec.into_iter().collect();
+    assert_eq!(array, sample_number_array(3));
+}
+
 #[test]
 fn test_objects_in_range() {
     let array = sample_array(4);
@@ -154,6 +176,28 @@ fn test_objects_in_range() {
     assert_eq!(all_objs.len(), 4);
 }
 
+#[test]
+#[should_panic = "range end index 5 out of range for array of length 4"]
+fn test_objects_in_range_out_of_bounds() {
+    let _ = sample_array(4).objects_in_range(2..5);
+}
+
+#[test]
+#[should_panic = "range start index 3 is greater than end index 2"]
+#[allow(clippy::reversed_empty_ranges)]
+fn test_objects_in_range_reversed() {
+    let _ = sample_array(4).objects_in_range(3..2);
+}
+
+#[test]
+fn test_to_vec_large() {
+    let array = sample_array(10_000);
+    let vec = array.to_vec();
+    assert_eq!(vec.len(), 10_000);
+    assert_eq!(vec[1234], array.objectAtIndex(1234));
+    assert_eq!(unsafe { array.objects_in_range_unchecked(10..12) }.len(), 2);
+}
+
 #[test]
 fn test_generic_ownership_traits() {
     fn assert_partialeq<T: PartialEq>() {}
@@ -190,3 +234,22 @@ fn test_access_anyobject() {
     for _ in unsafe { array.iter_unchecked() } {}
     for _ in array {}
 }
+
+#[test]
+#[cfg(feature = "block2")]
+#[cfg(feature = "NSObjCRuntime")]
+fn test_for_each_concurrent() {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    let array = sample_number_array(100);
+    let sum = AtomicUsize::new(0);
+    let index_sum = AtomicUsize::new(0);
+    array.for_each_concurrent(|index, number| {
+        sum.fetch_add(number.as_u8() as usize, Ordering::Relaxed);
+        index_sum.fetch_add(index, Ordering::Relaxed);
+    });
+    assert_eq!(sum.into_inner(), 4950);
+    assert_eq!(index_sum.into_inner(), 4950);
+
+    NSArray::<NSNumber>::new().for_each_concurrent(|_, _| panic!("empty array"));
+}
@@ -88,6 +88,7 @@ fn send_sync_unwindsafe() {
     assert_unwindsafe::<NSString>();
     assert_unwindsafe::<MainThreadMarker>(); // Intentional
     assert_auto_traits::<NSThread>();
+    assert_auto_traits::<NSURLCache>();
     assert_auto_traits::<NSUUID>();
     // assert_auto_traits::<NSValue>(); // Intentional
     assert_unwindsafe::<NSZone>(); // Intentional
@@ -15,3 +15,40 @@ fn try_running_functions() {
     assert_eq!(format!("{:?}", bundle.infoDictionary().unwrap()), "{}");
     assert_eq!(bundle.name(), None);
 }
+
+#[test]
+#[cfg(feature = "NSString")]
+#[cfg_attr(not(target_os = "macos"), ignore = "varies between platforms")]
+fn framework_bundle() {
+    use alloc::string::ToString;
+
+    use crate::{ns_string, NSString};
+    use objc2::runtime::NSObject;
+    use objc2::ClassType;
+
+    let bundle = NSBundle::bundleForClass(NSObject::class());
+    let identifier = bundle.bundleIdentifier().unwrap();
+    assert_eq!(identifier.to_string(), "com.apple.Foundation");
+    assert_eq!(
+        bundle
+            .info_value::<NSString>(ns_string!("CFBundleIdentifier"))
+            .unwrap()
+            .unwrap(),
+        identifier
+    );
+    assert!(bundle
+        .info_value::<NSString>(ns_string!("NotAKey"))
+        .is_none());
+
+    assert_eq!(
+        bundle
+            .localized_string(ns_string!("NotAKey"), Some(ns_string!("NotATable")))
+            .to_string(),
+        "NotAKey"
+    );
+
+    #[cfg(feature = "NSURL")]
+    assert!(bundle
+        .resource_url(ns_string!("NotAResource"), None)
+        .is_none());
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "NSCache")]
+#![cfg(feature = "NSString")]
+use alloc::string::{String, ToString};
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use std::sync::Mutex;
+
+use crate::{ns_string, NSCache, NSString};
+
+#[test]
+fn get_set_remove() {
+    let cache = NSCache::<NSString, NSString>::new();
+    assert!(cache.objectForKey(ns_string!("a")).is_none());
+
+    cache.setObject_forKey(ns_string!("1"), ns_string!("a"));
+    cache.setObject_forKey_cost(ns_string!("2"), ns_string!("b"), 10);
+    assert_eq!(
+        &*cache.objectForKey(ns_string!("a")).unwrap(),
+        ns_string!("1")
+    );
+
+    assert_eq!(&*cache.remove(ns_string!("b")).unwrap(), ns_string!("2"));
+    assert!(cache.remove(ns_string!("b")).is_none());
+
+    cache.setCountLimit(5);
+    assert_eq!(cache.countLimit(), 5);
+    cache.setTotalCostLimit(100);
+    assert_eq!(cache.totalCostLimit(), 100);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn eviction_handler() {
+    let cache = NSCache::<NSString, NSString>::new();
+    let evicted = Arc::new(Mutex::new(Vec::<String>::new()));
+    let evicted_clone = Arc::clone(&evicted);
+    cache.set_eviction_handler(move |value| {
+        evicted_clone.lock().unwrap().push(value.to_string());
+    });
+
+    cache.setObject_forKey(ns_string!("value"), ns_string!("key"));
+    cache.removeObjectForKey(ns_string!("key"));
+    assert_eq!(*evicted.lock().unwrap(), ["value"]);
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "NSCalendar")]
+#![cfg(feature = "NSDate")]
+use crate::{NSCalendar, NSCalendarOptions, NSCalendarUnit, NSDate, NSDateComponents};
+
+#[test]
+fn components() {
+    let components = NSDateComponents::new();
+    assert_eq!(components.get(NSCalendarUnit::Day), None);
+    components.set(NSCalendarUnit::Day, Some(3));
+    assert_eq!(components.get(NSCalendarUnit::Day), Some(3));
+    assert_eq!(components.day(), 3);
+    components.set(NSCalendarUnit::Day, None);
+    assert_eq!(components.get(NSCalendarUnit::Day), None);
+}
+
+#[test]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "NSTimeZone")]
+fn date_roundtrip() {
+    let calendar = NSCalendar::gregorian();
+    calendar.setTimeZone(&crate::NSTimeZone::timeZoneForSecondsFromGMT(0));
+
+    let components = NSDateComponents::new();
+    components.setYear(2001);
+    components.setMonth(1);
+    components.setDay(2);
+    let date = calendar.dateFromComponents(&components).unwrap();
+    // The reference date is 2001-01-01 00:00:00 UTC.
+    assert_eq!(date.timeIntervalSinceReferenceDate(), 86400.0);
+
+    let components = calendar.components_fromDate(
+        NSCalendarUnit::Year | NSCalendarUnit::Month | NSCalendarUnit::Day,
+        &date,
+    );
+    assert_eq!(components.get(NSCalendarUnit::Year), Some(2001));
+    assert_eq!(components.get(NSCalendarUnit::Month), Some(1));
+    assert_eq!(components.get(NSCalendarUnit::Day), Some(2));
+    assert_eq!(components.get(NSCalendarUnit::Hour), None);
+}
+
+#[test]
+#[cfg(feature = "NSString")]
+#[cfg(feature = "NSTimeZone")]
+fn arithmetic() {
+    let calendar = NSCalendar::gregorian();
+    calendar.setTimeZone(&crate::NSTimeZone::timeZoneForSecondsFromGMT(0));
+
+    let date = NSDate::dateWithTimeIntervalSinceReferenceDate(0.0);
+    let later = calendar
+        .dateByAddingUnit_value_toDate_options(
+            NSCalendarUnit::Month,
+            1,
+            &date,
+            NSCalendarOptions::empty(),
+        )
+        .unwrap();
+    // January has 31 days.
+    assert_eq!(later.timeIntervalSinceReferenceDate(), 31.0 * 86400.0);
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "NSCoder")]
+#![cfg(feature = "NSKeyedArchiver")]
+#![cfg(feature = "FoundationErrors")]
+#![cfg(feature = "NSObject")]
+#![cfg(feature = "NSData")]
+#![cfg(feature = "NSDictionary")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "NSSet")]
+#![cfg(feature = "NSString")]
+use objc2::rc::{Allocated, Retained};
+use objc2::runtime::NSObject;
+use objc2::{define_class, msg_send_id, AllocAnyThread, DefinedClass};
+
+use crate::{
+    ns_string, NSCoder, NSCoding, NSKeyedArchiver, NSKeyedUnarchiver, NSObjectProtocol,
+    NSSecureCoding, NSString,
+};
+
+struct Ivars {
+    name: Retained<NSString>,
+    count: i64,
+    flag: bool,
+}
+
+define_class!(
+    #[unsafe(super(NSObject))]
+    #[name = "Objc2TestCodingObject"]
+    #[ivars = Ivars]
+    struct CodingObject;
+
+    unsafe impl NSObjectProtocol for CodingObject {}
+
+    unsafe impl NSCoding for CodingObject {
+        #[method(encodeWithCoder:)]
+        fn encode_with_coder(&self, coder: &NSCoder) {
+            coder.encodeObject_forKey(Some(&self.ivars().name), ns_string!("name"));
+            coder.encodeInt64_forKey(self.ivars().count, ns_string!("count"));
+            coder.encodeBool_forKey(self.ivars().flag, ns_string!("flag"));
+        }
+
+        #[method_id(initWithCoder:)]
+        fn init_with_coder(this: Allocated<Self>, coder: &NSCoder) -> Option<Retained<Self>> {
+            let name = coder.decode_object::<NSString>(ns_string!("name"))?;
+            let count = coder.decodeInt64ForKey(ns_string!("count"));
+            let flag = coder.decodeBoolForKey(ns_string!("flag"));
+            let this = this.set_ivars(Ivars { name, count, flag });
+            unsafe { msg_send_id![super(this), init] }
+        }
+    }
+
+    unsafe impl NSSecureCoding for CodingObject {
+        #[method(supportsSecureCoding)]
+        fn supports_secure_coding() -> bool {
+            true
+        }
+    }
+);
+
+impl CodingObject {
+    fn new(name: &str, count: i64, flag: bool) -> Retained<Self> {
+        let this = Self::alloc().set_ivars(Ivars {
+            name: NSString::from_str(name),
+            count,
+            flag,
+        });
+        unsafe { msg_send_id![super(this), init] }
+    }
+}
+
+#[test]
+fn archive_roundtrip() {
+    let object = CodingObject::new("foo", 42, true);
+    let data = NSKeyedArchiver::archive_secure(&object).unwrap();
+
+    let decoded = NSKeyedUnarchiver::unarchive::<CodingObject>(&data, &[]).unwrap();
+    assert_eq!(decoded.ivars().name.to_string(), "foo");
+    assert_eq!(decoded.ivars().count, 42);
+    assert!(decoded.ivars().flag);
+}
@@ -1,6 +1,8 @@
 #![cfg(feature = "NSString")]
-use objc2::{rc::Retained, runtime::ProtocolObject};
-use objc2_foundation::{NSCopying, NSMutableCopying, NSString};
+use objc2::rc::Retained;
+use objc2::runtime::ProtocolObject;
+
+use crate::{NSCopying, NSMutableCopying, NSString};
 
 #[test]
 fn copy() {
@@ -15,3 +17,45 @@ fn copy_mutable() {
     let protocol_object: &ProtocolObject<dyn NSMutableCopying> = ProtocolObject::from_ref(&*obj);
     let _: Retained<ProtocolObject<dyn NSMutableCopying>> = protocol_object.mutableCopy();
 }
+
+#[test]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSOrderedSet")]
+fn copy_counterparts() {
+    use crate::{
+        NSArray, NSDictionary, NSMutableArray, NSMutableDictionary, NSMutableOrderedSet,
+        NSMutableString, NSOrderedSet,
+    };
+
+    let string = NSString::from_str("abc");
+    let _: Retained<NSMutableString> = string.mutableCopy();
+    let _: Retained<NSString> = string.mutableCopy().copy();
+
+    let array = NSArray::from_retained_slice(&[string.clone()]);
+    let mutable: Retained<NSMutableArray<NSString>> = array.mutableCopy();
+    mutable.addObject(&string);
+    let copied: Retained<NSArray<NSString>> = mutable.copy();
+    assert_eq!(copied.count(), 2);
+    assert_eq!(array.count(), 1);
+
+    let dictionary = NSDictionary::from_retained_objects(&[&*string], &[string.clone()]);
+    let _: Retained<NSMutableDictionary<NSString, NSString>> = dictionary.mutableCopy();
+    let _: Retained<NSDictionary<NSString, NSString>> = dictionary.mutableCopy().copy();
+
+    let set = NSOrderedSet::from_retained_slice(&[string]);
+    let _: Retained<NSMutableOrderedSet<NSString>> = set.mutableCopy();
+    let _: Retained<NSOrderedSet<NSString>> = set.mutableCopy().copy();
+}
+
+#[test]
+#[cfg(feature = "NSDictionary")]
+fn to_owned_keeps_mutability() {
+    use alloc::borrow::ToOwned;
+
+    use crate::NSMutableDictionary;
+
+    let dictionary = NSMutableDictionary::<NSString, NSString>::new();
+    let owned: Retained<NSMutableDictionary<NSString, NSString>> = (*dictionary).to_owned();
+    assert_ne!(Retained::as_ptr(&owned), Retained::as_ptr(&dictionary));
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "NSDate")]
+use core::time::Duration;
+
+use crate::NSDate;
+
+#[test]
+fn arithmetic() {
+    let date = NSDate::dateWithTimeIntervalSinceReferenceDate(100.0);
+    let later = date.add_duration(Duration::from_millis(1500));
+    assert_eq!(later.timeIntervalSinceReferenceDate(), 101.5);
+    let earlier = date.sub_duration(Duration::from_secs(200));
+    assert_eq!(earlier.timeIntervalSinceReferenceDate(), -100.0);
+
+    assert_eq!(
+        later.duration_since(&earlier),
+        Some(Duration::from_millis(201_500))
+    );
+    assert_eq!(earlier.duration_since(&later), None);
+    assert_eq!(date.duration_since(&date), Some(Duration::ZERO));
+}
+
+#[test]
+#[cfg(feature = "NSObjCRuntime")]
+fn ordering() {
+    let date = NSDate::dateWithTimeIntervalSince1970(0.0);
+    let later = NSDate::dateWithTimeIntervalSince1970(1.0);
+    assert!(date < later);
+    assert!(NSDate::distantPast() < date);
+    assert!(later < NSDate::distantFuture());
+    assert_eq!(
+        date.cmp(&NSDate::dateWithTimeIntervalSince1970(0.0)),
+        core::cmp::Ordering::Equal
+    );
+}
+
+#[cfg(feature = "std")]
+mod system_time {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let date = NSDate::from_system_time(time);
+        assert_eq!(date.timeIntervalSince1970(), 1_700_000_000.0);
+        assert_eq!(date.to_system_time(), Some(time));
+    }
+
+    #[test]
+    fn before_epoch() {
+        let time = UNIX_EPOCH - Duration::from_secs(10);
+        let date = NSDate::from_system_time(time);
+        assert_eq!(date.timeIntervalSince1970(), -10.0);
+        assert_eq!(date.to_system_time(), Some(time));
+    }
+
+    #[test]
+    fn now() {
+        let before = SystemTime::now();
+        let date = NSDate::now();
+        let after = SystemTime::now();
+        let time = date.to_system_time().unwrap();
+        // Allow a little leeway for floating point imprecision.
+        assert!(before - Duration::from_millis(1) <= time);
+        assert!(time <= after + Duration::from_millis(1));
+    }
+}
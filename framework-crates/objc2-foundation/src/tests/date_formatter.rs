@@ -0,0 +1,52 @@
+#![cfg(feature = "NSDateFormatter")]
+#![cfg(feature = "NSDate")]
+#![cfg(feature = "NSString")]
+use alloc::string::ToString;
+
+use crate::{ns_string, NSDate, NSDateFormatter};
+
+#[test]
+#[cfg(feature = "NSLocale")]
+#[cfg(feature = "NSTimeZone")]
+fn fixed_format() {
+    let formatter = NSDateFormatter::with_fixed_format(ns_string!("yyyy-MM-dd'T'HH:mm:ss"));
+    formatter.setTimeZone(Some(&crate::NSTimeZone::timeZoneForSecondsFromGMT(3600)));
+
+    let date = NSDate::dateWithTimeIntervalSince1970(0.0);
+    assert_eq!(
+        formatter.stringFromDate(&date).to_string(),
+        "1970-01-01T01:00:00"
+    );
+
+    let parsed = formatter
+        .dateFromString(ns_string!("1970-01-02T01:00:00"))
+        .unwrap();
+    assert_eq!(parsed.timeIntervalSince1970(), 86400.0);
+
+    assert!(formatter.dateFromString(ns_string!("invalid")).is_none());
+}
+
+#[test]
+fn styles() {
+    let formatter = NSDateFormatter::with_styles(
+        crate::NSDateFormatterStyle::NoStyle,
+        crate::NSDateFormatterStyle::NoStyle,
+    );
+    let date = NSDate::dateWithTimeIntervalSince1970(0.0);
+    assert_eq!(formatter.stringFromDate(&date).to_string(), "");
+}
+
+#[test]
+#[cfg(feature = "NSISO8601DateFormatter")]
+fn iso8601() {
+    use crate::{NSISO8601DateFormatOptions, NSISO8601DateFormatter};
+
+    let formatter = NSISO8601DateFormatter::new();
+    let date = NSDate::dateWithTimeIntervalSince1970(86400.0);
+    let string = formatter.stringFromDate(&date);
+    assert_eq!(string.to_string(), "1970-01-02T00:00:00Z");
+    assert_eq!(formatter.dateFromString(&string).unwrap(), date);
+
+    let formatter = NSISO8601DateFormatter::with_options(NSISO8601DateFormatOptions::WithFullDate);
+    assert_eq!(formatter.stringFromDate(&date).to_string(), "1970-01-02");
+}
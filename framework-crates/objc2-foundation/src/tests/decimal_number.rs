@@ -0,0 +1,41 @@
+#![cfg(feature = "NSDecimalNumber")]
+#![cfg(feature = "NSString")]
+use crate::{ns_string, NSDecimalNumber, NSRoundingMode};
+
+fn dec(s: &str) -> objc2::rc::Retained<NSDecimalNumber> {
+    NSDecimalNumber::decimalNumberWithString(&crate::NSString::from_str(s))
+}
+
+#[test]
+fn arithmetic() {
+    // Exact, unlike with floating point.
+    assert_eq!(&*dec("0.1") + &*dec("0.2"), dec("0.3"));
+    assert_eq!(&*dec("1.5") - &*dec("2"), dec("-0.5"));
+    assert_eq!(&*dec("1.5") * &*dec("1.5"), dec("2.25"));
+    assert_eq!(&*dec("1") / &*dec("8"), dec("0.125"));
+    assert_eq!(-&*dec("3.75"), dec("-3.75"));
+}
+
+#[test]
+fn rounding() {
+    let number = dec("2.345");
+    assert_eq!(number.round(2, NSRoundingMode::Plain), dec("2.35"));
+    assert_eq!(number.round(2, NSRoundingMode::Down), dec("2.34"));
+    assert_eq!(number.round(2, NSRoundingMode::Bankers), dec("2.34"));
+    assert_eq!(number.round(0, NSRoundingMode::Up), dec("3"));
+    assert_eq!(dec("1234").round(-2, NSRoundingMode::Plain), dec("1200"));
+}
+
+#[test]
+#[cfg(feature = "NSObjCRuntime")]
+fn ordering() {
+    assert!(dec("0.1") < dec("0.2"));
+    assert!(dec("-1") < NSDecimalNumber::zero());
+    assert_eq!(dec("1.0").cmp(&dec("1")), core::cmp::Ordering::Equal);
+}
+
+#[test]
+fn not_a_number() {
+    let nan = NSDecimalNumber::decimalNumberWithString(ns_string!("abc"));
+    assert_eq!(nan, NSDecimalNumber::notANumber());
+}
@@ -3,6 +3,7 @@
 #![cfg(feature = "NSObject")]
 use alloc::format;
 use alloc::string::ToString;
+use alloc::vec::Vec;
 use core::ptr;
 use std::ffi::CString;
 
@@ -61,6 +62,64 @@ fn test_iter_objects() {
     assert_eq!(dict.objects().count(), 1);
 }
 
+#[test]
+#[cfg(feature = "NSEnumerator")]
+fn test_iter() {
+    let dict = NSDictionary::from_slices(
+        &[ns_string!("a"), ns_string!("b")],
+        &[ns_string!("a"), ns_string!("b")],
+    );
+    let mut iterations = 0;
+    for (key, obj) in dict.iter() {
+        assert_eq!(key, obj);
+        iterations += 1;
+    }
+    assert_eq!(iterations, 2);
+    assert_eq!(dict.iter().size_hint().1, Some(2));
+}
+
+#[test]
+fn test_hash_map() {
+    use std::collections::HashMap;
+
+    let dict = NSDictionary::from_slices(
+        &[ns_string!("a"), ns_string!("b")],
+        &[ns_string!("1"), ns_string!("2")],
+    );
+    let map: HashMap<Retained<NSString>, Retained<NSString>> = (&*dict).into();
+    assert_eq!(map.len(), 2);
+    assert_eq!(&*map[ns_string!("b")], ns_string!("2"));
+
+    let dict: Retained<NSDictionary<NSString, NSString>> = map.into_iter().collect();
+    assert_eq!(dict.len(), 2);
+    assert_eq!(
+        &*dict.objectForKey(ns_string!("a")).unwrap(),
+        ns_string!("1")
+    );
+}
+
+#[test]
+fn test_keys_and_objects() {
+    let keys: Vec<_> = (0..1000)
+        .map(|i| NSString::from_str(&i.to_string()))
+        .collect();
+    let objects: Vec<_> = (0..1000).map(|_| NSObject::new()).collect();
+    let dict = NSDictionary::from_retained_objects(
+        &keys.iter().map(|key| &**key).collect::<Vec<_>>(),
+        &objects,
+    );
+
+    let pairs = dict.keys_and_objects();
+    assert_eq!(pairs.len(), 1000);
+    for (key, object) in &pairs {
+        assert_eq!(dict.objectForKey(key).as_ref(), Some(object));
+    }
+
+    let map = dict.to_hashmap();
+    assert_eq!(map.len(), 1000);
+    assert_eq!(map[&*NSString::from_str("42")], objects[42]);
+}
+
 #[test]
 #[cfg(feature = "NSArray")]
 fn test_arrays() {
@@ -254,3 +313,24 @@ fn root_object() {
     let cls = builder.register();
     test_from_base_class(cls);
 }
+
+#[test]
+#[cfg(feature = "block2")]
+#[cfg(feature = "NSObjCRuntime")]
+#[cfg(feature = "NSValue")]
+fn test_for_each_concurrent() {
+    use core::sync::atomic::{AtomicI32, Ordering};
+
+    use crate::NSNumber;
+
+    let keys = [1, 2, 3].map(NSNumber::new_i32);
+    let objects = [10, 20, 30].map(NSNumber::new_i32);
+    let keys: Vec<&NSNumber> = keys.iter().map(|key| &**key).collect();
+    let dict = NSDictionary::from_retained_objects(&keys, &objects);
+    let sum = AtomicI32::new(0);
+    dict.for_each_concurrent(|key, obj| {
+        assert_eq!(key.as_i32() * 10, obj.as_i32());
+        sum.fetch_add(obj.as_i32(), Ordering::Relaxed);
+    });
+    assert_eq!(sum.into_inner(), 60);
+}
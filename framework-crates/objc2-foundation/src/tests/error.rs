@@ -27,3 +27,61 @@ fn custom_domain() {
     };
     assert_eq!(format!("{error}"), expected);
 }
+
+#[test]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSObject")]
+fn with_description() {
+    let error = NSError::with_description(7, ns_string!("MyDomain"), ns_string!("Oh no!"));
+    assert_eq!(error.code(), 7);
+    assert_eq!(&*error.domain(), ns_string!("MyDomain"));
+    assert_eq!(format!("{error}"), "Oh no!");
+    assert_eq!(error.localizedFailureReason(), None);
+
+    let user_info = error.userInfo();
+    let description = user_info
+        .objectForKey(NSError::NSLocalizedDescriptionKey())
+        .unwrap();
+    assert_eq!(
+        description.downcast_ref::<crate::NSString>().unwrap(),
+        ns_string!("Oh no!")
+    );
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn into_boxed_error() {
+    fn fails() -> Result<(), std::boxed::Box<dyn std::error::Error>> {
+        Err(NSError::new(42, ns_string!("MyDomain")))?;
+        Ok(())
+    }
+
+    let error = fails().unwrap_err();
+    let error = error
+        .downcast_ref::<objc2::rc::Retained<NSError>>()
+        .unwrap();
+    assert_eq!(error.code(), 42);
+}
+
+#[test]
+#[cfg(feature = "std")]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSObject")]
+fn rust_error_roundtrip() {
+    use alloc::string::ToString;
+
+    let parse_error = "-1".parse::<u32>().unwrap_err();
+    let error = NSError::from_rust_error(3, ns_string!("MyDomain"), parse_error.clone());
+    assert_eq!(error.code(), 3);
+    assert_eq!(format!("{error}"), parse_error.to_string());
+
+    let original = error.rust_error().unwrap();
+    assert_eq!(
+        original.downcast_ref::<core::num::ParseIntError>(),
+        Some(&parse_error)
+    );
+
+    assert!(NSError::new(3, ns_string!("MyDomain"))
+        .rust_error()
+        .is_none());
+}
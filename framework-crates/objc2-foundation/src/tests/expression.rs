@@ -0,0 +1,80 @@
+#![cfg(feature = "NSExpression")]
+#![cfg(feature = "NSArray")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSDictionary")]
+#![cfg(feature = "NSValue")]
+use alloc::string::ToString;
+
+use crate::{ns_string, NSArray, NSExpression, NSNumber};
+
+fn as_i32(value: Option<objc2::rc::Retained<objc2::runtime::AnyObject>>) -> i32 {
+    value.unwrap().downcast_ref::<NSNumber>().unwrap().as_i32()
+}
+
+#[test]
+fn constant() {
+    let number = NSNumber::new_i32(5);
+    let expression = NSExpression::expressionForConstantValue(Some(&number));
+    // SAFETY: Constant expressions don't send any messages.
+    assert_eq!(as_i32(unsafe { expression.evaluate(None) }), 5);
+    assert_eq!(as_i32(expression.constantValue()), 5);
+}
+
+#[test]
+fn key_path() {
+    // SAFETY: `length` is safe to call on strings.
+    let expression = unsafe { NSExpression::expressionForKeyPath(ns_string!("length")) };
+    assert_eq!(expression.keyPath().to_string(), "length");
+    assert_eq!(
+        as_i32(unsafe { expression.evaluate(Some(ns_string!("abc"))) }),
+        3
+    );
+}
+
+#[test]
+fn format() {
+    // SAFETY: `length` is safe to call on strings.
+    let value = unsafe {
+        let expression =
+            NSExpression::from_format(ns_string!("%@ + length"), &[&*NSNumber::new_i32(1)]);
+        expression.evaluate(Some(ns_string!("ab")))
+    };
+    assert_eq!(as_i32(value), 3);
+}
+
+#[test]
+fn function() {
+    let numbers = [
+        NSExpression::expressionForConstantValue(Some(&NSNumber::new_i32(1))),
+        NSExpression::expressionForConstantValue(Some(&NSNumber::new_i32(2))),
+        NSExpression::expressionForConstantValue(Some(&NSNumber::new_i32(6))),
+    ];
+    let aggregate = NSExpression::aggregate(&[&*numbers[0], &*numbers[1], &*numbers[2]]);
+
+    // SAFETY: The functions are predefined, and only operate on numbers.
+    unsafe {
+        let sum = NSExpression::function(ns_string!("sum:"), &[&aggregate]);
+        assert_eq!(sum.function().to_string(), "sum:");
+        assert_eq!(as_i32(sum.evaluate(None)), 9);
+
+        let max = NSExpression::function(ns_string!("max:"), &[&aggregate]);
+        assert_eq!(as_i32(max.evaluate(None)), 6);
+
+        let add = NSExpression::function(ns_string!("add:to:"), &[&numbers[0], &numbers[1]]);
+        assert_eq!(as_i32(add.evaluate(None)), 3);
+    }
+}
+
+#[test]
+fn evaluated_object() {
+    let array = NSArray::from_slice(&[&*NSNumber::new_i32(2), &*NSNumber::new_i32(4)]);
+    // SAFETY: `average:` is predefined, and the array contains numbers.
+    let value = unsafe {
+        let expression = NSExpression::function(
+            ns_string!("average:"),
+            &[&NSExpression::expressionForEvaluatedObject()],
+        );
+        expression.evaluate(Some(&array))
+    };
+    assert_eq!(as_i32(value), 3);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "NSFileHandle")]
+#![cfg(feature = "NSData")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "std")]
+#![cfg(unix)]
+use alloc::vec::Vec;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use crate::NSFileHandle;
+
+#[test]
+fn read_write_seek() {
+    let path = std::env::temp_dir().join(alloc::format!(
+        "objc2-foundation-file-handle-{}",
+        std::process::id()
+    ));
+    let file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(&path)
+        .unwrap();
+    let handle = NSFileHandle::from_owned_fd(file.into());
+
+    let mut writer = &*handle;
+    writer.write_all(b"hello world").unwrap();
+    assert_eq!(writer.stream_position().unwrap(), 11);
+
+    let mut reader = &*handle;
+    assert_eq!(reader.seek(SeekFrom::Start(6)).unwrap(), 6);
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"world");
+
+    assert_eq!(reader.seek(SeekFrom::End(-5)).unwrap(), 6);
+    assert_eq!(reader.seek(SeekFrom::Current(-6)).unwrap(), 0);
+    let mut buf = [0; 5];
+    reader.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"hello");
+
+    assert!(reader.seek(SeekFrom::Current(-10)).is_err());
+
+    drop(handle);
+    std::fs::remove_file(&path).unwrap();
+}
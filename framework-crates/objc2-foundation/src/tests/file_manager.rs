@@ -0,0 +1,68 @@
+#![cfg(feature = "NSFileManager")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSURL")]
+#![cfg(feature = "std")]
+#![cfg(unix)]
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use std::path::PathBuf;
+
+use crate::{NSFileManager, NSString, NSURL};
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(alloc::format!(
+        "objc2-foundation-{name}-{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn create_copy_move_remove() {
+    let manager = NSFileManager::defaultManager();
+    let dir = temp_dir("file-manager");
+    let dir_url = NSURL::from_directory_path(&dir).unwrap();
+
+    manager
+        .createDirectoryAtURL_withIntermediateDirectories_attributes_error(&dir_url, true, None)
+        .unwrap();
+    let dir_path = NSString::from_str(dir.to_str().unwrap());
+    assert_eq!(manager.is_directory_at_path(&dir_path), Some(true));
+
+    let file = dir.join("a.txt");
+    std::fs::write(&file, "hello").unwrap();
+    let file_url = NSURL::from_file_path(&file).unwrap();
+    let file_path = NSString::from_str(file.to_str().unwrap());
+    assert_eq!(manager.is_directory_at_path(&file_path), Some(false));
+
+    let copy_url = NSURL::from_file_path(dir.join("b.txt")).unwrap();
+    manager
+        .copyItemAtURL_toURL_error(&file_url, &copy_url)
+        .unwrap();
+    let moved_url = NSURL::from_file_path(dir.join("c.txt")).unwrap();
+    manager
+        .moveItemAtURL_toURL_error(&copy_url, &moved_url)
+        .unwrap();
+    assert_eq!(std::fs::read_to_string(dir.join("c.txt")).unwrap(), "hello");
+
+    // Copying onto an existing file fails with a Cocoa error.
+    let err = manager
+        .copyItemAtURL_toURL_error(&file_url, &moved_url)
+        .unwrap_err();
+    assert_eq!(err.domain().to_string(), "NSCocoaErrorDomain");
+
+    #[cfg(feature = "NSEnumerator")]
+    {
+        let mut names: Vec<_> = manager
+            .enumerate_directory(&dir_url, crate::NSDirectoryEnumerationOptions::empty())
+            .unwrap()
+            .map(|url| url.lastPathComponent().unwrap().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["a.txt", "c.txt"]);
+    }
+
+    manager.removeItemAtURL_error(&dir_url).unwrap();
+    assert_eq!(manager.is_directory_at_path(&dir_path), None);
+}
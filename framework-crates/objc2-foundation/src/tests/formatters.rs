@@ -0,0 +1,40 @@
+#![cfg(feature = "NSString")]
+use alloc::string::ToString;
+
+#[test]
+#[cfg(feature = "NSByteCountFormatter")]
+fn byte_count() {
+    use crate::{NSByteCountFormatter, NSByteCountFormatterCountStyle, NSByteCountFormatterUnits};
+
+    let string = NSByteCountFormatter::stringFromByteCount_countStyle(
+        1_500_000,
+        NSByteCountFormatterCountStyle::Decimal,
+    );
+    assert_eq!(string.to_string(), "1.5 MB");
+
+    let formatter = NSByteCountFormatter::new();
+    formatter.setCountStyle(NSByteCountFormatterCountStyle::Binary);
+    formatter.setAllowedUnits(NSByteCountFormatterUnits::UseKB);
+    formatter.setIncludesUnit(false);
+    assert_eq!(formatter.stringFromByteCount(2048).to_string(), "2");
+}
+
+#[test]
+#[cfg(feature = "NSDateComponentsFormatter")]
+#[cfg(feature = "NSCalendar")]
+fn date_components() {
+    use core::time::Duration;
+
+    use crate::{NSCalendarUnit, NSDateComponentsFormatter, NSDateComponentsFormatterUnitsStyle};
+
+    let formatter = NSDateComponentsFormatter::new();
+    formatter.setUnitsStyle(NSDateComponentsFormatterUnitsStyle::Positional);
+    formatter.setAllowedUnits(NSCalendarUnit::Minute | NSCalendarUnit::Second);
+    assert_eq!(
+        formatter
+            .format_duration(Duration::from_secs(125))
+            .unwrap()
+            .to_string(),
+        "2:05"
+    );
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "NSHashTable")]
+#![cfg(feature = "NSArray")]
+use objc2::rc::autoreleasepool;
+
+use crate::{NSHashTable, NSObject};
+
+#[test]
+fn weak_objects() {
+    let table = NSHashTable::<NSObject>::weakObjectsHashTable();
+    let a = NSObject::new();
+    let b = NSObject::new();
+    table.addObject(Some(&a));
+    table.addObject(Some(&b));
+    assert!(table.containsObject(Some(&a)));
+    assert_eq!(table.live_count(), 2);
+
+    autoreleasepool(|_| drop(b));
+    assert_eq!(table.live_count(), 1);
+    let snapshot = table.snapshot();
+    assert_eq!(snapshot.len(), 1);
+    assert_eq!(snapshot[0], a);
+
+    table.removeObject(Some(&a));
+    assert_eq!(table.live_count(), 0);
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "NSHost")]
+#![cfg(feature = "NSArray")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "std")]
+#![cfg(target_os = "macos")]
+use std::net::{IpAddr, Ipv4Addr};
+
+use crate::{ns_string, NSHost};
+
+#[test]
+fn current_host() {
+    let host = NSHost::currentHost();
+    assert!(!host.names().is_empty());
+    assert_eq!(host.ip_addrs().len(), host.addresses().len());
+    assert!(host.isEqualToHost(&NSHost::currentHost()));
+}
+
+#[test]
+fn with_address() {
+    let host = NSHost::hostWithAddress(ns_string!("127.0.0.1"));
+    assert_eq!(host.address().unwrap().to_string(), "127.0.0.1");
+    assert_eq!(host.ip_addrs(), [IpAddr::V4(Ipv4Addr::LOCALHOST)]);
+}
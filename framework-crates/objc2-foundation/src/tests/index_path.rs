@@ -0,0 +1,26 @@
+#![cfg(feature = "NSIndexPath")]
+#![cfg(feature = "NSObjCRuntime")]
+use objc2::rc::Retained;
+
+use crate::NSIndexPath;
+
+fn index_path(indexes: &[usize]) -> Retained<NSIndexPath> {
+    // SAFETY: The pointer and length are valid.
+    unsafe { NSIndexPath::indexPathWithIndexes_length(indexes.as_ptr(), indexes.len()) }
+}
+
+#[test]
+fn ordering() {
+    let short = index_path(&[1]);
+    let long = index_path(&[1, 2]);
+    let other = index_path(&[2]);
+
+    assert!(short < long);
+    assert!(long < other);
+    assert_eq!(long, index_path(&[1, 2]));
+    assert_eq!(long.cmp(&index_path(&[1, 2])), core::cmp::Ordering::Equal);
+
+    let mut paths = [other.clone(), long.clone(), short.clone()];
+    paths.sort();
+    assert_eq!(paths, [short, long, other]);
+}
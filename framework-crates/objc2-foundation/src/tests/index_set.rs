@@ -0,0 +1,58 @@
+#![cfg(feature = "NSIndexSet")]
+#![cfg(feature = "NSRange")]
+use alloc::format;
+use alloc::vec::Vec;
+
+use objc2::rc::Retained;
+
+use crate::{NSIndexSet, NSMutableIndexSet};
+
+#[test]
+fn test_from_range() {
+    let set = NSIndexSet::from_range(3..6);
+    assert_eq!(set.len(), 3);
+    assert!(!set.containsIndex(2));
+    assert!(set.containsIndex(3));
+    assert!(set.containsIndex(5));
+    assert!(!set.containsIndex(6));
+
+    let empty = NSIndexSet::from_range(3..3);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_iter() {
+    let set = NSMutableIndexSet::from_range(0..2);
+    set.addIndex(10);
+    // The largest allowed index is `NSNotFound - 1`.
+    let max = isize::MAX as usize - 1;
+    set.addIndex(max);
+    let indexes: Vec<_> = set.iter().collect();
+    assert_eq!(indexes, [0, 1, 10, max]);
+
+    let mut iter = NSIndexSet::new().iter();
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_debug() {
+    let set = NSIndexSet::from_range(1..4);
+    assert_eq!(format!("{set:?}"), "{1, 2, 3}");
+}
+
+#[test]
+fn test_from_iter() {
+    let set: Retained<NSIndexSet> = [5, 1, 3, 1].into_iter().collect();
+    assert_eq!(set.iter().collect::<Vec<_>>(), [1, 3, 5]);
+}
+
+#[test]
+#[cfg(feature = "block2")]
+fn test_ranges() {
+    let set: Retained<NSMutableIndexSet> = [1, 2, 3, 7, 9, 10].into_iter().collect();
+    assert_eq!(set.ranges(), [1..4, 7..8, 9..11]);
+
+    set.removeAllIndexes();
+    assert!(set.ranges().is_empty());
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "NSJSONSerialization")]
+#![cfg(feature = "NSArray")]
+#![cfg(feature = "NSData")]
+#![cfg(feature = "NSDictionary")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "NSString")]
+use objc2::runtime::AnyObject;
+
+use crate::{
+    ns_string, NSArray, NSDictionary, NSJSONReadingOptions, NSJSONSerialization,
+    NSJSONWritingOptions, NSObject, NSString,
+};
+
+#[test]
+fn parse() {
+    let object =
+        NSJSONSerialization::from_slice(br#"{"a": [1, "b"]}"#, NSJSONReadingOptions::empty())
+            .unwrap();
+    let dict = object.downcast_ref::<NSDictionary>().unwrap();
+    let a = dict.objectForKey(ns_string!("a")).unwrap();
+    let a = a.downcast_ref::<NSArray>().unwrap();
+    assert_eq!(a.len(), 2);
+
+    assert!(NSJSONSerialization::from_slice(b"{", NSJSONReadingOptions::empty()).is_err());
+}
+
+#[test]
+#[cfg(feature = "FoundationErrors")]
+fn fragments() {
+    assert!(NSJSONSerialization::from_slice(b"\"a\"", NSJSONReadingOptions::empty()).is_err());
+    let object =
+        NSJSONSerialization::from_slice(b"\"a\"", NSJSONReadingOptions::FragmentsAllowed).unwrap();
+    assert_eq!(object.downcast_ref::<NSString>().unwrap(), ns_string!("a"));
+
+    let string: &AnyObject = ns_string!("a");
+    assert!(NSJSONSerialization::to_data(string, NSJSONWritingOptions::empty()).is_err());
+    let data =
+        NSJSONSerialization::to_data(string, NSJSONWritingOptions::FragmentsAllowed).unwrap();
+    assert_eq!(data.to_vec(), b"\"a\"");
+}
+
+#[test]
+#[cfg(feature = "FoundationErrors")]
+fn invalid_object() {
+    let array = NSArray::from_retained_slice(&[NSObject::new()]);
+    let err = NSJSONSerialization::to_data(&array, NSJSONWritingOptions::empty()).unwrap_err();
+    assert_eq!(err.code(), crate::NSPropertyListWriteInvalidError);
+}
+
+#[test]
+#[cfg(feature = "serde_json")]
+#[cfg(feature = "NSNull")]
+#[cfg(feature = "NSValue")]
+fn serde_json_roundtrip() {
+    let json = br#"{"bool": true, "int": -3, "big": 18446744073709551615, "float": 1.5, "null": null, "list": ["x", false]}"#;
+    let object = NSJSONSerialization::from_slice(json, NSJSONReadingOptions::empty()).unwrap();
+    let value = NSJSONSerialization::to_value(&object).unwrap();
+    assert_eq!(
+        value,
+        serde_json::from_slice::<serde_json::Value>(json).unwrap()
+    );
+
+    let object = NSJSONSerialization::from_value(&value);
+    assert_eq!(NSJSONSerialization::to_value(&object).unwrap(), value);
+
+    let array = NSArray::from_retained_slice(&[NSObject::new()]);
+    assert_eq!(NSJSONSerialization::to_value(&array), None);
+}
@@ -0,0 +1,74 @@
+#![cfg(feature = "NSKeyedArchiver")]
+#![cfg(feature = "FoundationErrors")]
+#![cfg(feature = "NSData")]
+#![cfg(feature = "NSDictionary")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "NSSet")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSArray")]
+use objc2::rc::Retained;
+use objc2::ClassType;
+
+use crate::{ns_string, NSArray, NSKeyedArchiver, NSKeyedUnarchiver, NSObject, NSString};
+
+#[test]
+fn roundtrip() {
+    let string = NSString::from_str("hello");
+    let data = NSKeyedArchiver::archive_secure(&string).unwrap();
+    let decoded = NSKeyedUnarchiver::unarchive::<NSString>(&data, &[]).unwrap();
+    assert_eq!(decoded, string);
+}
+
+#[test]
+fn nested_classes_must_be_allowed() {
+    let array = NSArray::from_slice(&[ns_string!("a")]);
+    let data = NSKeyedArchiver::archive_secure(&array).unwrap();
+
+    assert!(NSKeyedUnarchiver::unarchive::<NSArray>(&data, &[]).is_err());
+    let decoded = NSKeyedUnarchiver::unarchive::<NSArray>(&data, &[NSString::class()]).unwrap();
+    assert_eq!(decoded.len(), 1);
+}
+
+#[test]
+#[cfg(feature = "NSValue")]
+fn wrong_root_class() {
+    use crate::NSNumber;
+
+    let data = NSKeyedArchiver::archive_secure(&NSNumber::new_i32(1)).unwrap();
+    let err = NSKeyedUnarchiver::unarchive::<NSString>(&data, &[NSNumber::class()]).unwrap_err();
+    assert_eq!(err.code(), crate::NSCoderReadCorruptError);
+}
+
+#[test]
+fn not_secure_coding() {
+    assert!(NSKeyedArchiver::archive_secure(&NSObject::new()).is_err());
+}
+
+#[test]
+fn deep_copy() {
+    use objc2::runtime::AnyObject;
+
+    use crate::NSMutableString;
+
+    let string = NSMutableString::from_str("a");
+    let obj: &AnyObject = &string;
+    let array: Retained<NSArray> = NSArray::from_slice(&[obj]);
+    let copy = NSKeyedUnarchiver::deep_copy(&*array, &[NSMutableString::class()]).unwrap();
+    assert_eq!(copy.len(), 1);
+    let copied = copy.objectAtIndex(0);
+    assert_ne!(Retained::as_ptr(&copied), obj as *const AnyObject);
+
+    string.appendString(ns_string!("b"));
+    let copied = copied.downcast::<NSMutableString>().unwrap();
+    assert_eq!(copied.to_string(), "a");
+}
+
+#[test]
+fn archives_equal() {
+    let a = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    let b = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    let c = NSArray::from_slice(&[ns_string!("b"), ns_string!("a")]);
+    assert!(NSKeyedArchiver::archives_equal(&a, &b).unwrap());
+    assert!(!NSKeyedArchiver::archives_equal(&a, &c).unwrap());
+    assert!(NSKeyedArchiver::archives_equal(&a, &NSObject::new()).is_err());
+}
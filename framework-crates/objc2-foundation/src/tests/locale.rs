@@ -0,0 +1,44 @@
+#![cfg(feature = "NSLocale")]
+#![cfg(feature = "NSString")]
+use alloc::string::ToString;
+
+use crate::{ns_string, NSLocale};
+
+#[test]
+fn posix() {
+    let locale = NSLocale::posix();
+    assert_eq!(locale.localeIdentifier().to_string(), "en_US_POSIX");
+    assert_eq!(locale.languageCode().to_string(), "en");
+    assert_eq!(locale.countryCode().unwrap().to_string(), "US");
+    assert_eq!(locale.decimalSeparator().to_string(), ".");
+    assert_eq!(locale.groupingSeparator().to_string(), ",");
+}
+
+#[test]
+fn identifier() {
+    let locale = NSLocale::localeWithLocaleIdentifier(ns_string!("da_DK"));
+    assert_eq!(locale.localeIdentifier().to_string(), "da_DK");
+    assert_eq!(locale.decimalSeparator().to_string(), ",");
+    assert_eq!(locale.currencyCode().unwrap().to_string(), "DKK");
+    assert!(locale.usesMetricSystem());
+
+    let english = NSLocale::posix();
+    assert_eq!(
+        english
+            .localizedStringForLanguageCode(ns_string!("da"))
+            .unwrap()
+            .to_string(),
+        "Danish"
+    );
+}
+
+#[test]
+fn current() {
+    // The current locale depends on the environment, just check that these
+    // don't crash.
+    let locale = NSLocale::currentLocale();
+    let _ = locale.localeIdentifier();
+    let _ = NSLocale::autoupdatingCurrentLocale();
+    #[cfg(feature = "NSArray")]
+    let _ = NSLocale::preferredLanguages();
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "NSMapTable")]
+#![cfg(feature = "NSEnumerator")]
+#![cfg(feature = "NSString")]
+use objc2::rc::autoreleasepool;
+
+use crate::{ns_string, NSMapTable, NSObject, NSString};
+
+#[test]
+fn strong_to_weak() {
+    let table = NSMapTable::<NSString, NSObject>::strongToWeakObjectsMapTable();
+    let a = NSObject::new();
+    let b = NSObject::new();
+    table.setObject_forKey(Some(&a), Some(ns_string!("a")));
+    table.setObject_forKey(Some(&b), Some(ns_string!("b")));
+    assert_eq!(table.live_count(), 2);
+
+    autoreleasepool(|_| drop(b));
+    assert!(table.objectForKey(Some(ns_string!("b"))).is_none());
+    let snapshot = table.snapshot();
+    assert_eq!(snapshot.len(), 1);
+    assert_eq!(&*snapshot[0].0, ns_string!("a"));
+    assert_eq!(snapshot[0].1, a);
+
+    table.removeObjectForKey(Some(ns_string!("a")));
+    assert_eq!(table.live_count(), 0);
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "NSMeasurement")]
+#![cfg(feature = "NSUnit")]
+use crate::{NSMeasurement, NSUnitLength, NSUnitMass, NSUnitTemperature};
+
+#[test]
+fn convert() {
+    let length = NSMeasurement::new(1.0, &NSUnitLength::miles());
+    let meters = length.convert_to(&NSUnitLength::meters());
+    assert!((meters.doubleValue() - 1609.344).abs() < 1e-9);
+
+    let mass = NSMeasurement::new(2.0, &NSUnitMass::kilograms());
+    assert_eq!(mass.convert_to(&NSUnitMass::grams()).doubleValue(), 2000.0);
+
+    let temperature = NSMeasurement::new(100.0, &NSUnitTemperature::celsius());
+    let fahrenheit = temperature.convert_to(&NSUnitTemperature::fahrenheit());
+    assert!((fahrenheit.doubleValue() - 212.0).abs() < 1e-9);
+}
+
+#[test]
+fn can_convert() {
+    let length = NSMeasurement::new(1.0, &NSUnitLength::meters());
+    assert!(length.canBeConvertedToUnit(&NSUnitLength::feet()));
+    assert!(!length.canBeConvertedToUnit(&NSUnitMass::grams()));
+}
+
+#[test]
+#[cfg(feature = "NSMeasurementFormatter")]
+#[cfg(feature = "NSLocale")]
+#[cfg(feature = "NSString")]
+fn format() {
+    use alloc::string::ToString;
+
+    use crate::{ns_string, NSLocale, NSMeasurementFormatter, NSMeasurementFormatterUnitOptions};
+
+    let formatter = NSMeasurementFormatter::new();
+    formatter.setLocale(Some(&NSLocale::localeWithLocaleIdentifier(ns_string!(
+        "en_US"
+    ))));
+    formatter.setUnitOptions(NSMeasurementFormatterUnitOptions::ProvidedUnit);
+    let length = NSMeasurement::new(3.0, &NSUnitLength::kilometers());
+    assert_eq!(formatter.stringFromMeasurement(&length).to_string(), "3 km");
+}
@@ -2,21 +2,79 @@ mod array;
 mod attributed_string;
 mod auto_traits;
 mod bundle;
+mod cache;
+mod calendar;
+mod coder;
+mod copying;
 mod data;
+mod date;
+mod date_formatter;
+mod decimal_number;
 mod dictionary;
 mod error;
 mod exception;
+mod expression;
+mod file_handle;
+mod file_manager;
+mod formatters;
+mod hash_table;
+mod host;
+mod index_path;
+mod index_set;
+mod json_serialization;
+mod keyed_archiver;
+mod locale;
 mod lock;
+mod map_table;
+mod measurement;
 mod mutable_array;
 mod mutable_data;
 mod mutable_dictionary;
 mod mutable_set;
 mod mutable_string;
+mod notification;
+mod null;
 mod number;
+mod number_formatter;
+mod options;
+mod ordered_set;
+mod plist;
+mod pointer_array;
+mod predicate;
 mod process_info;
+mod progress;
+mod property_list_serialization;
 mod proxy;
+mod regular_expression;
+mod run_loop;
+mod serde_impls;
 mod set;
+mod sort_descriptor;
+mod stream;
 mod string;
+mod task;
 mod thread;
+mod time_zone;
+mod url;
+mod url_cache;
+mod url_credential;
+mod url_session;
+mod user_defaults;
 mod uuid;
 mod value;
+mod xml_parser;
+mod xpc_connection;
+
+/// Whether the object is a tagged pointer, i.e. stored inline in the pointer
+/// itself instead of being allocated.
+#[cfg(target_vendor = "apple")]
+#[cfg(target_pointer_width = "64")]
+#[allow(dead_code)]
+fn is_tagged_pointer<T: ?Sized>(obj: &T) -> bool {
+    extern "C" {
+        static objc_debug_taggedpointer_mask: usize;
+    }
+    let ptr: *const T = obj;
+    // SAFETY: The static is initialized by the runtime, and never modified.
+    ptr.cast::<u8>() as usize & unsafe { objc_debug_taggedpointer_mask } != 0
+}
@@ -128,3 +128,176 @@ fn null_itemsptr() {
     array.removeAllObjects();
     let _ = iter.next();
 }
+
+#[test]
+#[cfg(feature = "NSValue")]
+fn test_remove_replace_pop() {
+    use crate::NSNumber;
+
+    let array = NSMutableArray::from_retained_slice(&[
+        NSNumber::new_u8(0),
+        NSNumber::new_u8(1),
+        NSNumber::new_u8(2),
+    ]);
+
+    assert_eq!(array.remove(1), NSNumber::new_u8(1));
+    assert_eq!(array.len(), 2);
+
+    assert_eq!(array.replace(0, &NSNumber::new_u8(5)), NSNumber::new_u8(0));
+    assert_eq!(array.objectAtIndex(0), NSNumber::new_u8(5));
+
+    assert_eq!(array.pop(), Some(NSNumber::new_u8(2)));
+    assert_eq!(array.pop(), Some(NSNumber::new_u8(5)));
+    assert_eq!(array.pop(), None);
+}
+
+#[test]
+fn test_insert_at_end() {
+    let obj = NSObject::new();
+    let array = NSMutableArray::from_retained_slice(&[NSObject::new()]);
+    array.insert(1, &obj);
+    assert_eq!(array.len(), 2);
+    assert_eq!(array.lastObject().unwrap(), obj);
+
+    let array = <NSMutableArray<NSObject>>::new();
+    array.insert(0, &obj);
+    assert_eq!(array.len(), 1);
+}
+
+#[test]
+#[should_panic = "insertion index (is 2) should be <= len (is 1)"]
+fn test_insert_out_of_bounds() {
+    let array = NSMutableArray::from_retained_slice(&[NSObject::new()]);
+    array.insert(2, &NSObject::new());
+}
+
+#[test]
+#[should_panic = "removal index (is 1) should be < len (is 1)"]
+fn test_remove_out_of_bounds() {
+    let array = NSMutableArray::from_retained_slice(&[NSObject::new()]);
+    let _ = array.remove(1);
+}
+
+#[test]
+#[should_panic = "replacement index (is 0) should be < len (is 0)"]
+fn test_replace_out_of_bounds() {
+    let array = <NSMutableArray<NSObject>>::new();
+    let _ = array.replace(0, &NSObject::new());
+}
+
+#[test]
+#[cfg(feature = "NSValue")]
+fn test_add_objects_from_array() {
+    use crate::{NSArray, NSNumber};
+
+    let array = NSMutableArray::from_retained_slice(&[NSNumber::new_u8(0)]);
+    let other = NSArray::from_retained_slice(&[NSNumber::new_u8(1), NSNumber::new_u8(2)]);
+    array.addObjectsFromArray(&other);
+    assert_eq!(array.len(), 3);
+    assert_eq!(array.lastObject(), Some(NSNumber::new_u8(2)));
+}
+
+#[test]
+#[cfg(feature = "NSValue")]
+#[cfg(feature = "std")]
+fn test_equality_with_immutable() {
+    use std::collections::HashSet;
+
+    use crate::{NSArray, NSNumber};
+
+    let array = NSArray::from_retained_slice(&[NSNumber::new_u8(1)]);
+    let mutable = NSMutableArray::from_retained_slice(&[NSNumber::new_u8(1)]);
+    assert_eq!(*array, *mutable);
+    assert_eq!(*mutable, *array);
+
+    // Hashing is consistent with `isEqual:`.
+    let mut set = HashSet::new();
+    set.insert(array.clone());
+    assert!(set.contains(&*mutable.copy()));
+
+    mutable.addObject(&NSNumber::new_u8(2));
+    assert_ne!(*array, *mutable);
+}
+
+#[test]
+#[cfg(feature = "NSValue")]
+fn test_extend() {
+    use crate::NSNumber;
+
+    // Lengths around the internal batch size.
+    for len in [0, 1, 2, 63, 64, 65, 128, 200] {
+        let array = NSMutableArray::from_retained_slice(&[NSNumber::new_u32(u32::MAX)]);
+        let mut array = &*array;
+        array.extend((0..len).map(NSNumber::new_u32));
+        assert_eq!(array.len(), len as usize + 1);
+        for i in 0..len {
+            assert_eq!(array.objectAtIndex(i as usize + 1).as_u32(), i);
+        }
+
+        let numbers: alloc::vec::Vec<_> = (0..len).map(NSNumber::new_u32).collect();
+        array.extend(numbers.iter().map(|number| &**number));
+        assert_eq!(array.len(), 2 * len as usize + 1);
+        assert_eq!(
+            array.lastObject().map(|number| number.as_u32()),
+            len.checked_sub(1).or(Some(u32::MAX))
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "NSValue")]
+fn test_checked_access() {
+    use crate::NSNumber;
+
+    let array = NSMutableArray::from_retained_slice(&[NSNumber::new_u8(0)]);
+    assert_eq!(array.try_object_at(0).unwrap().as_u8(), 0);
+    let err = array.try_object_at(1).unwrap_err();
+    assert_eq!(err.range(), 1..2);
+    assert_eq!(err.array_len(), 1);
+
+    // Inserting at the end is allowed.
+    array.try_insert(1, &NSNumber::new_u8(1)).unwrap();
+    array.insert(2, &NSNumber::new_u8(2));
+    assert!(array.try_insert(4, &NSNumber::new_u8(4)).is_err());
+    assert_eq!(array.len(), 3);
+
+    assert_eq!(array.try_remove(2).unwrap().as_u8(), 2);
+    assert!(array.try_remove(2).is_err());
+    assert_eq!(array.len(), 2);
+}
+
+#[test]
+#[cfg(feature = "NSValue")]
+#[cfg(feature = "NSRange")]
+fn test_try_replace_range() {
+    use alloc::string::ToString;
+
+    use crate::NSNumber;
+
+    let array = NSMutableArray::from_retained_slice(&[NSNumber::new_u8(0), NSNumber::new_u8(1)]);
+    let new = [
+        NSNumber::new_u8(2),
+        NSNumber::new_u8(3),
+        NSNumber::new_u8(4),
+    ];
+    array
+        .try_replace_range(1..2, &[&*new[0], &*new[1], &*new[2]])
+        .unwrap();
+    let values: alloc::vec::Vec<_> = array.to_vec().iter().map(|n| n.as_u8()).collect();
+    assert_eq!(values, [0, 2, 3, 4]);
+
+    array.try_replace_range(0..4, &[]).unwrap();
+    assert!(array.is_empty());
+
+    let err = array.try_replace_range(0..1, &[&*new[0]]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "index 0 out of bounds for array of length 0"
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let err = array.try_replace_range(1..0, &[]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "range start index 1 is greater than end index 0"
+    );
+}
@@ -86,6 +86,34 @@ fn test_remove() {
     assert_eq!(dict.len(), 1);
 }
 
+#[test]
+fn test_remove_returning() {
+    let dict = sample_dict();
+    assert!(dict.remove(&NSNumber::new_i32(1)).is_some());
+    assert!(dict.remove(&NSNumber::new_i32(1)).is_none());
+    assert!(dict.remove(&NSNumber::new_i32(4)).is_none());
+    assert_eq!(dict.len(), 2);
+}
+
+#[test]
+fn test_get_or_insert_with() {
+    let dict = sample_dict();
+    let existing = dict.objectForKey(&NSNumber::new_i32(1)).unwrap();
+    let obj = dict.get_or_insert_with(&*NSNumber::new_i32(1), || unreachable!());
+    assert_eq!(obj, existing);
+
+    let obj = dict.get_or_insert_with(&*NSNumber::new_i32(4), NSObject::new);
+    assert_eq!(dict.objectForKey(&NSNumber::new_i32(4)).unwrap(), obj);
+    assert_eq!(dict.len(), 4);
+}
+
+#[test]
+fn test_add_entries() {
+    let dict = <NSMutableDictionary<NSNumber, NSObject>>::new();
+    dict.addEntriesFromDictionary(&sample_dict());
+    assert_eq!(dict.len(), 3);
+}
+
 #[test]
 fn test_clear() {
     let dict = sample_dict();
@@ -38,6 +38,24 @@ fn test_remove() {
     assert_eq!(set.count(), 2);
 }
 
+#[test]
+fn test_set_operations() {
+    use crate::NSSet;
+
+    let set = NSMutableSet::from_slice(&[ns_string!("one"), ns_string!("two")]);
+
+    set.unionSet(&NSSet::from_slice(&[ns_string!("three")]));
+    assert_eq!(set.len(), 3);
+
+    set.minusSet(&NSSet::from_slice(&[ns_string!("one"), ns_string!("four")]));
+    assert_eq!(set.len(), 2);
+    assert!(!set.containsObject(ns_string!("one")));
+
+    set.intersectSet(&NSSet::from_slice(&[ns_string!("two")]));
+    assert_eq!(set.len(), 1);
+    assert!(set.containsObject(ns_string!("two")));
+}
+
 #[test]
 fn test_clear() {
     let strs = [ns_string!("one"), ns_string!("two"), ns_string!("three")];
@@ -0,0 +1,171 @@
+#![cfg(feature = "NSNotification")]
+#![cfg(feature = "NSOperation")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "block2")]
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{ns_string, NSNotificationCenter};
+
+fn counter(center: &NSNotificationCenter) -> (Arc<AtomicUsize>, crate::notification::Observer) {
+    let count = Arc::new(AtomicUsize::new(0));
+    let observer_count = Arc::clone(&count);
+    let observer = center.observe(Some(ns_string!("Changed")), None, move |notification| {
+        assert_eq!(&*notification.name(), ns_string!("Changed"));
+        observer_count.fetch_add(1, Ordering::Relaxed);
+    });
+    (count, observer)
+}
+
+#[test]
+fn observe() {
+    let center = NSNotificationCenter::new();
+    let (count, observer) = counter(&center);
+
+    center.postNotificationName_object(ns_string!("Changed"), None);
+    center.postNotificationName_object(ns_string!("Other"), None);
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+
+    drop(observer);
+    center.postNotificationName_object(ns_string!("Changed"), None);
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+#[cfg(feature = "NSNotificationQueue")]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSObjCRuntime")]
+fn queue_coalescing() {
+    use crate::{NSNotification, NSNotificationCoalescing, NSNotificationQueue, NSPostingStyle};
+    use objc2::AllocAnyThread;
+
+    let center = NSNotificationCenter::new();
+    let queue =
+        NSNotificationQueue::initWithNotificationCenter(NSNotificationQueue::alloc(), &center);
+    let (count, _observer) = counter(&center);
+    let notification = NSNotification::notificationWithName_object(ns_string!("Changed"), None);
+
+    for _ in 0..3 {
+        queue.enqueueNotification_postingStyle_coalesceMask_forModes(
+            &notification,
+            NSPostingStyle::WhenIdle,
+            NSNotificationCoalescing::CoalescingOnName,
+            None,
+        );
+    }
+    assert_eq!(count.load(Ordering::Relaxed), 0);
+
+    // Posting now coalesces with the pending notifications.
+    queue.enqueueNotification_postingStyle_coalesceMask_forModes(
+        &notification,
+        NSPostingStyle::Now,
+        NSNotificationCoalescing::CoalescingOnName,
+        None,
+    );
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+
+    queue.enqueueNotification_postingStyle(&notification, NSPostingStyle::WhenIdle);
+    queue.dequeueNotificationsMatching_coalesceMask(
+        &notification,
+        NSNotificationCoalescing::CoalescingOnName.bits(),
+    );
+    queue.enqueueNotification_postingStyle_coalesceMask_forModes(
+        &notification,
+        NSPostingStyle::Now,
+        NSNotificationCoalescing::CoalescingOnName,
+        None,
+    );
+    assert_eq!(count.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+#[cfg(feature = "NSDistributedNotificationCenter")]
+#[cfg(feature = "NSDate")]
+#[cfg(feature = "NSRunLoop")]
+#[cfg_attr(
+    not(target_os = "macos"),
+    ignore = "distributed notifications are only available on macOS"
+)]
+fn distributed() {
+    use crate::{
+        NSDate, NSDistributedNotificationCenter, NSNotificationSuspensionBehavior, NSRunLoop,
+    };
+
+    let center = NSDistributedNotificationCenter::defaultCenter();
+    let count = Arc::new(AtomicUsize::new(0));
+    let observer_count = Arc::clone(&count);
+    let _observer = center.observe_with_suspension_behavior(
+        Some(ns_string!("org.objc2.tests.Distributed")),
+        None,
+        NSNotificationSuspensionBehavior::DeliverImmediately,
+        move |_| {
+            observer_count.fetch_add(1, Ordering::Relaxed);
+        },
+    );
+
+    center.setSuspended(true);
+    assert!(center.suspended());
+    center.setSuspended(false);
+
+    center.postNotificationName_object_userInfo_deliverImmediately(
+        ns_string!("org.objc2.tests.Distributed"),
+        None,
+        None,
+        true,
+    );
+
+    // Distributed notifications are delivered through the run loop.
+    let run_loop = NSRunLoop::currentRunLoop();
+    for _ in 0..50 {
+        if count.load(Ordering::Relaxed) != 0 {
+            break;
+        }
+        run_loop.runUntilDate(&NSDate::dateWithTimeIntervalSinceNow(0.1));
+    }
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn notifications_stream() {
+    use core::task::{Context, Poll};
+    use std::task::Wake;
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    let center = NSNotificationCenter::new();
+    let mut notifications = center.notifications(Some(ns_string!("Changed")), None);
+
+    let wakes = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Arc::clone(&wakes).into();
+    let mut cx = Context::from_waker(&waker);
+    assert!(notifications.poll_notification(&mut cx).is_pending());
+
+    center.postNotificationName_object(ns_string!("Changed"), None);
+    center.postNotificationName_object(ns_string!("Other"), None);
+    center.postNotificationName_object(ns_string!("Changed"), None);
+    assert_eq!(wakes.0.load(Ordering::Relaxed), 1);
+
+    for _ in 0..2 {
+        match notifications.poll_notification(&mut cx) {
+            Poll::Ready(Some(notification)) => {
+                assert_eq!(&*notification.name(), ns_string!("Changed"));
+            }
+            _ => panic!("expected a notification"),
+        }
+    }
+    assert!(notifications.poll_notification(&mut cx).is_pending());
+
+    // Queued notifications are released along with the stream.
+    center.postNotificationName_object(ns_string!("Changed"), None);
+    assert_eq!(wakes.0.load(Ordering::Relaxed), 2);
+    drop(notifications);
+    center.postNotificationName_object(ns_string!("Changed"), None);
+    assert_eq!(wakes.0.load(Ordering::Relaxed), 2);
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "NSNull")]
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, NSObject};
+
+use crate::NSNull;
+
+#[test]
+fn singleton() {
+    assert_eq!(NSNull::null(), NSNull::null());
+    assert_eq!(
+        Retained::as_ptr(&NSNull::null()),
+        Retained::as_ptr(&NSNull::null())
+    );
+}
+
+#[test]
+fn is_null() {
+    assert!(NSNull::is_null(&NSNull::null()));
+    assert!(!NSNull::is_null(&NSObject::new()));
+}
+
+#[test]
+fn downcast_nullable() {
+    let null: Retained<AnyObject> = Retained::into_super(Retained::into_super(NSNull::null()));
+    assert!(NSNull::downcast_nullable::<NSObject>(null)
+        .unwrap()
+        .is_none());
+
+    let obj: Retained<AnyObject> = Retained::into_super(NSObject::new());
+    assert!(NSNull::downcast_nullable::<NSObject>(obj)
+        .unwrap()
+        .is_some());
+
+    let obj: Retained<AnyObject> = Retained::into_super(NSObject::new());
+    assert!(NSNull::downcast_nullable::<NSNull>(obj).is_err());
+}
+
+#[test]
+fn downcast_nullable_ref() {
+    let null = NSNull::null();
+    assert!(NSNull::downcast_nullable_ref::<NSObject>(&null)
+        .unwrap()
+        .is_none());
+
+    let obj = NSObject::new();
+    assert!(NSNull::downcast_nullable_ref::<NSObject>(&obj)
+        .unwrap()
+        .is_some());
+    assert!(NSNull::downcast_nullable_ref::<NSNull>(&obj).is_err());
+}
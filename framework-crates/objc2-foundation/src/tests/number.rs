@@ -112,6 +112,39 @@ fn float_int_equality() {
     assert_ne!(val1, val3);
 }
 
+#[test]
+fn hash_consistent_with_equality() {
+    use core::hash::{BuildHasher, Hash, Hasher};
+    use std::collections::hash_map::RandomState;
+
+    let state = RandomState::new();
+    let hash = |val: &NSNumber| {
+        let mut hasher = state.build_hasher();
+        val.hash(&mut hasher);
+        hasher.finish()
+    };
+
+    let val1 = NSNumber::new_u8(42);
+    let val2 = NSNumber::new_i64(42);
+    let val3 = NSNumber::new_f64(42.0);
+    assert_eq!(val1, val2);
+    assert_eq!(val1, val3);
+    assert_eq!(hash(&val1), hash(&val2));
+    assert_eq!(hash(&val1), hash(&val3));
+}
+
+#[test]
+#[cfg(feature = "NSObjCRuntime")]
+fn ordering() {
+    assert!(NSNumber::new_i8(-1) < NSNumber::new_u64(1));
+    assert!(NSNumber::new_f32(1.5) > NSNumber::new_i32(1));
+    assert!(NSNumber::new_u64(u64::MAX) > NSNumber::new_i64(i64::MAX));
+    assert_eq!(
+        NSNumber::new_u16(7).cmp(&NSNumber::new_f64(7.0)),
+        core::cmp::Ordering::Equal
+    );
+}
+
 #[test]
 #[cfg(feature = "NSString")]
 fn display_debug() {
@@ -130,3 +163,16 @@ fn display_debug() {
     assert_display_debug(NSNumber::new_bool(true), "1");
     assert_display_debug(NSNumber::new_bool(false), "0");
 }
+
+#[test]
+#[cfg(target_vendor = "apple")]
+#[cfg(target_pointer_width = "64")]
+fn test_small_numbers_are_tagged() {
+    use super::is_tagged_pointer;
+
+    assert!(is_tagged_pointer(&*NSNumber::new_i32(42)));
+    assert!(is_tagged_pointer(&*NSNumber::new_i64(-1_000_000)));
+    assert!(is_tagged_pointer(&*NSNumber::new_usize(7)));
+    // Too large to fit in the pointer.
+    assert!(!is_tagged_pointer(&*NSNumber::new_u64(u64::MAX)));
+}
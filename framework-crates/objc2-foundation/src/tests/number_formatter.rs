@@ -0,0 +1,55 @@
+#![cfg(feature = "NSNumberFormatter")]
+#![cfg(feature = "NSLocale")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSValue")]
+use alloc::string::ToString;
+
+use crate::{ns_string, NSLocale, NSNumber, NSNumberFormatter, NSNumberFormatterStyle};
+
+fn format(formatter: &NSNumberFormatter, number: f64) -> alloc::string::String {
+    formatter
+        .stringFromNumber(&NSNumber::new_f64(number))
+        .unwrap()
+        .to_string()
+}
+
+#[test]
+fn decimal() {
+    let formatter = NSNumberFormatter::with_style(NSNumberFormatterStyle::DecimalStyle);
+    formatter.setLocale(Some(&NSLocale::localeWithLocaleIdentifier(ns_string!(
+        "en_US"
+    ))));
+    assert_eq!(format(&formatter, 1234567.891), "1,234,567.891");
+
+    formatter.setMaximumFractionDigits(1);
+    assert_eq!(format(&formatter, 1234567.891), "1,234,567.9");
+
+    formatter.setMinimumFractionDigits(3);
+    formatter.setMaximumFractionDigits(3);
+    assert_eq!(format(&formatter, 1.5), "1.500");
+
+    formatter.setLocale(Some(&NSLocale::localeWithLocaleIdentifier(ns_string!(
+        "de_DE"
+    ))));
+    assert_eq!(format(&formatter, 1234.5), "1.234,500");
+}
+
+#[test]
+fn percent() {
+    let formatter = NSNumberFormatter::with_style(NSNumberFormatterStyle::PercentStyle);
+    formatter.setLocale(Some(&NSLocale::localeWithLocaleIdentifier(ns_string!(
+        "en_US"
+    ))));
+    assert_eq!(format(&formatter, 0.25), "25%");
+}
+
+#[test]
+fn parse() {
+    let formatter = NSNumberFormatter::with_style(NSNumberFormatterStyle::DecimalStyle);
+    formatter.setLocale(Some(&NSLocale::localeWithLocaleIdentifier(ns_string!(
+        "en_US"
+    ))));
+    let number = formatter.numberFromString(ns_string!("1,000.5")).unwrap();
+    assert_eq!(number.as_f64(), 1000.5);
+    assert!(formatter.numberFromString(ns_string!("abc")).is_none());
+}
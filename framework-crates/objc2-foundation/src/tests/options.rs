@@ -0,0 +1,38 @@
+//! Option sets (`NS_OPTIONS`) are generated as `bitflags` newtypes over
+//! `NSUInteger`, so that they can be passed directly to methods.
+#![cfg(feature = "NSObjCRuntime")]
+use objc2::encode::{Encode, RefEncode};
+
+use crate::{NSEnumerationOptions, NSUInteger};
+
+fn assert_options<T: Encode + RefEncode + bitflags::Flags<Bits = NSUInteger>>() {
+    assert_eq!(T::ENCODING, NSUInteger::ENCODING);
+    assert_eq!(T::ENCODING_REF, NSUInteger::ENCODING_REF);
+    assert_eq!(
+        core::mem::size_of::<T>(),
+        core::mem::size_of::<NSUInteger>()
+    );
+}
+
+#[test]
+fn test_encoding() {
+    assert_options::<NSEnumerationOptions>();
+    #[cfg(feature = "NSString")]
+    assert_options::<crate::NSStringCompareOptions>();
+    #[cfg(feature = "NSData")]
+    assert_options::<crate::NSDataReadingOptions>();
+    #[cfg(feature = "NSJSONSerialization")]
+    assert_options::<crate::NSJSONWritingOptions>();
+}
+
+#[test]
+fn test_operations() {
+    let options = NSEnumerationOptions::Concurrent | NSEnumerationOptions::Reverse;
+    assert!(options.contains(NSEnumerationOptions::Concurrent));
+    assert_eq!(
+        options - NSEnumerationOptions::Reverse,
+        NSEnumerationOptions::Concurrent
+    );
+    assert_eq!(NSEnumerationOptions::empty().bits(), 0);
+    assert_eq!(NSEnumerationOptions::from_bits_retain(options.0), options);
+}
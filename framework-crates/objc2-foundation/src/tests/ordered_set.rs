@@ -0,0 +1,80 @@
+#![cfg(feature = "NSOrderedSet")]
+#![cfg(feature = "NSString")]
+use alloc::format;
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::{ns_string, NSMutableOrderedSet, NSOrderedSet, NSString};
+
+#[test]
+fn test_creation() {
+    let set = NSOrderedSet::<NSString>::new();
+    assert!(set.is_empty());
+
+    let set = NSOrderedSet::from_slice(&[ns_string!("one"), ns_string!("two"), ns_string!("one")]);
+    assert_eq!(set.len(), 2);
+
+    let strs = ["one", "two", "three"].map(NSString::from_str);
+    let set = NSOrderedSet::from_retained_slice(&strs);
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn test_access() {
+    let set = NSOrderedSet::from_slice(&[ns_string!("one"), ns_string!("two")]);
+    assert_eq!(set.get(0).as_deref(), Some(ns_string!("one")));
+    assert_eq!(set.get(1).as_deref(), Some(ns_string!("two")));
+    assert_eq!(set.get(2), None);
+
+    assert_eq!(set.position(ns_string!("two")), Some(1));
+    assert_eq!(set.position(ns_string!("three")), None);
+    assert!(set.containsObject(ns_string!("one")));
+}
+
+#[test]
+#[cfg(feature = "NSEnumerator")]
+fn test_iter() {
+    let set = NSOrderedSet::from_slice(&[ns_string!("b"), ns_string!("a"), ns_string!("c")]);
+    let strings: Vec<_> = set.iter().map(|s| s.to_string()).collect();
+    assert_eq!(strings, ["b", "a", "c"]);
+
+    assert_eq!(set.to_vec().len(), 3);
+    assert_eq!(format!("{set:?}"), r#"{"b", "a", "c"}"#);
+}
+
+#[test]
+#[cfg(feature = "NSArray")]
+fn test_array_view() {
+    let set = NSOrderedSet::from_slice(&[ns_string!("one"), ns_string!("two")]);
+    let array = set.array();
+    assert_eq!(array.len(), 2);
+    assert_eq!(&*array.objectAtIndex(1), ns_string!("two"));
+}
+
+#[test]
+fn test_mutation() {
+    let set = NSMutableOrderedSet::from_slice(&[ns_string!("a"), ns_string!("b")]);
+    set.insert(0, ns_string!("c"));
+    set.insert(3, ns_string!("d"));
+    // Already present, ignored.
+    set.insert(0, ns_string!("a"));
+    assert_eq!(set.len(), 4);
+    assert_eq!(set.position(ns_string!("c")), Some(0));
+
+    set.move_index(0, 3);
+    assert_eq!(set.position(ns_string!("c")), Some(3));
+    assert_eq!(set.position(ns_string!("a")), Some(0));
+
+    set.exchangeObjectAtIndex_withObjectAtIndex(0, 1);
+    assert_eq!(set.position(ns_string!("a")), Some(1));
+
+    set.removeObject(ns_string!("a"));
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+#[should_panic = "insertion index (is 2) should be <= len (is 1)"]
+fn test_insert_out_of_bounds() {
+    let set = NSMutableOrderedSet::from_slice(&[ns_string!("a")]);
+    set.insert(2, ns_string!("b"));
+}
@@ -0,0 +1,180 @@
+#![cfg(feature = "serde")]
+#![cfg(feature = "NSPropertyList")]
+#![cfg(feature = "NSArray")]
+#![cfg(feature = "NSData")]
+#![cfg(feature = "NSDate")]
+#![cfg(feature = "NSDictionary")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "NSObject")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSValue")]
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{ns_string, plist, NSData, NSDictionary, NSNumber, NSPropertyListFormat, NSString};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    Point(i32, i32),
+    Rect { width: u32, height: u32 },
+}
+
+/// Bytes that are serialized as `<data>` instead of as an array.
+#[derive(Debug, PartialEq)]
+struct Bytes(Vec<u8>);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct BytesVisitor;
+
+        impl serde::de::Visitor<'_> for BytesVisitor {
+            type Value = Bytes;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("bytes")
+            }
+
+            fn visit_byte_buf<E>(self, bytes: Vec<u8>) -> Result<Bytes, E> {
+                Ok(Bytes(bytes))
+            }
+        }
+
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Document {
+    title: String,
+    version: u8,
+    enabled: bool,
+    tags: Vec<String>,
+    payload: Bytes,
+    shapes: Vec<Shape>,
+    extra: BTreeMap<String, i64>,
+    missing: Option<String>,
+}
+
+fn document() -> Document {
+    Document {
+        title: String::from("Hello"),
+        version: 3,
+        enabled: true,
+        tags: vec![String::from("a"), String::from("b")],
+        payload: Bytes(vec![0, 1, 255]),
+        shapes: vec![
+            Shape::Empty,
+            Shape::Circle(1.5),
+            Shape::Point(-1, 2),
+            Shape::Rect {
+                width: 3,
+                height: 4,
+            },
+        ],
+        extra: BTreeMap::from([(String::from("x"), -7)]),
+        missing: None,
+    }
+}
+
+#[test]
+fn roundtrip() {
+    for format in [
+        NSPropertyListFormat::XMLFormat_v1_0,
+        NSPropertyListFormat::BinaryFormat_v1_0,
+    ] {
+        let bytes = plist::to_vec(&document(), format).unwrap();
+        assert_eq!(plist::from_slice::<Document>(&bytes).unwrap(), document());
+    }
+}
+
+#[test]
+fn object_representation() {
+    let object = plist::to_object(&document()).unwrap();
+    let dict = object.downcast_ref::<NSDictionary>().unwrap();
+
+    // `None` is skipped.
+    assert_eq!(dict.len(), 7);
+    assert!(dict.objectForKey(ns_string!("missing")).is_none());
+
+    let title = dict.objectForKey(ns_string!("title")).unwrap();
+    assert_eq!(
+        title.downcast_ref::<NSString>().unwrap(),
+        ns_string!("Hello")
+    );
+    let version = dict.objectForKey(ns_string!("version")).unwrap();
+    assert_eq!(version.downcast_ref::<NSNumber>().unwrap().as_u8(), 3);
+    let payload = dict.objectForKey(ns_string!("payload")).unwrap();
+    assert_eq!(
+        payload.downcast_ref::<NSData>().unwrap().to_vec(),
+        [0, 1, 255]
+    );
+}
+
+#[test]
+fn parse_system_plist() {
+    let xml = br#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>CFBundleName</key>
+    <string>Example</string>
+    <key>LSRequiresNativeExecution</key>
+    <true/>
+    <key>Created</key>
+    <date>1970-01-01T00:01:00Z</date>
+</dict>
+</plist>"#;
+
+    #[derive(Debug, Deserialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct InfoPlist {
+        #[serde(rename = "CFBundleName")]
+        bundle_name: String,
+        #[serde(rename = "LSRequiresNativeExecution")]
+        requires_native_execution: bool,
+        created: f64,
+        #[serde(rename = "CFBundleVersion")]
+        bundle_version: Option<String>,
+    }
+
+    let info: InfoPlist = plist::from_slice(xml).unwrap();
+    assert_eq!(info.bundle_name, "Example");
+    assert!(info.requires_native_execution);
+    assert_eq!(info.created, 60.0);
+    assert_eq!(info.bundle_version, None);
+}
+
+#[test]
+fn errors() {
+    // Top-level values must be present.
+    assert!(plist::to_object(&None::<i32>).is_err());
+    // Arrays cannot contain missing values.
+    assert!(plist::to_object(&[Some(1), None]).is_err());
+    // Keys must be strings.
+    assert!(plist::to_object(&BTreeMap::from([(1, 2)])).is_err());
+    // Invalid data.
+    assert!(matches!(
+        plist::from_slice::<String>(b"not a plist"),
+        Err(plist::Error::Foundation(_))
+    ));
+    // Type mismatch.
+    assert!(matches!(
+        plist::from_slice::<i32>(
+            &plist::to_vec("a", NSPropertyListFormat::BinaryFormat_v1_0).unwrap()
+        ),
+        Err(plist::Error::Message(_))
+    ));
+}
@@ -0,0 +1,64 @@
+#![cfg(feature = "NSPointerArray")]
+#![cfg(feature = "NSPointerFunctions")]
+use objc2::rc::autoreleasepool;
+
+use crate::{NSObject, NSPointerArray, NSPointerFunctions, NSPointerFunctionsOptions};
+
+#[test]
+fn strong_objects() {
+    let array = NSPointerArray::strongObjectsPointerArray();
+    let obj = NSObject::new();
+    unsafe { array.push_object(Some(&*obj)) };
+    unsafe { array.push_object::<NSObject>(None) };
+    assert_eq!(array.count(), 2);
+    assert_eq!(unsafe { array.object_at::<NSObject>(0) }.unwrap(), obj);
+    assert!(unsafe { array.object_at::<NSObject>(1) }.is_none());
+
+    // Growing the array fills it with NULL holes.
+    array.setCount(4);
+    assert!(unsafe { array.object_at::<NSObject>(3) }.is_none());
+    unsafe { array.replace_object(3, Some(&*obj)) };
+
+    array.compact();
+    assert_eq!(array.count(), 2);
+    assert_eq!(unsafe { array.object_at::<NSObject>(1) }.unwrap(), obj);
+}
+
+#[test]
+fn weak_objects() {
+    let array = NSPointerArray::weakObjectsPointerArray();
+    let a = NSObject::new();
+    let b = NSObject::new();
+    unsafe { array.push_object(Some(&*a)) };
+    unsafe { array.push_object(Some(&*b)) };
+
+    autoreleasepool(|_| drop(b));
+    assert!(unsafe { array.object_at::<NSObject>(1) }.is_none());
+
+    // Deallocated entries are removed when compacting (after first adding
+    // a NULL entry, see the NSPointerArray documentation).
+    unsafe { array.push_object::<NSObject>(None) };
+    array.compact();
+    assert_eq!(array.count(), 1);
+    assert_eq!(unsafe { array.object_at::<NSObject>(0) }.unwrap(), a);
+}
+
+#[test]
+fn with_pointer_functions() {
+    let functions = NSPointerFunctions::pointerFunctionsWithOptions(
+        NSPointerFunctionsOptions::StrongMemory | NSPointerFunctionsOptions::ObjectPersonality,
+    );
+    let array = NSPointerArray::pointerArrayWithPointerFunctions(&functions);
+    let obj = NSObject::new();
+    unsafe { array.push_object(Some(&*obj)) };
+    assert_eq!(array.count(), 1);
+    array.removePointerAtIndex(0);
+    assert_eq!(array.count(), 0);
+}
+
+#[test]
+#[should_panic = "index out of bounds"]
+fn out_of_bounds() {
+    let array = NSPointerArray::strongObjectsPointerArray();
+    let _ = unsafe { array.object_at::<NSObject>(0) };
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "NSPredicate")]
+#![cfg(feature = "NSArray")]
+#![cfg(feature = "NSString")]
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::{ns_string, NSArray, NSMutableArray, NSPredicate, NSString};
+
+fn strings(array: &NSArray<NSString>) -> Vec<alloc::string::String> {
+    array.to_vec().iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn format() {
+    // SAFETY: The format only calls string methods.
+    let predicate = unsafe {
+        NSPredicate::from_format(
+            ns_string!("SELF BEGINSWITH[c] %@ AND length > 2"),
+            &[ns_string!("a")],
+        )
+    };
+    assert_eq!(
+        predicate.predicateFormat().to_string(),
+        "SELF BEGINSWITH[c] \"a\" AND length > 2"
+    );
+
+    let array = NSArray::from_slice(&[
+        ns_string!("abc"),
+        ns_string!("Abcd"),
+        ns_string!("ab"),
+        ns_string!("bcd"),
+    ]);
+    let filtered = unsafe { array.filteredArrayUsingPredicate(&predicate) };
+    assert_eq!(strings(&filtered), ["abc", "Abcd"]);
+
+    let array = NSMutableArray::from_slice(&[ns_string!("abc"), ns_string!("bcd")]);
+    unsafe { array.filterUsingPredicate(&predicate) };
+    assert_eq!(strings(&array), ["abc"]);
+}
+
+#[test]
+fn value() {
+    // SAFETY: Constant predicates don't call any methods.
+    unsafe {
+        assert!(NSPredicate::predicateWithValue(true).evaluateWithObject(None));
+        assert!(!NSPredicate::predicateWithValue(false).evaluateWithObject(None));
+    }
+}
+
+#[test]
+#[cfg(feature = "block2")]
+#[cfg(feature = "NSDictionary")]
+fn closure() {
+    let predicate = NSPredicate::from_fn(|object| object.is_some());
+    // SAFETY: The predicate only calls the closure.
+    unsafe {
+        assert!(predicate.evaluateWithObject(Some(ns_string!("a"))));
+        assert!(!predicate.evaluateWithObject(None));
+    }
+}
+
+#[test]
+#[cfg(feature = "NSCompoundPredicate")]
+fn compound() {
+    use crate::NSCompoundPredicate;
+
+    // SAFETY: The formats only call string methods.
+    let starts_with_a = unsafe { NSPredicate::from_format(ns_string!("SELF BEGINSWITH 'a'"), &[]) };
+    let ends_with_c = unsafe { NSPredicate::from_format(ns_string!("SELF ENDSWITH 'c'"), &[]) };
+
+    let and = NSCompoundPredicate::and(&[&*starts_with_a, &*ends_with_c]);
+    let or = NSCompoundPredicate::or(&[&*starts_with_a, &*ends_with_c]);
+    let not = NSCompoundPredicate::not(&starts_with_a);
+
+    let array = NSArray::from_slice(&[ns_string!("abc"), ns_string!("ab"), ns_string!("bc")]);
+    // SAFETY: The predicates only call string methods.
+    let filter = |predicate: &NSPredicate| {
+        let filtered = unsafe { array.filteredArrayUsingPredicate(predicate) };
+        strings(&filtered)
+    };
+    assert_eq!(filter(&and), ["abc"]);
+    assert_eq!(filter(&or), ["abc", "ab", "bc"]);
+    assert_eq!(filter(&not), ["bc"]);
+
+    // SAFETY: Empty compound predicates don't call any methods.
+    unsafe {
+        assert!(NSCompoundPredicate::and(&[]).evaluateWithObject(None));
+        assert!(!NSCompoundPredicate::or(&[]).evaluateWithObject(None));
+    }
+}
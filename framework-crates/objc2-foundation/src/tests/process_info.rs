@@ -25,3 +25,27 @@ fn encoding_operating_system_version() {
     let info = NSProcessInfo::processInfo();
     let _version = info.operatingSystemVersion();
 }
+
+/// Check that `available!` agrees with Foundation's own version check.
+#[test]
+#[cfg(target_os = "macos")]
+fn available_matches_process_info() {
+    use crate::NSOperatingSystemVersion;
+    use objc2::available;
+
+    let info = NSProcessInfo::processInfo();
+    let at_least = |major, minor| {
+        info.isOperatingSystemAtLeastVersion(NSOperatingSystemVersion {
+            majorVersion: major,
+            minorVersion: minor,
+            patchVersion: 0,
+        })
+    };
+
+    assert_eq!(available!(macos = 10.12), at_least(10, 12));
+    assert_eq!(available!(macos = 11.0), at_least(11, 0));
+    assert_eq!(available!(macos = 13.3), at_least(13, 3));
+    assert_eq!(available!(macos = 15.0), at_least(15, 0));
+    assert_eq!(available!(macos = 99.0), at_least(99, 0));
+    assert!(!available!(macos = 99.0));
+}
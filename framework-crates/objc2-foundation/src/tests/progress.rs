@@ -0,0 +1,62 @@
+#![cfg(feature = "NSProgress")]
+use crate::NSProgress;
+
+#[test]
+fn counts() {
+    let progress = NSProgress::discrete(4);
+    assert_eq!(progress.totalUnitCount(), 4);
+    assert_eq!(progress.completedUnitCount(), 0);
+    progress.increment(1);
+    progress.increment(2);
+    assert_eq!(progress.completedUnitCount(), 3);
+    assert_eq!(progress.fractionCompleted(), 0.75);
+    assert!(!progress.isFinished());
+    progress.increment(1);
+    assert!(progress.isFinished());
+}
+
+#[test]
+fn children() {
+    let parent = NSProgress::progressWithTotalUnitCount(10);
+    let child = NSProgress::discrete(2);
+    parent.addChild_withPendingUnitCount(&child, 6);
+    child.increment(1);
+    assert_eq!(parent.fractionCompleted(), 0.3);
+
+    let implicit = parent.with_current(4, || NSProgress::progressWithTotalUnitCount(1));
+    assert!(NSProgress::currentProgress().is_none());
+    implicit.increment(1);
+    assert_eq!(parent.fractionCompleted(), 0.7);
+}
+
+#[test]
+#[cfg(feature = "block2")]
+#[cfg(feature = "std")]
+fn handlers() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let progress = NSProgress::discrete(1);
+    progress.setPausable(true);
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let c = calls.clone();
+    progress.set_pausing_handler(move || {
+        c.fetch_add(1, Ordering::SeqCst);
+    });
+    let c = calls.clone();
+    progress.set_resuming_handler(move || {
+        c.fetch_add(10, Ordering::SeqCst);
+    });
+    let c = calls.clone();
+    progress.set_cancellation_handler(move || {
+        c.fetch_add(100, Ordering::SeqCst);
+    });
+
+    progress.pause();
+    assert!(progress.isPaused());
+    progress.resume();
+    progress.cancel();
+    assert!(progress.isCancelled());
+    assert_eq!(calls.load(Ordering::SeqCst), 111);
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "NSPropertyList")]
+#![cfg(feature = "NSData")]
+#![cfg(feature = "NSDictionary")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSObject")]
+use crate::{
+    ns_string, NSDictionary, NSMutableDictionary, NSPropertyListFormat,
+    NSPropertyListMutabilityOptions, NSPropertyListSerialization, NSString,
+};
+
+#[test]
+fn roundtrip() {
+    let dict = NSDictionary::from_slices(&[ns_string!("key")], &[ns_string!("value")]);
+    for format in [
+        NSPropertyListFormat::XMLFormat_v1_0,
+        NSPropertyListFormat::BinaryFormat_v1_0,
+    ] {
+        let data = NSPropertyListSerialization::to_data(&dict, format).unwrap();
+        let (object, read_format) = NSPropertyListSerialization::from_slice(
+            &data.to_vec(),
+            NSPropertyListMutabilityOptions::Immutable,
+        )
+        .unwrap();
+        assert_eq!(read_format, format);
+        let object = object.downcast_ref::<NSDictionary>().unwrap();
+        let value = object.objectForKey(ns_string!("key")).unwrap();
+        assert_eq!(
+            value.downcast_ref::<NSString>().unwrap(),
+            ns_string!("value")
+        );
+    }
+}
+
+#[test]
+fn binary_header() {
+    let data = NSPropertyListSerialization::to_data(
+        ns_string!("a"),
+        NSPropertyListFormat::BinaryFormat_v1_0,
+    )
+    .unwrap();
+    assert!(data.to_vec().starts_with(b"bplist00"));
+}
+
+#[test]
+fn mutable_containers() {
+    let dict = NSDictionary::from_slices(&[ns_string!("key")], &[ns_string!("value")]);
+    let data = NSPropertyListSerialization::to_data(&dict, NSPropertyListFormat::BinaryFormat_v1_0)
+        .unwrap();
+    let (object, _) = NSPropertyListSerialization::from_slice(
+        &data.to_vec(),
+        NSPropertyListMutabilityOptions::MutableContainers,
+    )
+    .unwrap();
+    assert!(object.downcast_ref::<NSMutableDictionary>().is_some());
+}
+
+#[test]
+fn errors() {
+    assert!(NSPropertyListSerialization::from_slice(
+        b"not a plist",
+        NSPropertyListMutabilityOptions::Immutable
+    )
+    .is_err());
+    assert!(NSPropertyListSerialization::to_data(
+        &crate::NSObject::new(),
+        NSPropertyListFormat::XMLFormat_v1_0
+    )
+    .is_err());
+}
@@ -0,0 +1,127 @@
+#![cfg(feature = "NSRegularExpression")]
+#![cfg(feature = "NSTextCheckingResult")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSRange")]
+use alloc::string::ToString;
+use alloc::vec;
+
+use crate::{ns_string, NSRegularExpression, NSRegularExpressionOptions};
+
+#[test]
+fn invalid_pattern() {
+    let res = NSRegularExpression::new(ns_string!("(abc"), NSRegularExpressionOptions::empty());
+    assert!(res.is_err());
+}
+
+#[test]
+fn is_match() {
+    let regex = NSRegularExpression::new(
+        ns_string!("^ab+c$"),
+        NSRegularExpressionOptions::CaseInsensitive,
+    )
+    .unwrap();
+    assert!(regex.is_match(ns_string!("ABBBC")));
+    assert!(!regex.is_match(ns_string!("ac")));
+    assert!(regex.first_match(ns_string!("ac")).is_none());
+}
+
+#[test]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSEnumerator")]
+fn capture_ranges() {
+    let regex = NSRegularExpression::new(
+        ns_string!(r"(\d+)(x)?"),
+        NSRegularExpressionOptions::empty(),
+    )
+    .unwrap();
+    let string = ns_string!("12 345x");
+    assert_eq!(regex.count_matches(string), 2);
+
+    let mut matches = regex.matches(string);
+
+    let first = matches.next().unwrap();
+    assert_eq!(first.capture_ranges(), vec![Some(0..2), Some(0..2), None]);
+    assert_eq!(first.capture_range(2), None);
+    assert_eq!(first.capture_range(3), None);
+
+    let second = matches.next().unwrap();
+    assert_eq!(second.capture_range(0), Some(3..7));
+    assert_eq!(second.capture_range(1), Some(3..6));
+    assert_eq!(second.capture_range(2), Some(6..7));
+
+    assert!(matches.next().is_none());
+}
+
+#[test]
+#[cfg(target_vendor = "apple")]
+fn named_captures() {
+    let regex = NSRegularExpression::new(
+        ns_string!(r"(?<key>\w+)=(?<value>\w*)"),
+        NSRegularExpressionOptions::empty(),
+    )
+    .unwrap();
+    let m = regex.first_match(ns_string!("-- foo=bar")).unwrap();
+    assert_eq!(m.named_capture_range(ns_string!("key")), Some(3..6));
+    assert_eq!(m.named_capture_range(ns_string!("value")), Some(7..10));
+}
+
+#[test]
+fn utf16_ranges() {
+    let regex =
+        NSRegularExpression::new(ns_string!("b"), NSRegularExpressionOptions::empty()).unwrap();
+    // U+1F600 is encoded as two UTF-16 code units.
+    let m = regex.first_match(ns_string!("\u{1F600}b")).unwrap();
+    assert_eq!(m.capture_range(0), Some(2..3));
+}
+
+#[test]
+fn replace_all() {
+    let regex = NSRegularExpression::new(
+        ns_string!(r"(\w+)@(\w+)"),
+        NSRegularExpressionOptions::empty(),
+    )
+    .unwrap();
+    let res = regex.replace_all(ns_string!("foo@bar, baz@qux"), ns_string!("$2@$1"));
+    assert_eq!(res.to_string(), "bar@foo, qux@baz");
+
+    let template = NSRegularExpression::escapedTemplateForString(ns_string!("$1"));
+    let res = regex.replace_all(ns_string!("a@b"), &template);
+    assert_eq!(res.to_string(), "$1");
+}
+
+#[test]
+fn data_detector_phone_number() {
+    use crate::{NSDataDetector, NSTextCheckingType};
+
+    let detector = NSDataDetector::new(NSTextCheckingType::PhoneNumber).unwrap();
+    let result = detector
+        .first_match(ns_string!("Call me at 555-123-4567 tomorrow"))
+        .unwrap();
+    assert_eq!(result.resultType(), NSTextCheckingType::PhoneNumber);
+    assert_eq!(result.phoneNumber().unwrap().to_string(), "555-123-4567");
+    assert_eq!(result.capture_range(0), Some(11..23));
+}
+
+#[test]
+#[cfg(feature = "NSURL")]
+fn data_detector_links() {
+    use crate::{NSDataDetector, NSTextCheckingType};
+
+    let detector =
+        NSDataDetector::new(NSTextCheckingType::Link | NSTextCheckingType::PhoneNumber).unwrap();
+    let string = ns_string!("Go to example.com or https://example.org/a");
+    assert_eq!(detector.count_matches(string), 2);
+    let result = detector.first_match(string).unwrap();
+    assert_eq!(result.resultType(), NSTextCheckingType::Link);
+    assert_eq!(
+        result.URL().unwrap().absoluteString().unwrap().to_string(),
+        "http://example.com"
+    );
+}
+
+#[test]
+fn data_detector_unsupported_type() {
+    use crate::{NSDataDetector, NSTextCheckingType};
+
+    assert!(NSDataDetector::new(NSTextCheckingType::Spelling).is_err());
+}
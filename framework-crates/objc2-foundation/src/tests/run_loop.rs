@@ -0,0 +1,62 @@
+#![cfg(feature = "NSRunLoop")]
+#![cfg(feature = "NSObjCRuntime")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSDate")]
+#![cfg(feature = "block2")]
+use alloc::rc::Rc;
+use core::cell::Cell;
+
+use crate::{ns_string, NSDate, NSDefaultRunLoopMode, NSRunLoop};
+
+#[test]
+#[cfg(feature = "NSTimer")]
+fn timer_only_fires_in_its_mode() {
+    use crate::NSTimer;
+
+    let mode = ns_string!("org.objc2.tests.CustomMode");
+    let count = Rc::new(Cell::new(0));
+    let timer = NSTimer::schedule_in_mode(0.0, false, mode, {
+        let count = Rc::clone(&count);
+        move |_timer| count.set(count.get() + 1)
+    });
+
+    let run_loop = NSRunLoop::currentRunLoop();
+    let date = NSDate::dateWithTimeIntervalSinceNow(0.05);
+    run_loop.runMode_beforeDate(unsafe { NSDefaultRunLoopMode }, &date);
+    assert_eq!(count.get(), 0);
+
+    for _ in 0..10 {
+        if count.get() != 0 {
+            break;
+        }
+        let date = NSDate::dateWithTimeIntervalSinceNow(0.1);
+        run_loop.runMode_beforeDate(mode, &date);
+    }
+    assert_eq!(count.get(), 1);
+    assert!(!timer.isValid());
+}
+
+#[test]
+#[cfg(feature = "NSArray")]
+fn perform_in_mode() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let count = Arc::new(AtomicUsize::new(0));
+    let run_loop = NSRunLoop::currentRunLoop();
+    run_loop.perform_in_mode(unsafe { NSDefaultRunLoopMode }, {
+        let count = Arc::clone(&count);
+        move || {
+            count.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+
+    for _ in 0..10 {
+        if count.load(Ordering::Relaxed) != 0 {
+            break;
+        }
+        let date = NSDate::dateWithTimeIntervalSinceNow(0.1);
+        run_loop.runMode_beforeDate(unsafe { NSDefaultRunLoopMode }, &date);
+    }
+    assert_eq!(count.load(Ordering::Relaxed), 1);
+}
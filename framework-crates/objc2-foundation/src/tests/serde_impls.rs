@@ -0,0 +1,89 @@
+#![cfg(feature = "serde")]
+#![cfg(feature = "serde_json")]
+#![cfg(feature = "NSArray")]
+#![cfg(feature = "NSDictionary")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSValue")]
+use objc2::rc::Retained;
+use serde_json::json;
+
+use crate::{ns_string, NSArray, NSDictionary, NSNumber, NSString};
+
+#[test]
+fn string() {
+    let string = ns_string!("hello 😀");
+    assert_eq!(serde_json::to_value(string).unwrap(), json!("hello 😀"));
+
+    let string: Retained<NSString> = serde_json::from_value(json!("hello 😀")).unwrap();
+    assert_eq!(string.to_string(), "hello 😀");
+
+    assert!(serde_json::from_value::<Retained<NSString>>(json!(1)).is_err());
+}
+
+#[test]
+fn number() {
+    let cases = [
+        (NSNumber::new_bool(true), json!(true)),
+        (NSNumber::new_i32(-3), json!(-3)),
+        (NSNumber::new_u64(u64::MAX), json!(u64::MAX)),
+        (NSNumber::new_f64(1.5), json!(1.5)),
+    ];
+    for (number, value) in cases {
+        assert_eq!(serde_json::to_value(&number).unwrap(), value);
+        let deserialized: Retained<NSNumber> = serde_json::from_value(value).unwrap();
+        assert_eq!(deserialized, number);
+    }
+}
+
+#[test]
+fn collections() {
+    let value = json!({"a": ["x", "y"], "b": []});
+    let dict: Retained<NSDictionary<NSString, NSArray<NSString>>> =
+        serde_json::from_value(value.clone()).unwrap();
+    assert_eq!(dict.len(), 2);
+    let a = dict.objectForKey(ns_string!("a")).unwrap();
+    assert_eq!(a.objectAtIndex(1).to_string(), "y");
+    assert_eq!(serde_json::to_value(&dict).unwrap(), value);
+}
+
+#[test]
+#[cfg(feature = "NSData")]
+fn data() {
+    use crate::NSData;
+
+    let data = NSData::with_bytes(&[1, 2, 3]);
+    let value = serde_json::to_value(&data).unwrap();
+    assert_eq!(value, json!([1, 2, 3]));
+    let data: Retained<NSData> = serde_json::from_value(value).unwrap();
+    assert_eq!(data.to_vec(), [1, 2, 3]);
+}
+
+#[test]
+#[cfg(feature = "NSDate")]
+fn date() {
+    use crate::NSDate;
+
+    let date = NSDate::dateWithTimeIntervalSince1970(60.5);
+    let value = serde_json::to_value(&date).unwrap();
+    assert_eq!(value, json!(60.5));
+    let date: Retained<NSDate> = serde_json::from_value(value).unwrap();
+    assert_eq!(date.timeIntervalSince1970(), 60.5);
+}
+
+#[test]
+#[cfg(feature = "NSURL")]
+#[cfg(feature = "NSUUID")]
+fn url_and_uuid() {
+    use crate::{NSURL, NSUUID};
+
+    let url: Retained<NSURL> = serde_json::from_value(json!("https://example.com/a?b")).unwrap();
+    assert_eq!(
+        serde_json::to_value(&url).unwrap(),
+        json!("https://example.com/a?b")
+    );
+
+    let uuid = "E621E1F8-C36C-495A-93FC-0C247A3E6E5F";
+    let deserialized: Retained<NSUUID> = serde_json::from_value(json!(uuid)).unwrap();
+    assert_eq!(serde_json::to_value(&deserialized).unwrap(), json!(uuid));
+    assert!(serde_json::from_value::<Retained<NSUUID>>(json!("not a uuid")).is_err());
+}
@@ -118,6 +118,16 @@ fn test_intersection() {
     assert!(!set2.intersectsSet(&set3));
 }
 
+#[test]
+fn test_union() {
+    let set1 = NSSet::from_slice(&[ns_string!("one"), ns_string!("two")]);
+    let set2 = NSSet::from_slice(&[ns_string!("two"), ns_string!("three")]);
+
+    let union = set1.setByAddingObjectsFromSet(&set2);
+    assert_eq!(union.len(), 3);
+    assert!(union.isEqualToSet(&set1.setByAddingObject(ns_string!("three"))));
+}
+
 #[test]
 #[cfg(feature = "NSArray")]
 fn test_to_array() {
@@ -145,6 +155,21 @@ fn test_into_iter() {
     assert!(set.into_iter().all(|i| nums.contains(&i.as_i32())));
 }
 
+#[test]
+fn test_hash_set() {
+    use std::collections::HashSet;
+
+    use objc2::rc::Retained;
+
+    let set = NSSet::from_slice(&[ns_string!("one"), ns_string!("two")]);
+    let hash_set: HashSet<Retained<NSString>> = (&*set).into();
+    assert_eq!(hash_set.len(), 2);
+    assert!(hash_set.contains(ns_string!("one")));
+
+    let set: Retained<NSSet<NSString>> = hash_set.into_iter().collect();
+    assert!(set.containsObject(ns_string!("two")));
+}
+
 #[test]
 fn test_into_vec() {
     let strs = [ns_string!("one"), ns_string!("two"), ns_string!("three")];
@@ -187,3 +212,17 @@ fn test_debug() {
 fn new_from_nsobject() {
     let _ = NSSet::from_retained_slice(&[NSObject::new()]);
 }
+
+#[test]
+#[cfg(feature = "block2")]
+#[cfg(feature = "NSObjCRuntime")]
+fn test_for_each_concurrent() {
+    use core::sync::atomic::{AtomicI32, Ordering};
+
+    let set = NSSet::from_retained_slice(&[1, 2, 3, 4].map(NSNumber::new_i32));
+    let sum = AtomicI32::new(0);
+    set.for_each_concurrent(|number| {
+        sum.fetch_add(number.as_i32(), Ordering::Relaxed);
+    });
+    assert_eq!(sum.into_inner(), 10);
+}
@@ -0,0 +1,87 @@
+#![cfg(feature = "NSSortDescriptor")]
+#![cfg(feature = "NSArray")]
+#![cfg(feature = "NSString")]
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use crate::{ns_string, NSArray, NSMutableArray, NSSortDescriptor, NSString};
+
+fn strings(array: &NSArray<NSString>) -> Vec<alloc::string::String> {
+    array.to_vec().iter().map(|s| s.to_string()).collect()
+}
+
+fn sorted(
+    array: &NSArray<NSString>,
+    descriptors: &[&NSSortDescriptor],
+) -> Vec<alloc::string::String> {
+    // SAFETY: The tests only use the `length` and `self` keys, which are safe
+    // to look up on strings.
+    strings(&unsafe { array.sorted_by_descriptors(descriptors) })
+}
+
+#[test]
+fn key() {
+    let descriptor = NSSortDescriptor::new(ns_string!("length"), true);
+    assert_eq!(descriptor.key().unwrap().to_string(), "length");
+    assert!(descriptor.ascending());
+
+    let array = NSArray::from_slice(&[ns_string!("ccc"), ns_string!("a"), ns_string!("bb")]);
+    assert_eq!(sorted(&array, &[&descriptor]), ["a", "bb", "ccc"]);
+
+    let reversed = descriptor.reversedSortDescriptor();
+    let reversed = reversed.downcast_ref::<NSSortDescriptor>().unwrap();
+    assert!(!reversed.ascending());
+    assert_eq!(sorted(&array, &[reversed]), ["ccc", "bb", "a"]);
+}
+
+#[test]
+fn tie_breaking() {
+    let by_length = NSSortDescriptor::new(ns_string!("length"), true);
+    let by_value = NSSortDescriptor::new(ns_string!("self"), false);
+
+    let array = NSMutableArray::from_slice(&[
+        ns_string!("ab"),
+        ns_string!("b"),
+        ns_string!("ba"),
+        ns_string!("a"),
+    ]);
+    // SAFETY: `length` and `self` are safe to look up on strings.
+    unsafe { array.sort_by_descriptors(&[&by_length, &by_value]) };
+    assert_eq!(strings(&array), ["b", "a", "ba", "ab"]);
+}
+
+#[test]
+fn case_insensitive() {
+    let descriptor = NSSortDescriptor::case_insensitive(ns_string!("self"), true);
+    assert_eq!(
+        descriptor.selector(),
+        Some(objc2::sel!(caseInsensitiveCompare:))
+    );
+
+    let array = NSArray::from_slice(&[ns_string!("b"), ns_string!("C"), ns_string!("A")]);
+    assert_eq!(sorted(&array, &[&descriptor]), ["A", "b", "C"]);
+
+    let descriptor = NSSortDescriptor::localized_standard(ns_string!("self"), true);
+    let array = NSArray::from_slice(&[ns_string!("file10"), ns_string!("file2")]);
+    assert_eq!(sorted(&array, &[&descriptor]), ["file2", "file10"]);
+}
+
+#[test]
+#[cfg(feature = "block2")]
+#[cfg(feature = "NSObjCRuntime")]
+fn comparator() {
+    use crate::NSComparisonResult;
+
+    let descriptor = NSSortDescriptor::with_comparator(ns_string!("self"), false, |a, b| {
+        let a = a.downcast_ref::<NSString>().unwrap().to_string();
+        let b = b.downcast_ref::<NSString>().unwrap().to_string();
+        a.chars().rev().cmp(b.chars().rev())
+    });
+
+    let array = NSArray::from_slice(&[ns_string!("ab"), ns_string!("ba"), ns_string!("cc")]);
+    assert_eq!(sorted(&array, &[&descriptor]), ["cc", "ab", "ba"]);
+    assert_eq!(
+        unsafe { descriptor.compareObject_toObject(ns_string!("ab"), ns_string!("ba")) },
+        NSComparisonResult::Ascending,
+    );
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "NSStream")]
+#![cfg(feature = "NSData")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "std")]
+use alloc::vec::Vec;
+use std::io::{ErrorKind, Read, Write};
+
+use crate::{NSData, NSInputStream, NSOutputStream, NSStreamStatus};
+
+#[test]
+fn read_from_data() {
+    let data = NSData::with_bytes(b"hello world");
+    let stream = NSInputStream::inputStreamWithData(&data).unwrap();
+
+    // Reading before the stream is opened fails.
+    let err = (&*stream).read(&mut [0; 4]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::NotConnected);
+
+    stream.open();
+    let mut buf = Vec::new();
+    (&*stream).read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, b"hello world");
+    assert_eq!(stream.streamStatus(), NSStreamStatus::AtEnd);
+    stream.close();
+}
+
+#[test]
+fn write_to_memory() {
+    let stream = NSOutputStream::outputStreamToMemory();
+    stream.open();
+    (&*stream).write_all(b"hello").unwrap();
+    (&*stream).write_all(b" world").unwrap();
+    stream.close();
+
+    let data = stream
+        .propertyForKey(unsafe { crate::NSStreamDataWrittenToMemoryStreamKey })
+        .unwrap()
+        .downcast::<NSData>()
+        .unwrap();
+    assert_eq!(data.to_vec(), b"hello world");
+}
@@ -76,6 +76,50 @@ fn test_interior_nul() {
     assert_eq!(s.to_string(), expected);
 }
 
+#[test]
+#[cfg(target_vendor = "apple")]
+fn test_to_str_no_copy() {
+    let short = NSString::from_str("ascii");
+    if let Some(s) = unsafe { short.to_str_no_copy() } {
+        assert_eq!(s, "ascii");
+    }
+
+    // Non-ASCII strings are stored as UTF-16.
+    let s = NSString::from_str("ประเทศไทย");
+    assert_eq!(unsafe { s.to_str_no_copy() }, None);
+
+    // The length is not determined by the first NUL character.
+    let s = NSString::from_str("a\0b");
+    if let Some(s) = unsafe { s.to_str_no_copy() } {
+        assert_eq!(s, "a\0b");
+    }
+
+    // Longer than the stack buffer used when formatting.
+    let expected = "a".repeat(1000);
+    let s = NSString::from_str(&expected);
+    if let Some(s) = unsafe { s.to_str_no_copy() } {
+        assert_eq!(s, expected);
+    }
+    assert_eq!(s.to_string(), expected);
+    assert_eq!(short.to_string(), "ascii");
+    assert_eq!(format!("{short:>7}"), "  ascii");
+}
+
+#[test]
+#[cfg(target_vendor = "apple")]
+#[cfg(target_pointer_width = "64")]
+fn test_short_strings_are_tagged() {
+    use super::is_tagged_pointer;
+
+    assert!(is_tagged_pointer(&*NSString::from_str("a")));
+    assert!(is_tagged_pointer(&*NSString::from_str("abcdefg")));
+    // Non-ASCII and long strings are allocated.
+    assert!(!is_tagged_pointer(&*NSString::from_str("ประเทศไทย")));
+    assert!(!is_tagged_pointer(&*NSString::from_str(&"a".repeat(100))));
+    // Mutable strings are never tagged.
+    assert!(!is_tagged_pointer(&*crate::NSMutableString::from_str("a")));
+}
+
 #[test]
 #[cfg(feature = "NSObject")]
 fn test_copy() {
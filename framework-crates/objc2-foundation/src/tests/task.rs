@@ -0,0 +1,68 @@
+#![cfg(feature = "NSTask")]
+#![cfg(feature = "NSArray")]
+#![cfg(feature = "NSData")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "NSFileHandle")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "NSURL")]
+#![cfg(feature = "std")]
+#![cfg(target_os = "macos")]
+use crate::{ns_string, NSArray, NSPipe, NSTask, NSURL};
+
+#[test]
+fn run_with_pipe() {
+    let task = NSTask::with_executable(
+        &NSURL::from_file_path("/bin/echo").unwrap(),
+        &NSArray::from_slice(&[ns_string!("hello")]),
+    );
+    let pipe = NSPipe::pipe();
+    task.setStandardOutput(Some(&pipe));
+    assert_eq!(task.run_until_exit().unwrap(), 0);
+    assert!(!task.isRunning());
+
+    let output = pipe
+        .fileHandleForReading()
+        .readDataToEndOfFileAndReturnError()
+        .unwrap();
+    assert_eq!(output.to_vec(), b"hello\n");
+}
+
+#[test]
+fn exit_status() {
+    let task = NSTask::with_executable(
+        &NSURL::from_file_path("/bin/sh").unwrap(),
+        &NSArray::from_slice(&[ns_string!("-c"), ns_string!("exit 3")]),
+    );
+    assert_eq!(task.run_until_exit().unwrap(), 3);
+}
+
+#[test]
+fn missing_executable() {
+    let task = NSTask::with_executable(
+        &NSURL::from_file_path("/does/not/exist").unwrap(),
+        &NSArray::new(),
+    );
+    assert!(task.run_until_exit().is_err());
+}
+
+#[test]
+#[cfg(feature = "block2")]
+fn termination_handler() {
+    use std::sync::mpsc;
+
+    let task = NSTask::with_executable(
+        &NSURL::from_file_path("/usr/bin/true").unwrap(),
+        &NSArray::new(),
+    );
+    let (sender, receiver) = mpsc::channel();
+    let sender = std::sync::Mutex::new(sender);
+    task.set_termination_handler(move |task| {
+        sender
+            .lock()
+            .unwrap()
+            .send(task.terminationStatus())
+            .unwrap();
+    });
+    task.launchAndReturnError().unwrap();
+    assert_eq!(receiver.recv().unwrap(), 0);
+}
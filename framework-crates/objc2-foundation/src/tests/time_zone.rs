@@ -0,0 +1,47 @@
+#![cfg(feature = "NSTimeZone")]
+#![cfg(feature = "NSString")]
+use alloc::string::ToString;
+
+use crate::{ns_string, NSTimeZone};
+
+#[test]
+fn utc() {
+    let utc = NSTimeZone::utc();
+    assert_eq!(utc.secondsFromGMT(), 0);
+    assert_eq!(utc.daylightSavingTimeOffset(), 0.0);
+}
+
+#[test]
+fn fixed_offset() {
+    let tz = NSTimeZone::timeZoneForSecondsFromGMT(-5 * 3600);
+    assert_eq!(tz.secondsFromGMT(), -5 * 3600);
+    assert_eq!(tz.abbreviation().unwrap().to_string(), "GMT-5");
+}
+
+#[test]
+#[cfg(feature = "NSDate")]
+#[cfg_attr(
+    not(target_vendor = "apple"),
+    ignore = "depends on the system time zone database"
+)]
+fn named() {
+    let tz = NSTimeZone::timeZoneWithName(ns_string!("Europe/Copenhagen")).unwrap();
+    assert_eq!(tz.name().to_string(), "Europe/Copenhagen");
+
+    // 2001-01-01 is in winter, 2001-07-01 is in summer.
+    let winter = crate::NSDate::dateWithTimeIntervalSinceReferenceDate(0.0);
+    let summer = crate::NSDate::dateWithTimeIntervalSinceReferenceDate(181.0 * 86400.0);
+    assert_eq!(tz.secondsFromGMTForDate(&winter), 3600);
+    assert_eq!(tz.secondsFromGMTForDate(&summer), 7200);
+    assert!(!tz.isDaylightSavingTimeForDate(&winter));
+    assert!(tz.isDaylightSavingTimeForDate(&summer));
+
+    assert!(NSTimeZone::timeZoneWithName(ns_string!("Not/A_Zone")).is_none());
+}
+
+#[test]
+#[cfg(feature = "NSArray")]
+fn known_names() {
+    let names = NSTimeZone::knownTimeZoneNames();
+    assert!(!names.is_empty());
+}
@@ -0,0 +1,104 @@
+#![cfg(feature = "NSURL")]
+#![cfg(feature = "NSString")]
+use alloc::string::ToString;
+
+use crate::{ns_string, NSURLComponents, NSURL};
+
+#[test]
+fn web_url() {
+    let url = NSURL::URLWithString(ns_string!("https://example.com/a/b?c=d#e")).unwrap();
+    assert!(!url.isFileURL());
+    assert_eq!(url.scheme().unwrap().to_string(), "https");
+    assert_eq!(url.host().unwrap().to_string(), "example.com");
+    assert_eq!(url.path().unwrap().to_string(), "/a/b");
+    assert_eq!(url.query().unwrap().to_string(), "c=d");
+    assert_eq!(url.fragment().unwrap().to_string(), "e");
+}
+
+#[test]
+fn relative_url() {
+    let base = NSURL::URLWithString(ns_string!("https://example.com/a/b")).unwrap();
+    let url = NSURL::URLWithString_relativeToURL(ns_string!("../c"), Some(&base)).unwrap();
+    assert_eq!(url.relativeString().to_string(), "../c");
+    assert_eq!(
+        url.absoluteString().unwrap().to_string(),
+        "https://example.com/c"
+    );
+}
+
+#[test]
+#[cfg(feature = "NSArray")]
+#[cfg(feature = "NSEnumerator")]
+#[cfg(feature = "NSValue")]
+fn components() {
+    let components = NSURLComponents::new();
+    components.setScheme(Some(ns_string!("https")));
+    components.setHost(Some(ns_string!("example.com")));
+    components.set_port_number(Some(8080));
+    components.setPath(Some(ns_string!("/a b")));
+    components.append_query_item(ns_string!("key"), Some(ns_string!("v=1&2")));
+    components.append_query_item(ns_string!("empty"), None);
+
+    assert_eq!(components.port_number(), Some(8080));
+    assert_eq!(
+        components
+            .URL()
+            .unwrap()
+            .absoluteString()
+            .unwrap()
+            .to_string(),
+        "https://example.com:8080/a%20b?key=v%3D1%262&empty",
+    );
+
+    let pairs = components.query_pairs();
+    assert_eq!(pairs.len(), 2);
+    assert_eq!(pairs[0].0.to_string(), "key");
+    assert_eq!(pairs[0].1.as_ref().unwrap().to_string(), "v=1&2");
+    assert_eq!(pairs[1].0.to_string(), "empty");
+    assert!(pairs[1].1.is_none());
+
+    components.set_port_number(None);
+    assert_eq!(components.port_number(), None);
+}
+
+#[cfg(all(feature = "std", unix))]
+mod path {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let url = NSURL::from_file_path("/tmp/a b/ü.txt").unwrap();
+        assert!(url.isFileURL());
+        assert_eq!(url.to_file_path().unwrap(), Path::new("/tmp/a b/ü.txt"));
+    }
+
+    #[test]
+    fn directory() {
+        let url = NSURL::from_directory_path("/tmp/dir").unwrap();
+        assert!(url.absoluteString().unwrap().to_string().ends_with('/'));
+        assert_eq!(url.to_file_path().unwrap(), Path::new("/tmp/dir"));
+    }
+
+    #[test]
+    #[cfg_attr(feature = "gnustep-1-7", ignore = "GNUStep validates UTF-8")]
+    fn non_utf8() {
+        let path = Path::new(OsStr::from_bytes(b"/tmp/\xff.txt"));
+        let url = NSURL::from_file_path(path).unwrap();
+        assert_eq!(url.to_file_path().unwrap(), path);
+    }
+
+    #[test]
+    fn interior_nul() {
+        assert!(NSURL::from_file_path("/tmp/a\0b").is_none());
+    }
+
+    #[test]
+    fn not_file_url() {
+        let url = NSURL::URLWithString(ns_string!("https://example.com")).unwrap();
+        assert_eq!(url.to_file_path(), None);
+    }
+}
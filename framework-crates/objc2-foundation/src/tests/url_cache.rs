@@ -0,0 +1,61 @@
+#![cfg(feature = "NSURLCache")]
+#![cfg(feature = "NSString")]
+use objc2::AllocAnyThread;
+
+use crate::NSURLCache;
+
+#[test]
+fn capacity() {
+    #[allow(deprecated)]
+    let cache = NSURLCache::initWithMemoryCapacity_diskCapacity_diskPath(
+        NSURLCache::alloc(),
+        1024 * 1024,
+        0,
+        None,
+    );
+    assert_eq!(cache.memoryCapacity(), 1024 * 1024);
+    assert_eq!(cache.diskCapacity(), 0);
+
+    cache.setMemoryCapacity(2048);
+    assert_eq!(cache.memoryCapacity(), 2048);
+}
+
+#[test]
+#[cfg(all(
+    feature = "NSData",
+    feature = "NSURL",
+    feature = "NSURLRequest",
+    feature = "NSURLResponse"
+))]
+#[cfg(target_vendor = "apple")]
+fn store_lookup_remove() {
+    use crate::{ns_string, NSData, NSURLRequest, NSURLResponse, NSURL};
+
+    #[allow(deprecated)]
+    let cache = NSURLCache::initWithMemoryCapacity_diskCapacity_diskPath(
+        NSURLCache::alloc(),
+        1024 * 1024,
+        0,
+        None,
+    );
+    let url = NSURL::URLWithString(ns_string!("https://example.com/cached")).unwrap();
+    let request = NSURLRequest::requestWithURL(&url);
+    let response = NSURLResponse::initWithURL_MIMEType_expectedContentLength_textEncodingName(
+        NSURLResponse::alloc(),
+        &url,
+        Some(ns_string!("text/plain")),
+        5,
+        None,
+    );
+    let data = NSData::with_bytes(b"hello");
+
+    assert!(cache.lookup(&request).is_none());
+
+    cache.store(&request, &response, &data);
+    let (cached_response, cached_data) = cache.lookup(&request).unwrap();
+    assert_eq!(cached_response.URL().as_deref(), Some(&*url));
+    assert_eq!(cached_data.to_vec(), b"hello");
+
+    cache.removeCachedResponseForRequest(&request);
+    assert!(cache.lookup(&request).is_none());
+}
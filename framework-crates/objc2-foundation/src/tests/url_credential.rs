@@ -0,0 +1,34 @@
+#![cfg(feature = "NSURLCredential")]
+#![cfg(feature = "NSURLProtectionSpace")]
+#![cfg(feature = "NSString")]
+
+#[test]
+#[cfg(target_vendor = "apple")]
+fn password_credential_has_no_identity() {
+    use crate::{ns_string, NSURLCredential, NSURLCredentialPersistence};
+
+    let credential = NSURLCredential::credentialWithUser_password_persistence(
+        ns_string!("user"),
+        ns_string!("password"),
+        NSURLCredentialPersistence::None,
+    );
+    assert!(credential.identity().is_none());
+}
+
+#[test]
+#[cfg(target_vendor = "apple")]
+fn http_protection_space_has_no_server_trust() {
+    use objc2::AllocAnyThread;
+
+    use crate::{ns_string, NSURLProtectionSpace};
+
+    let space = NSURLProtectionSpace::initWithHost_port_protocol_realm_authenticationMethod(
+        NSURLProtectionSpace::alloc(),
+        ns_string!("example.com"),
+        80,
+        Some(ns_string!("http")),
+        Some(ns_string!("realm")),
+        None,
+    );
+    assert!(space.serverTrust().is_none());
+}
@@ -0,0 +1,102 @@
+#![cfg(feature = "NSURLSession")]
+#![cfg(feature = "NSURLRequest")]
+#![cfg(feature = "NSURLResponse")]
+#![cfg(feature = "NSURLError")]
+#![cfg(feature = "NSURL")]
+#![cfg(feature = "NSData")]
+#![cfg(feature = "NSDictionary")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "NSString")]
+#![cfg(feature = "block2")]
+#![cfg(feature = "std")]
+#![cfg(target_os = "macos")]
+use alloc::string::ToString;
+use alloc::sync::Arc;
+use core::future::Future;
+use core::pin::pin;
+use core::task::{Context, Poll};
+use std::task::Wake;
+use std::thread::{self, Thread};
+
+use crate::{NSURLRequest, NSURLSession, NSURL};
+
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let waker = Arc::new(ThreadWaker(thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        match fut.as_mut().poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+#[test]
+fn data_task_file_url() {
+    let path = std::env::temp_dir().join(alloc::format!(
+        "objc2-foundation-url-session-{}",
+        std::process::id()
+    ));
+    std::fs::write(&path, "hello").unwrap();
+
+    let url = NSURL::from_file_path(&path).unwrap();
+    let request = NSURLRequest::requestWithURL(&url);
+    let (data, response) = block_on(NSURLSession::sharedSession().data_task(&request)).unwrap();
+    assert_eq!(data.to_vec(), b"hello");
+    assert_eq!(response.expectedContentLength(), 5);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn data_task_error() {
+    let url = NSURL::from_file_path("/does/not/exist").unwrap();
+    let request = NSURLRequest::requestWithURL(&url);
+    let err = block_on(NSURLSession::sharedSession().data_task(&request)).unwrap_err();
+    assert_eq!(err.domain().to_string(), "NSURLErrorDomain");
+}
+
+#[test]
+#[cfg(feature = "NSFileManager")]
+fn download_task_file_url() {
+    use crate::url_session::TransferEvent;
+
+    let source = std::env::temp_dir().join(alloc::format!(
+        "objc2-foundation-download-source-{}",
+        std::process::id()
+    ));
+    let destination = source.with_file_name(alloc::format!(
+        "objc2-foundation-download-destination-{}",
+        std::process::id()
+    ));
+    std::fs::write(&source, "hello").unwrap();
+
+    let request = NSURLRequest::requestWithURL(&NSURL::from_file_path(&source).unwrap());
+    let mut task = NSURLSession::sharedSession()
+        .download_task(&request, &NSURL::from_file_path(&destination).unwrap());
+
+    let mut completed = false;
+    while let Some(event) = block_on(core::future::poll_fn(|cx| task.poll_event(cx))) {
+        match event {
+            TransferEvent::Progress { transferred, .. } => assert!(transferred <= 5),
+            TransferEvent::Completed(result) => {
+                result.unwrap();
+                completed = true;
+            }
+        }
+    }
+    assert!(completed);
+    assert_eq!(std::fs::read_to_string(&destination).unwrap(), "hello");
+
+    std::fs::remove_file(&source).unwrap();
+    std::fs::remove_file(&destination).unwrap();
+}
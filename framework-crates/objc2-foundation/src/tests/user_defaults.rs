@@ -0,0 +1,70 @@
+#![cfg(feature = "NSUserDefaults")]
+#![cfg(feature = "NSString")]
+use alloc::string::ToString;
+
+use crate::{ns_string, NSString, NSUserDefaults};
+
+#[test]
+#[cfg(target_vendor = "apple")]
+fn typed_values() {
+    let suite = ns_string!("com.github.madsmtm.objc2.tests.typed-values");
+    let defaults = NSUserDefaults::suite(suite).unwrap();
+
+    defaults.setBool_forKey(true, ns_string!("bool"));
+    assert!(defaults.boolForKey(ns_string!("bool")));
+
+    defaults.setInteger_forKey(-7, ns_string!("integer"));
+    assert_eq!(defaults.integerForKey(ns_string!("integer")), -7);
+
+    defaults.setDouble_forKey(1.5, ns_string!("double"));
+    assert_eq!(defaults.doubleForKey(ns_string!("double")), 1.5);
+
+    let value: &objc2::runtime::AnyObject = ns_string!("hello");
+    defaults.setObject_forKey(Some(value), ns_string!("string"));
+    assert_eq!(
+        defaults
+            .stringForKey(ns_string!("string"))
+            .unwrap()
+            .to_string(),
+        "hello"
+    );
+    assert_eq!(
+        defaults
+            .get::<NSString>(ns_string!("string"))
+            .unwrap()
+            .unwrap()
+            .to_string(),
+        "hello"
+    );
+
+    defaults.removeObjectForKey(ns_string!("string"));
+    assert!(defaults.get::<NSString>(ns_string!("string")).is_none());
+
+    // Don't leave the suite behind on disk.
+    defaults.removePersistentDomainForName(suite);
+    assert!(!defaults.boolForKey(ns_string!("bool")));
+}
+
+#[test]
+#[cfg(feature = "NSDictionary")]
+#[cfg(feature = "NSObject")]
+fn register_defaults() {
+    use objc2::runtime::AnyObject;
+
+    use crate::NSDictionary;
+
+    // Registered defaults are only kept in memory.
+    let defaults = NSUserDefaults::new();
+    let registered = NSDictionary::from_slices(
+        &[ns_string!("objc2-registered")],
+        &[ns_string!("default") as &AnyObject],
+    );
+    defaults.registerDefaults(&registered);
+    assert_eq!(
+        defaults
+            .stringForKey(ns_string!("objc2-registered"))
+            .unwrap()
+            .to_string(),
+        "default"
+    );
+}
@@ -41,6 +41,23 @@ fn display_debug() {
     assert_eq!(format!("{uuid:?}"), expected);
 }
 
+#[test]
+#[cfg(feature = "NSString")]
+fn string_roundtrip() {
+    use crate::ns_string;
+
+    let uuid = NSUUID::UUID();
+    let string = uuid.UUIDString();
+    assert_eq!(NSUUID::from_string(&string).unwrap(), uuid);
+
+    // Parsing is case-insensitive, but rendering is always uppercase.
+    let uuid = NSUUID::from_string(ns_string!("e621e1f8-c36c-495a-93fc-0c247a3e6e5f")).unwrap();
+    assert_eq!(format!("{uuid}"), "E621E1F8-C36C-495A-93FC-0C247A3E6E5F");
+
+    assert!(NSUUID::from_string(ns_string!("")).is_none());
+    assert!(NSUUID::from_string(ns_string!("E621E1F8-C36C-495A-93FC")).is_none());
+}
+
 // #[test]
 // fn test_compare() {
 //     let uuid1 = NSUUID::from_bytes([10; 16]);
@@ -52,6 +52,7 @@ fn nsrange() {
     let range = NSRange::from(1..2);
     let val = NSValue::new(range);
     assert_eq!(val.get_range(), Some(range));
+    assert_eq!(NSValue::new_range(range), val);
     #[cfg(feature = "NSGeometry")]
     {
         assert_eq!(val.get_point(), None);
@@ -96,6 +97,28 @@ fn nsrect() {
     assert_eq!(unsafe { val.get::<NSRect>() }, rect);
 }
 
+#[test]
+fn try_get() {
+    let val = NSValue::new(13u32);
+    assert_eq!(unsafe { val.try_get::<u32>() }, Some(13));
+    assert_eq!(unsafe { val.try_get::<i32>() }, None);
+    assert_eq!(unsafe { val.try_get::<u8>() }, None);
+    assert_eq!(unsafe { val.try_get::<f32>() }, None);
+}
+
+#[test]
+#[cfg(feature = "NSGeometry")]
+fn convenience_constructors() {
+    use crate::{NSPoint, NSRect, NSSize};
+    let point = NSPoint::new(1.0, 2.0);
+    let size = NSSize::new(3.0, 4.0);
+    let rect = NSRect::new(point, size);
+    assert_eq!(NSValue::new_point(point).get_point(), Some(point));
+    assert_eq!(NSValue::new_size(size).get_size(), Some(size));
+    assert_eq!(NSValue::new_rect(rect).get_rect(), Some(rect));
+    assert_eq!(NSValue::new_rect(rect).get_point(), None);
+}
+
 #[test]
 fn store_str() {
     let s = "abc";
@@ -0,0 +1,117 @@
+#![cfg(feature = "NSXMLParser")]
+#![cfg(feature = "NSData")]
+#![cfg(feature = "NSDictionary")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "NSString")]
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+use crate::xml_parser::ParserDelegate;
+use crate::{ns_string, NSData, NSDictionary, NSError, NSString, NSXMLParser};
+
+#[derive(Default)]
+struct Events(Vec<String>);
+
+impl ParserDelegate for Events {
+    fn did_start_document(&mut self, _parser: &NSXMLParser) {
+        self.0.push("start document".to_string());
+    }
+
+    fn did_end_document(&mut self, _parser: &NSXMLParser) {
+        self.0.push("end document".to_string());
+    }
+
+    fn did_start_element(
+        &mut self,
+        _parser: &NSXMLParser,
+        element_name: &NSString,
+        _namespace_uri: Option<&NSString>,
+        _qualified_name: Option<&NSString>,
+        attributes: &NSDictionary<NSString, NSString>,
+    ) {
+        let id = attributes
+            .objectForKey(ns_string!("id"))
+            .map(|id| id.to_string())
+            .unwrap_or_default();
+        self.0.push(format!("start {element_name} {id}"));
+    }
+
+    fn did_end_element(
+        &mut self,
+        _parser: &NSXMLParser,
+        element_name: &NSString,
+        _namespace_uri: Option<&NSString>,
+        _qualified_name: Option<&NSString>,
+    ) {
+        self.0.push(format!("end {element_name}"));
+    }
+
+    fn found_characters(&mut self, _parser: &NSXMLParser, string: &NSString) {
+        self.0.push(format!("characters {string}"));
+    }
+
+    fn found_cdata(&mut self, _parser: &NSXMLParser, cdata: &NSData) {
+        self.0.push(format!(
+            "cdata {}",
+            String::from_utf8(cdata.to_vec()).unwrap()
+        ));
+    }
+
+    fn parse_error_occurred(&mut self, _parser: &NSXMLParser, _error: &NSError) {
+        self.0.push("error".to_string());
+    }
+}
+
+#[test]
+fn events() {
+    let parser = NSXMLParser::from_slice(br#"<a id="1">text<b><![CDATA[raw]]></b></a>"#);
+    let mut events = Events::default();
+    parser.parse_with(&mut events).unwrap();
+    assert_eq!(
+        events.0,
+        [
+            "start document",
+            "start a 1",
+            "characters text",
+            "start b ",
+            "cdata raw",
+            "end b",
+            "end a",
+            "end document",
+        ]
+    );
+}
+
+#[test]
+fn invalid_document() {
+    let parser = NSXMLParser::from_slice(b"<a><b></a>");
+    let mut events = Events::default();
+    let err = parser.parse_with(&mut events).unwrap_err();
+    assert_eq!(err.domain().to_string(), "NSXMLParserErrorDomain");
+    assert!(events.0.contains(&"error".to_string()));
+}
+
+#[test]
+fn abort() {
+    struct AbortOnFirst(usize);
+
+    impl ParserDelegate for AbortOnFirst {
+        fn did_start_element(
+            &mut self,
+            parser: &NSXMLParser,
+            _element_name: &NSString,
+            _namespace_uri: Option<&NSString>,
+            _qualified_name: Option<&NSString>,
+            _attributes: &NSDictionary<NSString, NSString>,
+        ) {
+            self.0 += 1;
+            parser.abortParsing();
+        }
+    }
+
+    let parser = NSXMLParser::from_slice(b"<a><b/><c/></a>");
+    let mut delegate = AbortOnFirst(0);
+    assert!(parser.parse_with(&mut delegate).is_err());
+    assert_eq!(delegate.0, 1);
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "NSXPCConnection")]
+#![cfg(feature = "NSError")]
+#![cfg(feature = "block2")]
+#![cfg(target_os = "macos")]
+use objc2::runtime::{NSObjectProtocol, ProtocolObject};
+use objc2::{AllocAnyThread, ProtocolType};
+
+use crate::{NSXPCConnection, NSXPCInterface, NSXPCListener};
+
+#[test]
+fn interface_from_protocol() {
+    let interface = NSXPCInterface::from_protocol::<dyn NSObjectProtocol>();
+    assert_eq!(
+        &*interface.protocol(),
+        <dyn NSObjectProtocol>::protocol().unwrap()
+    );
+}
+
+#[test]
+fn remote_object_proxy() {
+    let listener = NSXPCListener::anonymousListener();
+    let connection =
+        NSXPCConnection::initWithListenerEndpoint(NSXPCConnection::alloc(), &listener.endpoint());
+    connection.setRemoteObjectInterface(Some(
+        &NSXPCInterface::from_protocol::<dyn NSObjectProtocol>(),
+    ));
+    let _proxy: objc2::rc::Retained<ProtocolObject<dyn NSObjectProtocol>> =
+        connection.remote_object_proxy(|_| {});
+    connection.invalidate();
+}
+
+#[test]
+#[should_panic = "remoteObjectInterface must be set"]
+fn remote_object_proxy_without_interface() {
+    let listener = NSXPCListener::anonymousListener();
+    let connection =
+        NSXPCConnection::initWithListenerEndpoint(NSXPCConnection::alloc(), &listener.endpoint());
+    let _ = connection.remote_object_proxy::<dyn NSObjectProtocol>(|_| {});
+}
+
+#[test]
+fn exported_object() {
+    let listener = NSXPCListener::anonymousListener();
+    let connection =
+        NSXPCConnection::initWithListenerEndpoint(NSXPCConnection::alloc(), &listener.endpoint());
+    connection.setExportedInterface(Some(
+        &NSXPCInterface::from_protocol::<dyn NSObjectProtocol>(),
+    ));
+    let object = objc2::runtime::NSObject::new();
+    connection.set_exported_object(ProtocolObject::<dyn NSObjectProtocol>::from_ref(&*object));
+    connection.set_interruption_handler(|| {});
+    connection.set_invalidation_handler(|| {});
+    connection.invalidate();
+}
+
+#[test]
+fn listener_delegate() {
+    let listener = NSXPCListener::anonymousListener();
+    let _delegate = listener.set_connection_handler(|_| false);
+    assert!(listener.delegate().is_some());
+    listener.invalidate();
+}
@@ -0,0 +1,24 @@
+//! Utilities for the `NSTimeZone` class.
+use objc2::rc::Retained;
+
+use crate::NSTimeZone;
+
+/// Convenience creation methods.
+impl NSTimeZone {
+    /// The UTC time zone, with an offset of zero seconds from GMT.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSTimeZone;
+    ///
+    /// let utc = NSTimeZone::utc();
+    /// assert_eq!(utc.secondsFromGMT(), 0);
+    /// assert!(!utc.isDaylightSavingTime());
+    /// ```
+    #[doc(alias = "timeZoneForSecondsFromGMT:")]
+    pub fn utc() -> Retained<Self> {
+        Self::timeZoneForSecondsFromGMT(0)
+    }
+}
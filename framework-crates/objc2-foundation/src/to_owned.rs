@@ -6,6 +6,22 @@ use objc2::Message;
 
 use crate::{NSCopying, NSMutableCopying};
 
+#[cfg(feature = "NSCharacterSet")]
+impl ToOwned for crate::NSCharacterSet {
+    type Owned = Retained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        self.copy()
+    }
+}
+
+#[cfg(feature = "NSCharacterSet")]
+impl ToOwned for crate::NSMutableCharacterSet {
+    type Owned = Retained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        self.mutableCopy()
+    }
+}
+
 #[cfg(feature = "NSArray")]
 impl<ObjectType: Message> ToOwned for crate::NSArray<ObjectType> {
     type Owned = Retained<Self>;
@@ -38,6 +54,24 @@ impl ToOwned for crate::NSMutableData {
     }
 }
 
+#[cfg(feature = "NSDictionary")]
+impl<KeyType: Message, ObjectType: Message> ToOwned for crate::NSDictionary<KeyType, ObjectType> {
+    type Owned = Retained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        self.copy()
+    }
+}
+
+#[cfg(feature = "NSDictionary")]
+impl<KeyType: Message, ObjectType: Message> ToOwned
+    for crate::NSMutableDictionary<KeyType, ObjectType>
+{
+    type Owned = Retained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        self.mutableCopy()
+    }
+}
+
 #[cfg(feature = "NSException")]
 impl ToOwned for crate::NSException {
     type Owned = Retained<Self>;
@@ -46,6 +80,38 @@ impl ToOwned for crate::NSException {
     }
 }
 
+#[cfg(feature = "NSIndexSet")]
+impl ToOwned for crate::NSIndexSet {
+    type Owned = Retained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        self.copy()
+    }
+}
+
+#[cfg(feature = "NSIndexSet")]
+impl ToOwned for crate::NSMutableIndexSet {
+    type Owned = Retained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        self.mutableCopy()
+    }
+}
+
+#[cfg(feature = "NSOrderedSet")]
+impl<ObjectType: Message> ToOwned for crate::NSOrderedSet<ObjectType> {
+    type Owned = Retained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        self.copy()
+    }
+}
+
+#[cfg(feature = "NSOrderedSet")]
+impl<ObjectType: Message> ToOwned for crate::NSMutableOrderedSet<ObjectType> {
+    type Owned = Retained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        self.mutableCopy()
+    }
+}
+
 #[cfg(feature = "NSSet")]
 impl<ObjectType: Message> ToOwned for crate::NSSet<ObjectType> {
     type Owned = Retained<Self>;
@@ -94,6 +160,22 @@ impl ToOwned for crate::NSMutableAttributedString {
     }
 }
 
+#[cfg(feature = "NSURLRequest")]
+impl ToOwned for crate::NSURLRequest {
+    type Owned = Retained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        self.copy()
+    }
+}
+
+#[cfg(feature = "NSURLRequest")]
+impl ToOwned for crate::NSMutableURLRequest {
+    type Owned = Retained<Self>;
+    fn to_owned(&self) -> Self::Owned {
+        self.mutableCopy()
+    }
+}
+
 #[cfg(feature = "NSUUID")]
 impl ToOwned for crate::NSUUID {
     type Owned = Retained<Self>;
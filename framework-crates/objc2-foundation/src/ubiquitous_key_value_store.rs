@@ -0,0 +1,194 @@
+//! Utilities for the `NSUbiquitousKeyValueStore` class.
+//!
+//! The iCloud key-value store has the same shape as `NSUserDefaults`, but
+//! its contents are synced between the user's devices. Changes that arrive
+//! from iCloud can be handled in a Rust closure with
+//! [`NSUbiquitousKeyValueStore::observe_external_changes`].
+//!
+//! Note that the store is only synced if the app has the
+//! `com.apple.developer.ubiquity-kvstore-identifier` entitlement.
+use alloc::vec::Vec;
+
+use objc2::rc::Retained;
+use objc2::runtime::AnyObject;
+use objc2::DowncastTarget;
+
+use crate::notification::Observer;
+use crate::{
+    NSArray, NSNotification, NSNotificationCenter, NSNumber, NSString, NSUbiquitousKeyValueStore,
+    NSUbiquitousKeyValueStoreChangeReasonKey, NSUbiquitousKeyValueStoreChangedKeysKey,
+    NSUbiquitousKeyValueStoreDidChangeExternallyNotification,
+};
+
+/// The reason for an [`ExternalChange`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ChangeReason {
+    /// Another device changed the values.
+    #[doc(alias = "NSUbiquitousKeyValueStoreServerChange")]
+    Server,
+    /// The values were downloaded for the first time, and replaced values
+    /// that were set locally before that.
+    #[doc(alias = "NSUbiquitousKeyValueStoreInitialSyncChange")]
+    InitialSync,
+    /// The store exceeded its quota, and some values were discarded.
+    #[doc(alias = "NSUbiquitousKeyValueStoreQuotaViolationChange")]
+    QuotaViolation,
+    /// The user changed their iCloud account, and the values were replaced
+    /// by those of the new account.
+    #[doc(alias = "NSUbiquitousKeyValueStoreAccountChange")]
+    Account,
+}
+
+impl ChangeReason {
+    fn from_raw(raw: isize) -> Option<Self> {
+        match raw {
+            0 => Some(Self::Server),
+            1 => Some(Self::InitialSync),
+            2 => Some(Self::QuotaViolation),
+            3 => Some(Self::Account),
+            _ => None,
+        }
+    }
+}
+
+/// A change to the values in an [`NSUbiquitousKeyValueStore`] that was
+/// made outside of the current process.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct ExternalChange {
+    /// Why the values changed, if known.
+    pub reason: Option<ChangeReason>,
+    /// The keys whose values changed.
+    pub changed_keys: Vec<Retained<NSString>>,
+}
+
+impl ExternalChange {
+    pub(crate) fn from_notification(notification: &NSNotification) -> Self {
+        let user_info = notification.userInfo();
+        let value = |key: &NSString| user_info.as_ref()?.objectForKey(key.as_ref());
+
+        // SAFETY: The statics are valid `NSString`s.
+        let (reason_key, changed_keys_key) = unsafe {
+            (
+                NSUbiquitousKeyValueStoreChangeReasonKey,
+                NSUbiquitousKeyValueStoreChangedKeysKey,
+            )
+        };
+        let reason = value(reason_key)
+            .and_then(|reason| reason.downcast::<NSNumber>().ok())
+            .and_then(|reason| ChangeReason::from_raw(reason.as_isize()));
+        // The key is documented to contain an array of strings, but check
+        // that instead of trusting the notification's sender.
+        let changed_keys = value(changed_keys_key)
+            .and_then(|keys| keys.downcast::<NSArray>().ok())
+            .map(|keys| {
+                keys.to_vec()
+                    .into_iter()
+                    .filter_map(|key| key.downcast::<NSString>().ok())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Self {
+            reason,
+            changed_keys,
+        }
+    }
+}
+
+impl NSUbiquitousKeyValueStore {
+    /// Look up the value for the given key, and attempt to downcast it to
+    /// the given type.
+    ///
+    /// Unlike the `...ForKey:` methods, this allows distinguishing between a
+    /// missing value (`None`), and a value of a different type
+    /// (`Some(None)`).
+    #[doc(alias = "objectForKey:")]
+    pub fn get<T: DowncastTarget>(&self, key: &NSString) -> Option<Option<Retained<T>>> {
+        let value: Retained<AnyObject> = self.objectForKey(key)?;
+        Some(value.downcast().ok())
+    }
+
+    /// Register a closure to be called when values in the store are changed
+    /// from iCloud.
+    ///
+    /// The closure may be called on any thread. The returned [`Observer`]
+    /// unregisters the closure when dropped.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2_foundation::NSUbiquitousKeyValueStore;
+    ///
+    /// let store = NSUbiquitousKeyValueStore::defaultStore();
+    /// let _observer = store.observe_external_changes(|store, change| {
+    ///     for key in &change.changed_keys {
+    ///         println!("{key} changed to {:?}", store.objectForKey(key));
+    ///     }
+    /// });
+    /// // Fetch the initial values.
+    /// store.synchronize();
+    /// ```
+    #[doc(alias = "NSUbiquitousKeyValueStoreDidChangeExternallyNotification")]
+    pub fn observe_external_changes(
+        &self,
+        f: impl Fn(&NSUbiquitousKeyValueStore, ExternalChange) + Send + Sync + 'static,
+    ) -> Observer {
+        let center = NSNotificationCenter::defaultCenter();
+        // SAFETY: The static is a valid notification name.
+        let name = unsafe { NSUbiquitousKeyValueStoreDidChangeExternallyNotification };
+        center.observe(Some(name), Some(self.as_ref()), move |notification| {
+            let Some(store) = notification.object() else {
+                return;
+            };
+            let Ok(store) = store.downcast::<NSUbiquitousKeyValueStore>() else {
+                return;
+            };
+            f(&store, ExternalChange::from_notification(notification));
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    #[cfg(target_vendor = "apple")]
+    fn change_from_notification() {
+        use alloc::string::ToString;
+
+        use super::*;
+        use crate::{ns_string, NSDictionary};
+
+        let name = unsafe { NSUbiquitousKeyValueStoreDidChangeExternallyNotification };
+        let keys = NSArray::from_slice(&[ns_string!("a"), ns_string!("b")]);
+        let reason = NSNumber::new_isize(1);
+        let user_info = NSDictionary::from_slices(
+            unsafe {
+                &[
+                    NSUbiquitousKeyValueStoreChangeReasonKey,
+                    NSUbiquitousKeyValueStoreChangedKeysKey,
+                ]
+            },
+            &[&*reason as &AnyObject, &*keys as &AnyObject],
+        );
+        // SAFETY: Erasing the generic types is fine.
+        let user_info = unsafe { Retained::cast_unchecked::<NSDictionary>(user_info) };
+        let notification =
+            NSNotification::notificationWithName_object_userInfo(name, None, Some(&user_info));
+
+        let change = ExternalChange::from_notification(&notification);
+        assert_eq!(change.reason, Some(ChangeReason::InitialSync));
+        let keys: Vec<_> = change
+            .changed_keys
+            .iter()
+            .map(|key| key.to_string())
+            .collect();
+        assert_eq!(keys, ["a", "b"]);
+
+        let notification = NSNotification::notificationWithName_object(name, None);
+        let change = ExternalChange::from_notification(&notification);
+        assert_eq!(change.reason, None);
+        assert!(change.changed_keys.is_empty());
+    }
+}
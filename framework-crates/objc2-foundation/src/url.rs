@@ -0,0 +1,186 @@
+//! Utilities for the `NSURL` class.
+//!
+//! To convert to and from the [`url`] crate's `Url` type, go through the
+//! absolute string representation:
+//!
+//! ```ignore
+//! use objc2_foundation::{NSString, NSURL};
+//! use url::Url;
+//!
+//! let url: Url;
+//! # url = todo!();
+//! let ns_url = NSURL::URLWithString(&NSString::from_str(url.as_str())).unwrap();
+//! let url = Url::parse(&ns_url.absoluteString().unwrap().to_string()).unwrap();
+//! ```
+//!
+//! [`url`]: https://docs.rs/url
+#[cfg(all(feature = "std", unix))]
+use core::ptr::NonNull;
+#[cfg(all(feature = "std", unix))]
+use std::{
+    ffi::{CStr, CString, OsStr},
+    os::unix::ffi::OsStrExt,
+    path::{Path, PathBuf},
+};
+
+#[cfg(all(feature = "std", unix))]
+use objc2::rc::{autoreleasepool, Retained};
+
+use crate::{NSURLComponents, NSURL};
+
+/// Conversions to and from file system paths.
+///
+/// These use the file system representation of the path directly, so unlike
+/// going through `NSString`, they also work for paths that are not valid
+/// UTF-8, or that use a different Unicode normalization form than the one
+/// that `NSString` would produce.
+#[cfg(all(feature = "std", unix))]
+impl NSURL {
+    fn from_path(path: &Path, is_directory: bool) -> Option<Retained<Self>> {
+        let path = CString::new(path.as_os_str().as_bytes()).ok()?;
+        let ptr = NonNull::new(path.as_ptr() as *mut _).unwrap();
+        // SAFETY: The pointer is a valid C string, which is copied by the
+        // URL before we free it.
+        Some(unsafe {
+            Self::fileURLWithFileSystemRepresentation_isDirectory_relativeToURL(
+                ptr,
+                is_directory,
+                None,
+            )
+        })
+    }
+
+    /// Create a file URL from the given path.
+    ///
+    /// Relative paths are resolved against the current working directory.
+    ///
+    /// Returns `None` if the path contains an interior NUL byte.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    /// use objc2_foundation::NSURL;
+    ///
+    /// let url = NSURL::from_file_path("/tmp/foo.txt").unwrap();
+    /// assert!(url.isFileURL());
+    /// assert_eq!(url.to_file_path().unwrap(), Path::new("/tmp/foo.txt"));
+    /// ```
+    #[doc(alias = "fileURLWithFileSystemRepresentation:isDirectory:relativeToURL:")]
+    pub fn from_file_path<P: AsRef<Path>>(path: P) -> Option<Retained<Self>> {
+        Self::from_path(path.as_ref(), false)
+    }
+
+    /// Create a file URL from the given path, treating it as a directory.
+    ///
+    /// This makes the URL end with a trailing slash, such that relative URLs
+    /// created against it resolve inside the directory.
+    ///
+    /// Returns `None` if the path contains an interior NUL byte.
+    #[doc(alias = "fileURLWithFileSystemRepresentation:isDirectory:relativeToURL:")]
+    pub fn from_directory_path<P: AsRef<Path>>(path: P) -> Option<Retained<Self>> {
+        Self::from_path(path.as_ref(), true)
+    }
+
+    /// Convert a file URL to a path.
+    ///
+    /// Returns `None` if the URL is not a file URL.
+    #[doc(alias = "fileSystemRepresentation")]
+    pub fn to_file_path(&self) -> Option<PathBuf> {
+        if !self.isFileURL() {
+            return None;
+        }
+        autoreleasepool(|_| {
+            let ptr = self.fileSystemRepresentation();
+            // SAFETY: `fileSystemRepresentation` returns a NUL-terminated C
+            // string that lives until the end of the autorelease pool, and
+            // we copy it before then.
+            let bytes = unsafe { CStr::from_ptr(ptr.as_ptr()) }.to_bytes();
+            Some(PathBuf::from(OsStr::from_bytes(bytes)))
+        })
+    }
+}
+
+/// Typed accessors.
+///
+/// Note that the plain accessors like [`setHost`] and [`setPath`] take care
+/// of percent-encoding for you, use the `percentEncoded` variants if you
+/// need to handle the encoding yourself.
+///
+/// [`setHost`]: Self::setHost
+/// [`setPath`]: Self::setPath
+impl NSURLComponents {
+    /// The port of the URL, if any.
+    #[cfg(feature = "NSValue")]
+    #[doc(alias = "port")]
+    pub fn port_number(&self) -> Option<u16> {
+        self.port().map(|port| port.as_u16())
+    }
+
+    /// Set or clear the port of the URL.
+    #[cfg(feature = "NSValue")]
+    #[doc(alias = "setPort:")]
+    pub fn set_port_number(&self, port: Option<u16>) {
+        let port = port.map(crate::NSNumber::new_u16);
+        self.setPort(port.as_deref());
+    }
+
+    /// Append a query item to the URL.
+    ///
+    /// The name and value are percent-encoded as needed.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSURLComponents};
+    ///
+    /// let components = NSURLComponents::new();
+    /// components.setScheme(Some(ns_string!("https")));
+    /// components.setHost(Some(ns_string!("example.com")));
+    /// components.setPath(Some(ns_string!("/search")));
+    /// components.append_query_item(ns_string!("q"), Some(ns_string!("a&b c")));
+    /// components.append_query_item(ns_string!("flag"), None);
+    ///
+    /// let url = components.URL().unwrap();
+    /// assert_eq!(
+    ///     url.absoluteString().unwrap().to_string(),
+    ///     "https://example.com/search?q=a%26b%20c&flag",
+    /// );
+    /// ```
+    #[cfg(feature = "NSArray")]
+    #[cfg(feature = "NSString")]
+    #[doc(alias = "setQueryItems:")]
+    pub fn append_query_item(&self, name: &crate::NSString, value: Option<&crate::NSString>) {
+        let item = crate::NSURLQueryItem::queryItemWithName_value(name, value);
+        let items = match self.queryItems() {
+            Some(items) => items.arrayByAddingObject(&item),
+            None => crate::NSArray::from_retained_slice(&[item]),
+        };
+        self.setQueryItems(Some(&items));
+    }
+
+    /// The name and value of each query item in the URL, in order.
+    ///
+    /// The names and values are percent-decoded.
+    #[cfg(feature = "NSArray")]
+    #[cfg(feature = "NSEnumerator")]
+    #[cfg(feature = "NSString")]
+    #[doc(alias = "queryItems")]
+    pub fn query_pairs(
+        &self,
+    ) -> alloc::vec::Vec<(
+        objc2::rc::Retained<crate::NSString>,
+        Option<objc2::rc::Retained<crate::NSString>>,
+    )> {
+        self.queryItems()
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|item| (item.name(), item.value()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
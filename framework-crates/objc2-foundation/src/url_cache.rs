@@ -0,0 +1,53 @@
+//! Utilities for the `NSURLCache` class.
+use core::panic::{RefUnwindSafe, UnwindSafe};
+
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSURLRequest")]
+#[cfg(feature = "NSURLResponse")]
+use objc2::{rc::Retained, AllocAnyThread};
+
+use crate::NSURLCache;
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSURLRequest")]
+#[cfg(feature = "NSURLResponse")]
+use crate::{NSCachedURLResponse, NSData, NSURLRequest, NSURLResponse};
+
+// SAFETY: `NSURLCache` is documented as thread safe since macOS 10.10 and
+// iOS 8.
+unsafe impl Send for NSURLCache {}
+unsafe impl Sync for NSURLCache {}
+
+impl UnwindSafe for NSURLCache {}
+impl RefUnwindSafe for NSURLCache {}
+
+/// Storing and looking up responses.
+#[cfg(feature = "NSData")]
+#[cfg(feature = "NSURLRequest")]
+#[cfg(feature = "NSURLResponse")]
+impl NSURLCache {
+    /// Store the response and data for the given request in the cache.
+    ///
+    /// This replaces any response that is already cached for the request.
+    ///
+    /// Note that the cache may decide not to store the response, for example
+    /// if it is larger than the cache's capacity.
+    #[doc(alias = "storeCachedResponse:forRequest:")]
+    pub fn store(&self, request: &NSURLRequest, response: &NSURLResponse, data: &NSData) {
+        let cached = NSCachedURLResponse::initWithResponse_data(
+            NSCachedURLResponse::alloc(),
+            response,
+            data,
+        );
+        self.storeCachedResponse_forRequest(&cached, request);
+    }
+
+    /// The cached response and data for the given request, if any.
+    #[doc(alias = "cachedResponseForRequest:")]
+    pub fn lookup(
+        &self,
+        request: &NSURLRequest,
+    ) -> Option<(Retained<NSURLResponse>, Retained<NSData>)> {
+        let cached = self.cachedResponseForRequest(request)?;
+        Some((cached.response(), cached.data()))
+    }
+}
@@ -0,0 +1,146 @@
+//! Security framework types used by `NSURLCredential` and
+//! `NSURLProtectionSpace`.
+//!
+//! The methods that take or return these types are not generated, since
+//! they come from the Security framework. They are implemented manually here
+//! in terms of opaque types, so that authentication challenges with client
+//! certificates or custom server trust evaluation can be handled in Rust.
+//!
+//! To work with the objects themselves, use a crate that binds the Security
+//! framework, and cast the pointers to its types.
+use core::cell::UnsafeCell;
+use core::fmt;
+use core::marker::{PhantomData, PhantomPinned};
+use core::ptr::NonNull;
+
+use objc2::encode::{Encoding, RefEncode};
+use objc2::extern_methods;
+use objc2::rc::{Allocated, Retained};
+
+#[cfg(feature = "NSArray")]
+use crate::NSArray;
+#[cfg(feature = "NSURLProtectionSpace")]
+use crate::NSURLProtectionSpace;
+use crate::{NSURLCredential, NSURLCredentialPersistence};
+
+/// An opaque `SecTrust`, used for evaluating the trust of a server's
+/// certificate chain.
+///
+/// A pointer to this is a `SecTrustRef`.
+#[repr(C)]
+pub struct SecTrust {
+    _priv: [u8; 0],
+    // Mark the type as !Send, !Sync and !Unpin.
+    _inner: PhantomData<(*const UnsafeCell<()>, PhantomPinned)>,
+}
+
+impl fmt::Debug for SecTrust {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<SecTrust {self:p}>")
+    }
+}
+
+// SAFETY: Matches the encoding of `SecTrustRef`.
+unsafe impl RefEncode for SecTrust {
+    const ENCODING_REF: Encoding = Encoding::Pointer(&Encoding::Struct("__SecTrust", &[]));
+}
+
+/// An opaque `SecIdentity`, consisting of a certificate and its private
+/// key, used for client certificate authentication.
+///
+/// A pointer to this is a `SecIdentityRef`.
+#[repr(C)]
+pub struct SecIdentity {
+    _priv: [u8; 0],
+    // Mark the type as !Send, !Sync and !Unpin.
+    _inner: PhantomData<(*const UnsafeCell<()>, PhantomPinned)>,
+}
+
+impl fmt::Debug for SecIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<SecIdentity {self:p}>")
+    }
+}
+
+// SAFETY: Matches the encoding of `SecIdentityRef`.
+unsafe impl RefEncode for SecIdentity {
+    const ENCODING_REF: Encoding = Encoding::Pointer(&Encoding::Struct("__SecIdentity", &[]));
+}
+
+extern_methods!(
+    unsafe impl NSURLCredential {
+        /// Create a credential for client certificate authentication.
+        ///
+        /// `certificates` are the intermediate certificates to send along
+        /// with the identity's certificate.
+        ///
+        /// # Safety
+        ///
+        /// `identity` must be a valid `SecIdentityRef`, and `certificates`
+        /// must only contain `SecCertificateRef`s.
+        #[cfg(feature = "NSArray")]
+        #[method_id(initWithIdentity:certificates:persistence:)]
+        pub unsafe fn initWithIdentity_certificates_persistence(
+            this: Allocated<Self>,
+            identity: NonNull<SecIdentity>,
+            certificates: Option<&NSArray>,
+            persistence: NSURLCredentialPersistence,
+        ) -> Retained<Self>;
+
+        /// Create a credential for client certificate authentication.
+        ///
+        /// # Safety
+        ///
+        /// `identity` must be a valid `SecIdentityRef`, and `certificates`
+        /// must only contain `SecCertificateRef`s.
+        #[cfg(feature = "NSArray")]
+        #[method_id(credentialWithIdentity:certificates:persistence:)]
+        pub unsafe fn credentialWithIdentity_certificates_persistence(
+            identity: NonNull<SecIdentity>,
+            certificates: Option<&NSArray>,
+            persistence: NSURLCredentialPersistence,
+        ) -> Retained<Self>;
+
+        /// Create a credential that accepts the given server trust.
+        ///
+        /// # Safety
+        ///
+        /// `trust` must be a valid `SecTrustRef`.
+        #[method_id(initWithTrust:)]
+        pub unsafe fn initWithTrust(
+            this: Allocated<Self>,
+            trust: NonNull<SecTrust>,
+        ) -> Retained<Self>;
+
+        /// Create a credential that accepts the given server trust.
+        ///
+        /// This is usually passed to the completion handler of a server
+        /// trust authentication challenge, after having evaluated
+        /// [`NSURLProtectionSpace::serverTrust`].
+        ///
+        /// # Safety
+        ///
+        /// `trust` must be a valid `SecTrustRef`.
+        #[method_id(credentialForTrust:)]
+        pub unsafe fn credentialForTrust(trust: NonNull<SecTrust>) -> Retained<Self>;
+
+        /// The identity of a client certificate credential.
+        ///
+        /// This is `None` if the credential was not created with an
+        /// identity. The returned pointer is not retained, and is only valid
+        /// for as long as the credential is alive.
+        #[method(identity)]
+        pub fn identity(&self) -> Option<NonNull<SecIdentity>>;
+    }
+
+    #[cfg(feature = "NSURLProtectionSpace")]
+    unsafe impl NSURLProtectionSpace {
+        /// The trust of the server, if this protection space is for
+        /// `NSURLAuthenticationMethodServerTrust`.
+        ///
+        /// The returned pointer is not retained, and is only valid for as
+        /// long as the protection space is alive.
+        #[method(serverTrust)]
+        pub fn serverTrust(&self) -> Option<NonNull<SecTrust>>;
+    }
+);
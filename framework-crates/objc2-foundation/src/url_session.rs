@@ -0,0 +1,452 @@
+//! Utilities for the `NSURLSession` class.
+//!
+//! The completion handler based methods on `NSURLSession` are bridged to
+//! Rust futures. The futures do not depend on any particular executor; they
+//! are woken from the session's delegate queue when the task completes.
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+use std::sync::Mutex;
+
+use objc2::rc::Retained;
+
+use crate::{
+    NSData, NSError, NSURLErrorBadServerResponse, NSURLErrorDomain, NSURLRequest, NSURLResponse,
+    NSURLSession, NSURLSessionDataTask,
+};
+
+type DataTaskResult = Result<(Retained<NSData>, Retained<NSURLResponse>), Retained<NSError>>;
+
+#[derive(Default)]
+struct Shared {
+    /// The received data, set once the task completes.
+    ///
+    /// Only the bytes are handed over from the delegate queue, since the
+    /// objects passed to the completion handler are not thread-safe. The
+    /// response and error are read from the task itself instead.
+    data: Option<Vec<u8>>,
+    waker: Option<Waker>,
+}
+
+impl NSURLSession {
+    /// Start a data task for the given request.
+    ///
+    /// The returned future resolves to the downloaded data and the response
+    /// once the task completes. Dropping the future before then cancels the
+    /// task.
+    ///
+    /// Note that a HTTP error status such as 404 is not an error at this
+    /// level; check `NSHTTPURLResponse::statusCode` for that. If the task
+    /// completes without a response, the future resolves to an error with
+    /// the code `NSURLErrorBadServerResponse`.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2_foundation::{NSURLRequest, NSURLSession, NSURL, NSString};
+    ///
+    /// # async fn example() -> Result<(), objc2::rc::Retained<objc2_foundation::NSError>> {
+    /// let url = NSURL::URLWithString(&NSString::from_str("https://example.com")).unwrap();
+    /// let request = NSURLRequest::requestWithURL(&url);
+    /// let (data, response) = NSURLSession::sharedSession().data_task(&request).await?;
+    /// println!("{:?}: {} bytes", response.MIMEType(), data.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "dataTaskWithRequest:completionHandler:")]
+    pub fn data_task(&self, request: &NSURLRequest) -> DataTask {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let handler_shared = Arc::clone(&shared);
+        let block = block2::RcBlock::new(
+            move |data: *mut NSData, _response: *mut NSURLResponse, _error: *mut NSError| {
+                // SAFETY: The pointer is either NULL or a valid object.
+                let data = unsafe { data.as_ref() }.map(NSData::to_vec);
+                let mut shared = handler_shared.lock().unwrap();
+                shared.data = Some(data.unwrap_or_default());
+                if let Some(waker) = shared.waker.take() {
+                    waker.wake();
+                }
+            },
+        );
+        // SAFETY: The block is `'static`, and only accesses thread-safe
+        // state.
+        let task = unsafe { self.dataTaskWithRequest_completionHandler(request, &block) };
+        task.resume();
+        DataTask {
+            task,
+            shared,
+            done: false,
+        }
+    }
+}
+
+/// A future for a running data task.
+///
+/// Created with [`NSURLSession::data_task`].
+#[must_use = "futures do nothing unless polled, and dropping the future cancels the task"]
+pub struct DataTask {
+    task: Retained<NSURLSessionDataTask>,
+    shared: Arc<Mutex<Shared>>,
+    done: bool,
+}
+
+impl DataTask {
+    /// The underlying task.
+    ///
+    /// This can be used to inspect the progress of the task, or to suspend
+    /// and resume it.
+    pub fn task(&self) -> &NSURLSessionDataTask {
+        &self.task
+    }
+}
+
+impl Future for DataTask {
+    type Output = DataTaskResult;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mut shared = this.shared.lock().unwrap();
+        match shared.data.take() {
+            Some(data) => {
+                this.done = true;
+                let result = match (this.task.error(), this.task.response()) {
+                    (Some(error), _) => Err(error),
+                    (None, Some(response)) => Ok((NSData::from_vec(data), response)),
+                    // Shouldn't happen, but report it as a bad response
+                    // instead of panicking.
+                    (None, None) => {
+                        // SAFETY: The static is a valid error domain.
+                        let domain = unsafe { NSURLErrorDomain };
+                        Err(NSError::new(NSURLErrorBadServerResponse, domain))
+                    }
+                };
+                Poll::Ready(result)
+            }
+            None => {
+                shared.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for DataTask {
+    fn drop(&mut self) {
+        if !self.done {
+            self.task.cancel();
+        }
+    }
+}
+
+impl fmt::Debug for DataTask {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DataTask")
+            .field("task", &self.task)
+            .field("done", &self.done)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "NSFileManager")]
+#[cfg(feature = "NSURL")]
+pub use self::transfer::{TransferEvent, TransferTask};
+
+#[cfg(feature = "NSFileManager")]
+#[cfg(feature = "NSURL")]
+mod transfer {
+    use core::fmt;
+    use core::task::{Context, Poll, Waker};
+    use std::sync::Mutex;
+
+    use objc2::rc::Retained;
+    use objc2::runtime::{NSObject, NSObjectProtocol, ProtocolObject};
+    use objc2::{define_class, msg_send_id, AllocAnyThread, DefinedClass, Message};
+
+    use crate::{
+        NSData, NSError, NSFileManager, NSURLRequest, NSURLSession, NSURLSessionDelegate,
+        NSURLSessionDownloadDelegate, NSURLSessionDownloadTask, NSURLSessionTask,
+        NSURLSessionTaskDelegate, NSURL,
+    };
+
+    /// An event emitted by a [`TransferTask`].
+    #[derive(Debug)]
+    pub enum TransferEvent {
+        /// Data was sent or received.
+        Progress {
+            /// The number of bytes transferred so far.
+            transferred: u64,
+            /// The total number of bytes expected to be transferred, if
+            /// known.
+            expected: Option<u64>,
+        },
+        /// The transfer finished.
+        ///
+        /// The response can be retrieved with `task().response()`.
+        Completed(Result<(), Retained<NSError>>),
+    }
+
+    #[derive(Default)]
+    struct Shared {
+        /// Only the latest progress is kept, to avoid buffering events when
+        /// the stream isn't polled.
+        progress: Option<(u64, Option<u64>)>,
+        completion: Option<Result<(), Retained<NSError>>>,
+        move_error: Option<Retained<NSError>>,
+        waker: Option<Waker>,
+    }
+
+    struct Ivars {
+        shared: Mutex<Shared>,
+        destination: Option<Retained<NSURL>>,
+    }
+
+    define_class!(
+        // SAFETY:
+        // - The superclass NSObject does not have any subclassing requirements.
+        // - `TransferDelegate` does not implement `Drop`.
+        #[unsafe(super(NSObject))]
+        #[name = "Objc2TransferDelegate"]
+        #[ivars = Ivars]
+        struct TransferDelegate;
+
+        unsafe impl NSObjectProtocol for TransferDelegate {}
+
+        unsafe impl NSURLSessionDelegate for TransferDelegate {}
+
+        unsafe impl NSURLSessionTaskDelegate for TransferDelegate {
+            #[method(URLSession:task:didSendBodyData:totalBytesSent:totalBytesExpectedToSend:)]
+            fn did_send_body_data(
+                &self,
+                _session: &NSURLSession,
+                _task: &NSURLSessionTask,
+                _bytes_sent: i64,
+                total_bytes_sent: i64,
+                total_bytes_expected: i64,
+            ) {
+                self.progress(total_bytes_sent, total_bytes_expected);
+            }
+
+            #[method(URLSession:task:didCompleteWithError:)]
+            fn did_complete(
+                &self,
+                _session: &NSURLSession,
+                _task: &NSURLSessionTask,
+                error: Option<&NSError>,
+            ) {
+                let mut shared = self.ivars().shared.lock().unwrap();
+                let result = match (error, shared.move_error.take()) {
+                    (Some(error), _) => Err(error.retain()),
+                    (None, Some(move_error)) => Err(move_error),
+                    (None, None) => Ok(()),
+                };
+                shared.completion = Some(result);
+                if let Some(waker) = shared.waker.take() {
+                    waker.wake();
+                }
+            }
+        }
+
+        unsafe impl NSURLSessionDownloadDelegate for TransferDelegate {
+            #[method(URLSession:downloadTask:didWriteData:totalBytesWritten:totalBytesExpectedToWrite:)]
+            fn did_write_data(
+                &self,
+                _session: &NSURLSession,
+                _task: &NSURLSessionDownloadTask,
+                _bytes_written: i64,
+                total_bytes_written: i64,
+                total_bytes_expected: i64,
+            ) {
+                self.progress(total_bytes_written, total_bytes_expected);
+            }
+
+            #[method(URLSession:downloadTask:didFinishDownloadingToURL:)]
+            fn did_finish_downloading(
+                &self,
+                _session: &NSURLSession,
+                _task: &NSURLSessionDownloadTask,
+                location: &NSURL,
+            ) {
+                // The temporary file is removed when this method returns, so
+                // it must be moved into place immediately.
+                if let Some(destination) = &self.ivars().destination {
+                    let manager = NSFileManager::defaultManager();
+                    let _ = manager.removeItemAtURL_error(destination);
+                    if let Err(error) = manager.moveItemAtURL_toURL_error(location, destination) {
+                        self.ivars().shared.lock().unwrap().move_error = Some(error);
+                    }
+                }
+            }
+        }
+    );
+
+    impl TransferDelegate {
+        fn new(destination: Option<Retained<NSURL>>) -> Retained<Self> {
+            let this = Self::alloc().set_ivars(Ivars {
+                shared: Mutex::new(Shared::default()),
+                destination,
+            });
+            unsafe { msg_send_id![super(this), init] }
+        }
+
+        fn progress(&self, transferred: i64, expected: i64) {
+            let mut shared = self.ivars().shared.lock().unwrap();
+            // `NSURLSessionTransferSizeUnknown` is -1.
+            shared.progress = Some((
+                u64::try_from(transferred).unwrap_or(0),
+                u64::try_from(expected).ok(),
+            ));
+            if let Some(waker) = shared.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    /// Download and upload tasks.
+    ///
+    /// Progress is reported through a delegate set on the individual task,
+    /// which requires macOS 12.0 / iOS 15.0 or above.
+    impl NSURLSession {
+        /// Start a task that downloads the given request to a file.
+        ///
+        /// Any existing file at `destination` is replaced once the download
+        /// has finished.
+        #[doc(alias = "downloadTaskWithRequest:")]
+        pub fn download_task(&self, request: &NSURLRequest, destination: &NSURL) -> TransferTask {
+            let task = self.downloadTaskWithRequest(request);
+            TransferTask::start(Retained::into_super(task), Some(destination.retain()))
+        }
+
+        /// Start a task that uploads the given data as the body of the
+        /// request.
+        #[doc(alias = "uploadTaskWithRequest:fromData:")]
+        pub fn upload_task(&self, request: &NSURLRequest, body: &NSData) -> TransferTask {
+            let task = self.uploadTaskWithRequest_fromData(request, body);
+            TransferTask::start(Retained::into_super(Retained::into_super(task)), None)
+        }
+
+        /// Start a task that uploads the file at the given URL as the body
+        /// of the request.
+        #[doc(alias = "uploadTaskWithRequest:fromFile:")]
+        pub fn upload_file_task(&self, request: &NSURLRequest, file: &NSURL) -> TransferTask {
+            let task = self.uploadTaskWithRequest_fromFile(request, file);
+            TransferTask::start(Retained::into_super(Retained::into_super(task)), None)
+        }
+    }
+
+    /// A running download or upload task.
+    ///
+    /// The progress of the task can be observed by polling it for
+    /// [`TransferEvent`]s with [`poll_event`], or, with the `"futures-core"`
+    /// feature enabled, by using it as a [`Stream`]. The last event is always
+    /// [`TransferEvent::Completed`].
+    ///
+    /// Dropping the task before it has completed cancels it.
+    ///
+    /// Created with [`NSURLSession::download_task`],
+    /// [`NSURLSession::upload_task`] or [`NSURLSession::upload_file_task`].
+    ///
+    /// [`poll_event`]: Self::poll_event
+    /// [`Stream`]: https://docs.rs/futures-core/latest/futures_core/stream/trait.Stream.html
+    #[must_use = "dropping the task cancels it"]
+    pub struct TransferTask {
+        task: Retained<NSURLSessionTask>,
+        delegate: Retained<TransferDelegate>,
+        done: bool,
+    }
+
+    impl TransferTask {
+        fn start(task: Retained<NSURLSessionTask>, destination: Option<Retained<NSURL>>) -> Self {
+            let delegate = TransferDelegate::new(destination);
+            // SAFETY: The delegate is retained by the task until it
+            // completes, and we keep it alive for as long as the task too.
+            unsafe { task.setDelegate(Some(ProtocolObject::from_ref(&*delegate))) };
+            task.resume();
+            Self {
+                task,
+                delegate,
+                done: false,
+            }
+        }
+
+        /// The underlying task.
+        pub fn task(&self) -> &NSURLSessionTask {
+            &self.task
+        }
+
+        /// Cancel the task.
+        ///
+        /// The task completes with an `NSURLErrorCancelled` error.
+        #[doc(alias = "cancel")]
+        pub fn cancel(&self) {
+            self.task.cancel();
+        }
+
+        /// Temporarily suspend the task.
+        #[doc(alias = "suspend")]
+        pub fn suspend(&self) {
+            self.task.suspend();
+        }
+
+        /// Resume a suspended task.
+        #[doc(alias = "resume")]
+        pub fn resume(&self) {
+            self.task.resume();
+        }
+
+        /// Poll for the next event.
+        ///
+        /// Returns `Poll::Ready(None)` once the completion event has been
+        /// returned.
+        pub fn poll_event(&mut self, cx: &mut Context<'_>) -> Poll<Option<TransferEvent>> {
+            if self.done {
+                return Poll::Ready(None);
+            }
+            let mut shared = self.delegate.ivars().shared.lock().unwrap();
+            if let Some((transferred, expected)) = shared.progress.take() {
+                return Poll::Ready(Some(TransferEvent::Progress {
+                    transferred,
+                    expected,
+                }));
+            }
+            if let Some(result) = shared.completion.take() {
+                self.done = true;
+                return Poll::Ready(Some(TransferEvent::Completed(result)));
+            }
+            shared.waker = Some(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    #[cfg(feature = "futures-core")]
+    impl futures_core::Stream for TransferTask {
+        type Item = TransferEvent;
+
+        fn poll_next(
+            self: core::pin::Pin<&mut Self>,
+            cx: &mut Context<'_>,
+        ) -> Poll<Option<TransferEvent>> {
+            self.get_mut().poll_event(cx)
+        }
+    }
+
+    impl Drop for TransferTask {
+        fn drop(&mut self) {
+            if !self.done {
+                self.task.cancel();
+            }
+        }
+    }
+
+    impl fmt::Debug for TransferTask {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_struct("TransferTask")
+                .field("task", &self.task)
+                .field("done", &self.done)
+                .finish_non_exhaustive()
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Utilities for the `NSUserDefaults` class.
+use objc2::rc::Retained;
+use objc2::{AllocAnyThread, DowncastTarget};
+
+use crate::{NSString, NSUserDefaults};
+
+/// Convenience methods.
+impl NSUserDefaults {
+    /// The user defaults for the given suite, such as an app group shared
+    /// with other apps or extensions.
+    ///
+    /// Returns `None` if the suite name is invalid, for example if it is the
+    /// same as the app's own bundle identifier.
+    #[doc(alias = "initWithSuiteName:")]
+    pub fn suite(name: &NSString) -> Option<Retained<Self>> {
+        Self::initWithSuiteName(Self::alloc(), Some(name))
+    }
+
+    /// Look up the value for the given key, and attempt to downcast it to
+    /// the given type.
+    ///
+    /// Unlike the `...ForKey:` methods, this allows distinguishing between a
+    /// missing value (`None`), and a value of a different type
+    /// (`Some(None)`).
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSNumber, NSString, NSUserDefaults};
+    ///
+    /// let defaults = NSUserDefaults::suite(ns_string!("com.example.objc2-doctest")).unwrap();
+    /// defaults.setInteger_forKey(42, ns_string!("answer"));
+    ///
+    /// let value = defaults.get::<NSNumber>(ns_string!("answer"));
+    /// assert_eq!(value.unwrap().unwrap().as_i64(), 42);
+    /// assert!(defaults.get::<NSString>(ns_string!("answer")).unwrap().is_none());
+    ///
+    /// defaults.removeObjectForKey(ns_string!("answer"));
+    /// assert!(defaults.get::<NSNumber>(ns_string!("answer")).is_none());
+    /// # defaults.removePersistentDomainForName(ns_string!("com.example.objc2-doctest"));
+    /// ```
+    #[doc(alias = "objectForKey:")]
+    pub fn get<T: DowncastTarget>(&self, key: &NSString) -> Option<Option<Retained<T>>> {
+        let value = self.objectForKey(key)?;
+        Some(value.downcast().ok())
+    }
+}
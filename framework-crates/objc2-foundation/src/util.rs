@@ -4,9 +4,13 @@ use core::{fmt, ptr::NonNull};
 use objc2::runtime::__nsstring::nsstring_to_str;
 use objc2::{
     rc::{autoreleasepool_leaking, Retained},
-    runtime::NSObject,
+    runtime::{AnyObject, NSObject},
 };
 
+/// `NSNotFound`, returned as the index or location when something isn't
+/// found, and by `NSIndexSet` to signal that there are no more indexes.
+pub(crate) const NOT_FOUND: usize = isize::MAX as usize;
+
 pub(crate) fn retained_ptr_cast<T: ?Sized>(objects: *mut Retained<T>) -> *mut NonNull<T> {
     // SAFETY: `Retained<T>` has the same memory layout as `NonNull<T>`, and
     // stronger guarantees.
@@ -23,6 +27,54 @@ pub(crate) fn retained_ptr_cast_const<T: ?Sized>(objects: *const Retained<T>) ->
     retained_ptr_cast(objects as *mut Retained<T>)
 }
 
+/// Get a pointer to the string's internal UTF-8 storage, if it has one.
+///
+/// # Safety
+///
+/// The string must be an instance of `NSString`, and must not be mutated
+/// while the returned string is alive.
+#[cfg(target_vendor = "apple")]
+pub(crate) unsafe fn string_as_str_no_copy(string: &NSObject) -> Option<&str> {
+    use core::ffi::{c_char, CStr};
+
+    type CFStringEncoding = u32;
+    #[allow(non_upper_case_globals)]
+    // https://developer.apple.com/documentation/corefoundation/cfstringbuiltinencodings/kcfstringencodingutf8?language=objc
+    const kCFStringEncodingUTF8: CFStringEncoding = 0x08000100;
+    extern "C" {
+        // https://developer.apple.com/documentation/corefoundation/1542133-cfstringgetcstringptr?language=objc
+        fn CFStringGetCStringPtr(s: &NSObject, encoding: CFStringEncoding) -> *const c_char;
+        // https://developer.apple.com/documentation/corefoundation/1542853-cfstringgetlength?language=objc
+        fn CFStringGetLength(s: &NSObject) -> isize;
+    }
+
+    // SAFETY: `NSString` is toll-free bridged with `CFString`, and custom
+    // subclasses are handled by CoreFoundation dispatching back to
+    // Objective-C (where this always returns NULL).
+    let ptr = unsafe { CFStringGetCStringPtr(string, kCFStringEncodingUTF8) };
+    if ptr.is_null() {
+        return None;
+    }
+    // The returned pointer is to a NUL-terminated C string in the requested
+    // encoding. We can't rely on the string's length, since that is in
+    // UTF-16 code units, which only matches the number of bytes for ASCII.
+    //
+    // SAFETY: The pointer is valid and NUL-terminated, and the caller
+    // upholds that the string is not mutated while the slice is alive.
+    let bytes = unsafe { CStr::from_ptr(ptr) }.to_bytes();
+    // UTF-8 was requested, but we check to be sure.
+    let s = core::str::from_utf8(bytes).ok()?;
+    // If the string contains a NUL character, the C string stops early. The
+    // string's length is in UTF-16 code units, so compare with that.
+    //
+    // SAFETY: Same as above.
+    let len = unsafe { CFStringGetLength(string) } as usize;
+    if s.len() != len && s.encode_utf16().count() != len {
+        return None;
+    }
+    Some(s)
+}
+
 /// Display the string.
 ///
 /// Put here to allow using it without the `"NSString"` feature being active.
@@ -31,6 +83,28 @@ pub(crate) fn retained_ptr_cast_const<T: ?Sized>(objects: *const Retained<T>) ->
 ///
 /// The string must be an instance of `NSString`.
 pub(crate) unsafe fn display_string(string: &NSObject, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // Fast path: Copy short strings out of the internal storage and into a
+    // stack buffer. The copy is needed since the formatter may mutate the
+    // string while we write (if it is a `NSMutableString`).
+    #[cfg(target_vendor = "apple")]
+    {
+        let mut buf = [0u8; 128];
+        // SAFETY: The caller ensures that the object is an instance of
+        // `NSString`, and no other code runs until we're done copying.
+        let len = match unsafe { string_as_str_no_copy(string) } {
+            Some(s) if s.len() <= buf.len() => {
+                buf[..s.len()].copy_from_slice(s.as_bytes());
+                Some(s.len())
+            }
+            _ => None,
+        };
+        if let Some(len) = len {
+            // SAFETY: The bytes were copied from a valid `str`.
+            let s = unsafe { core::str::from_utf8_unchecked(&buf[..len]) };
+            return fmt::Display::fmt(s, f);
+        }
+    }
+
     // SAFETY:
     // - The caller upholds that the object is a `NSString`.
     // - We control the scope in which the string is alive, so we know
@@ -45,3 +119,53 @@ pub(crate) unsafe fn display_string(string: &NSObject, f: &mut fmt::Formatter<'_
     // somehow check that the string is immutable?).
     autoreleasepool_leaking(|pool| fmt::Display::fmt(unsafe { nsstring_to_str(string, pool) }, f))
 }
+
+/// Abort the process if dropped while unwinding.
+///
+/// Used to guard closures that are called from Foundation's worker threads,
+/// where unwinding out of the block is not allowed.
+pub(crate) struct AbortOnUnwind;
+
+impl Drop for AbortOnUnwind {
+    #[inline]
+    fn drop(&mut self) {
+        // Panicking while already panicking aborts the process.
+        panic!("closure panicked during concurrent enumeration");
+    }
+}
+
+impl AbortOnUnwind {
+    /// Run the closure, aborting if it panics.
+    #[inline]
+    pub(crate) fn call<R>(f: impl FnOnce() -> R) -> R {
+        let guard = Self;
+        let ret = f();
+        core::mem::forget(guard);
+        ret
+    }
+}
+
+/// Make the object hold a strong reference to its delegate.
+///
+/// Many classes only keep an unretained (`assign`) reference to their
+/// delegate, which would dangle if the delegate was deallocated first. We
+/// store the delegate as an associated object, so that it stays alive for as
+/// long as the object does. This replaces any delegate that was previously
+/// stored this way.
+pub(crate) fn retain_delegate(object: &AnyObject, delegate: &AnyObject) {
+    // Only the address is used, as a unique key.
+    static KEY: u8 = 0;
+    let object: *const AnyObject = object;
+    let delegate: *const AnyObject = delegate;
+    // SAFETY: The object and the delegate are valid, and the association
+    // retains the delegate until the object is deallocated, or until the
+    // association is replaced.
+    unsafe {
+        objc2::ffi::objc_setAssociatedObject(
+            object.cast_mut(),
+            core::ptr::addr_of!(KEY).cast(),
+            delegate.cast_mut(),
+            objc2::ffi::OBJC_ASSOCIATION_RETAIN,
+        )
+    }
+}
@@ -60,16 +60,41 @@ impl NSUUID {
     /// let obj = NSUUID::from_bytes(uuid.into_bytes());
     /// assert_eq!(obj.as_bytes(), uuid.into_bytes());
     /// ```
+    #[doc(alias = "initWithUUIDBytes:")]
     pub fn from_bytes(bytes: [u8; 16]) -> Retained<Self> {
         let bytes = UuidBytes(bytes);
         Self::initWithUUIDBytes(Self::alloc(), &bytes)
     }
 
+    /// Parse a `NSUUID` from its string representation, such as
+    /// `"E621E1F8-C36C-495A-93FC-0C247A3E6E5F"`.
+    ///
+    /// Returns `None` if the string is not a valid UUID.
+    ///
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use objc2_foundation::{ns_string, NSUUID};
+    ///
+    /// let uuid = NSUUID::from_string(ns_string!("E621E1F8-C36C-495A-93FC-0C247A3E6E5F")).unwrap();
+    /// assert_eq!(uuid.to_string(), "E621E1F8-C36C-495A-93FC-0C247A3E6E5F");
+    ///
+    /// assert!(NSUUID::from_string(ns_string!("not a uuid")).is_none());
+    /// ```
     #[cfg(feature = "NSString")]
+    #[doc(alias = "initWithUUIDString:")]
     pub fn from_string(string: &crate::NSString) -> Option<Retained<Self>> {
         Self::initWithUUIDString(Self::alloc(), string)
     }
 
+    /// The raw bytes of the UUID.
+    ///
+    /// This is the inverse of [`from_bytes`], and can be used to convert to
+    /// the `uuid` crate's `Uuid` with `Uuid::from_bytes`.
+    ///
+    /// [`from_bytes`]: Self::from_bytes
+    #[doc(alias = "getUUIDBytes:")]
     pub fn as_bytes(&self) -> [u8; 16] {
         let mut bytes = UuidBytes([0; 16]);
         self.getUUIDBytes(&mut bytes);
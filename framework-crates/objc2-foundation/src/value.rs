@@ -46,6 +46,54 @@ impl NSValue {
             )
         }
     }
+
+    /// Create a new `NSValue` containing the given range.
+    ///
+    /// The value can be retrieved again with [`get_range`].
+    ///
+    /// [`get_range`]: Self::get_range
+    #[cfg(feature = "NSRange")]
+    #[doc(alias = "valueWithRange:")]
+    #[inline]
+    pub fn new_range(range: crate::NSRange) -> Retained<Self> {
+        Self::new(range)
+    }
+
+    /// Create a new `NSValue` containing the given point.
+    ///
+    /// The value can be retrieved again with [`get_point`].
+    ///
+    /// [`get_point`]: Self::get_point
+    #[cfg(feature = "NSGeometry")]
+    #[doc(alias = "valueWithPoint:")]
+    #[inline]
+    pub fn new_point(point: crate::NSPoint) -> Retained<Self> {
+        Self::new(point)
+    }
+
+    /// Create a new `NSValue` containing the given size.
+    ///
+    /// The value can be retrieved again with [`get_size`].
+    ///
+    /// [`get_size`]: Self::get_size
+    #[cfg(feature = "NSGeometry")]
+    #[doc(alias = "valueWithSize:")]
+    #[inline]
+    pub fn new_size(size: crate::NSSize) -> Retained<Self> {
+        Self::new(size)
+    }
+
+    /// Create a new `NSValue` containing the given rectangle.
+    ///
+    /// The value can be retrieved again with [`get_rect`].
+    ///
+    /// [`get_rect`]: Self::get_rect
+    #[cfg(feature = "NSGeometry")]
+    #[doc(alias = "valueWithRect:")]
+    #[inline]
+    pub fn new_rect(rect: crate::NSRect) -> Retained<Self> {
+        Self::new(rect)
+    }
 }
 
 /// Getter methods.
@@ -104,6 +152,41 @@ impl NSValue {
         unsafe { value.assume_init() }
     }
 
+    /// Retrieve the data contained in the `NSValue`, if its encoding matches
+    /// the encoding of `T`.
+    ///
+    /// Unlike [`get`], this always checks the encoding, and returns `None`
+    /// instead of reading the value if it does not match.
+    ///
+    /// [`get`]: Self::get
+    ///
+    ///
+    /// # Safety
+    ///
+    /// Same as [`get`], except that the encoding is checked for you. Any
+    /// safety invariants that the value has must still be upheld.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::NSValue;
+    ///
+    /// let val = NSValue::new(42i32);
+    /// // SAFETY: `i32` has no safety invariants
+    /// assert_eq!(unsafe { val.try_get::<i32>() }, Some(42));
+    /// assert_eq!(unsafe { val.try_get::<u64>() }, None);
+    /// ```
+    pub unsafe fn try_get<T: 'static + Copy + Encode>(&self) -> Option<T> {
+        if self.contains_encoding::<T>() {
+            // SAFETY: The encoding was just checked, and the caller upholds
+            // the remaining invariants.
+            Some(unsafe { self.get() })
+        } else {
+            None
+        }
+    }
+
     #[cfg(feature = "NSRange")]
     pub fn get_range(&self) -> Option<crate::NSRange> {
         if self.contains_encoding::<crate::NSRange>() {
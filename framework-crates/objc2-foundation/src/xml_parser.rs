@@ -0,0 +1,228 @@
+//! Utilities for the `NSXMLParser` class.
+//!
+//! `NSXMLParser` is an event-driven parser, which reports the contents of
+//! the document to a delegate. Implement [`ParserDelegate`] and pass it to
+//! [`NSXMLParser::parse_with`] to handle these events in Rust.
+use core::cell::Cell;
+use core::ptr::NonNull;
+
+use objc2::rc::Retained;
+use objc2::runtime::{NSObject, NSObjectProtocol, ProtocolObject};
+use objc2::{define_class, msg_send_id, AllocAnyThread, DefinedClass};
+
+use crate::{NSData, NSDictionary, NSError, NSString, NSXMLParser, NSXMLParserDelegate};
+
+/// Handler for the events emitted by an [`NSXMLParser`].
+///
+/// All methods have a default implementation that does nothing.
+///
+/// The parser is passed to each method, so that e.g. the current line
+/// number can be inspected, or parsing stopped with `abortParsing`.
+#[allow(unused_variables)]
+pub trait ParserDelegate {
+    /// The parser began parsing the document.
+    #[doc(alias = "parserDidStartDocument:")]
+    fn did_start_document(&mut self, parser: &NSXMLParser) {}
+
+    /// The parser finished parsing the document successfully.
+    #[doc(alias = "parserDidEndDocument:")]
+    fn did_end_document(&mut self, parser: &NSXMLParser) {}
+
+    /// The parser found the start tag of an element.
+    ///
+    /// The namespace URI and qualified name are only reported if
+    /// `shouldProcessNamespaces` is enabled.
+    #[doc(alias = "parser:didStartElement:namespaceURI:qualifiedName:attributes:")]
+    fn did_start_element(
+        &mut self,
+        parser: &NSXMLParser,
+        element_name: &NSString,
+        namespace_uri: Option<&NSString>,
+        qualified_name: Option<&NSString>,
+        attributes: &NSDictionary<NSString, NSString>,
+    ) {
+    }
+
+    /// The parser found the end tag of an element.
+    #[doc(alias = "parser:didEndElement:namespaceURI:qualifiedName:")]
+    fn did_end_element(
+        &mut self,
+        parser: &NSXMLParser,
+        element_name: &NSString,
+        namespace_uri: Option<&NSString>,
+        qualified_name: Option<&NSString>,
+    ) {
+    }
+
+    /// The parser found characters inside the current element.
+    ///
+    /// The text of an element may be reported in several chunks.
+    #[doc(alias = "parser:foundCharacters:")]
+    fn found_characters(&mut self, parser: &NSXMLParser, string: &NSString) {}
+
+    /// The parser found a CDATA block.
+    #[doc(alias = "parser:foundCDATA:")]
+    fn found_cdata(&mut self, parser: &NSXMLParser, cdata: &NSData) {}
+
+    /// The parser encountered a fatal error.
+    ///
+    /// The error is also returned from [`NSXMLParser::parse_with`].
+    #[doc(alias = "parser:parseErrorOccurred:")]
+    fn parse_error_occurred(&mut self, parser: &NSXMLParser, error: &NSError) {}
+}
+
+type DelegatePtr = NonNull<dyn ParserDelegate + 'static>;
+
+define_class!(
+    // SAFETY:
+    // - The superclass NSObject does not have any subclassing requirements.
+    // - `RustParserDelegate` does not implement `Drop`.
+    #[unsafe(super(NSObject))]
+    #[name = "Objc2XMLParserDelegate"]
+    #[ivars = Cell<Option<DelegatePtr>>]
+    struct RustParserDelegate;
+
+    unsafe impl NSObjectProtocol for RustParserDelegate {}
+
+    unsafe impl NSXMLParserDelegate for RustParserDelegate {
+        #[method(parserDidStartDocument:)]
+        fn parser_did_start_document(&self, parser: &NSXMLParser) {
+            self.with(|delegate| delegate.did_start_document(parser));
+        }
+
+        #[method(parserDidEndDocument:)]
+        fn parser_did_end_document(&self, parser: &NSXMLParser) {
+            self.with(|delegate| delegate.did_end_document(parser));
+        }
+
+        #[method(parser:didStartElement:namespaceURI:qualifiedName:attributes:)]
+        fn parser_did_start_element(
+            &self,
+            parser: &NSXMLParser,
+            element_name: &NSString,
+            namespace_uri: Option<&NSString>,
+            qualified_name: Option<&NSString>,
+            attributes: &NSDictionary<NSString, NSString>,
+        ) {
+            self.with(|delegate| {
+                delegate.did_start_element(
+                    parser,
+                    element_name,
+                    namespace_uri,
+                    qualified_name,
+                    attributes,
+                )
+            });
+        }
+
+        #[method(parser:didEndElement:namespaceURI:qualifiedName:)]
+        fn parser_did_end_element(
+            &self,
+            parser: &NSXMLParser,
+            element_name: &NSString,
+            namespace_uri: Option<&NSString>,
+            qualified_name: Option<&NSString>,
+        ) {
+            self.with(|delegate| {
+                delegate.did_end_element(parser, element_name, namespace_uri, qualified_name)
+            });
+        }
+
+        #[method(parser:foundCharacters:)]
+        fn parser_found_characters(&self, parser: &NSXMLParser, string: &NSString) {
+            self.with(|delegate| delegate.found_characters(parser, string));
+        }
+
+        #[method(parser:foundCDATA:)]
+        fn parser_found_cdata(&self, parser: &NSXMLParser, cdata: &NSData) {
+            self.with(|delegate| delegate.found_cdata(parser, cdata));
+        }
+
+        #[method(parser:parseErrorOccurred:)]
+        fn parser_parse_error_occurred(&self, parser: &NSXMLParser, error: &NSError) {
+            self.with(|delegate| delegate.parse_error_occurred(parser, error));
+        }
+    }
+);
+
+impl RustParserDelegate {
+    fn new(delegate: &mut dyn ParserDelegate) -> Retained<Self> {
+        let ptr = NonNull::from(delegate);
+        // SAFETY: Only the lifetime is erased. The pointer is only
+        // dereferenced while `parse_with` is running, during which the
+        // delegate is mutably borrowed.
+        let ptr: DelegatePtr = unsafe { core::mem::transmute(ptr) };
+        let this = Self::alloc().set_ivars(Cell::new(Some(ptr)));
+        unsafe { msg_send_id![super(this), init] }
+    }
+
+    fn with(&self, f: impl FnOnce(&mut dyn ParserDelegate)) {
+        // Take the pointer while the delegate is in use, so that re-entrant
+        // calls (which the parser shouldn't make) can't create aliasing
+        // mutable references.
+        if let Some(mut ptr) = self.ivars().take() {
+            // SAFETY: See `new`.
+            f(unsafe { ptr.as_mut() });
+            self.ivars().set(Some(ptr));
+        }
+    }
+}
+
+impl NSXMLParser {
+    /// Create a parser for the given XML data.
+    #[doc(alias = "initWithData:")]
+    pub fn from_slice(bytes: &[u8]) -> Retained<Self> {
+        Self::initWithData(Self::alloc(), &NSData::with_bytes(bytes))
+    }
+
+    /// Parse the document, reporting events to the given delegate.
+    ///
+    /// This blocks until the whole document has been parsed, or until
+    /// parsing is aborted.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use objc2_foundation::xml_parser::ParserDelegate;
+    /// use objc2_foundation::{NSDictionary, NSString, NSXMLParser};
+    ///
+    /// #[derive(Default)]
+    /// struct ElementNames(Vec<String>);
+    ///
+    /// impl ParserDelegate for ElementNames {
+    ///     fn did_start_element(
+    ///         &mut self,
+    ///         _parser: &NSXMLParser,
+    ///         element_name: &NSString,
+    ///         _namespace_uri: Option<&NSString>,
+    ///         _qualified_name: Option<&NSString>,
+    ///         _attributes: &NSDictionary<NSString, NSString>,
+    ///     ) {
+    ///         self.0.push(element_name.to_string());
+    ///     }
+    /// }
+    ///
+    /// let parser = NSXMLParser::from_slice(b"<a><b/><c/></a>");
+    /// let mut names = ElementNames::default();
+    /// parser.parse_with(&mut names).unwrap();
+    /// assert_eq!(names.0, ["a", "b", "c"]);
+    /// ```
+    #[doc(alias = "parse")]
+    #[doc(alias = "setDelegate:")]
+    pub fn parse_with(&self, delegate: &mut impl ParserDelegate) -> Result<(), Retained<NSError>> {
+        let rust_delegate = RustParserDelegate::new(delegate);
+        // SAFETY: The delegate is kept alive until after parsing.
+        unsafe { self.setDelegate(Some(ProtocolObject::from_ref(&*rust_delegate))) };
+        let success = self.parse();
+        // Make sure that the delegate isn't used after we return.
+        unsafe { self.setDelegate(None) };
+        rust_delegate.ivars().set(None);
+
+        if success {
+            Ok(())
+        } else {
+            Err(self.parserError().expect("parsing failed without an error"))
+        }
+    }
+}
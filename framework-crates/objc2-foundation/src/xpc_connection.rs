@@ -0,0 +1,287 @@
+//! Utilities for the `NSXPCConnection`, `NSXPCInterface` and
+//! `NSXPCListener` classes.
+//!
+//! The interfaces are described by protocols declared with
+//! [`extern_protocol!`]. Note that `NSXPCInterface` uses the protocol as
+//! registered with the Objective-C runtime, so the protocol must have been
+//! compiled into the program, e.g. by linking Objective-C code that
+//! references it with `@protocol(...)`.
+//!
+//! [`extern_protocol!`]: objc2::extern_protocol
+use alloc::boxed::Box;
+use core::ptr::NonNull;
+
+use objc2::rc::Retained;
+use objc2::runtime::{AnyObject, AnyProtocol, NSObject, NSObjectProtocol, ProtocolObject};
+use objc2::{define_class, msg_send_id, AllocAnyThread, DefinedClass, ProtocolType};
+
+use crate::{NSError, NSXPCConnection, NSXPCInterface, NSXPCListener, NSXPCListenerDelegate};
+
+fn protocol<P: ?Sized + ProtocolType>() -> &'static AnyProtocol {
+    P::protocol()
+        .unwrap_or_else(|| panic!("protocol {} is not registered with the runtime", P::NAME))
+}
+
+impl NSXPCInterface {
+    /// Create an interface from the protocol `P`.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if the protocol is not registered with the Objective-C
+    /// runtime.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2::extern_protocol;
+    /// use objc2::runtime::NSObjectProtocol;
+    /// use objc2_foundation::NSXPCInterface;
+    ///
+    /// extern_protocol!(
+    ///     unsafe trait HelperProtocol: NSObjectProtocol {}
+    ///
+    ///     unsafe impl ProtocolType for dyn HelperProtocol {}
+    /// );
+    ///
+    /// let interface = NSXPCInterface::from_protocol::<dyn HelperProtocol>();
+    /// ```
+    #[doc(alias = "interfaceWithProtocol:")]
+    pub fn from_protocol<P: ?Sized + ProtocolType>() -> Retained<Self> {
+        Self::interfaceWithProtocol(protocol::<P>())
+    }
+}
+
+/// Proxies and exported objects.
+impl NSXPCConnection {
+    /// Get a proxy for the object exported by the other side of the
+    /// connection.
+    ///
+    /// Messages sent to the proxy are forwarded asynchronously to the remote
+    /// object. If the message cannot be delivered, e.g. because the
+    /// connection was interrupted, `error_handler` is called instead of the
+    /// reply block of the message.
+    ///
+    /// The error handler may be called on any thread.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if `remoteObjectInterface` has not been set, or if its
+    /// protocol does not conform to `P`.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2::runtime::NSObjectProtocol;
+    /// use objc2::{extern_protocol, AllocAnyThread};
+    /// use objc2_foundation::{ns_string, NSXPCConnection, NSXPCConnectionOptions, NSXPCInterface};
+    ///
+    /// extern_protocol!(
+    ///     unsafe trait HelperProtocol: NSObjectProtocol {
+    ///         #[method(ping)]
+    ///         fn ping(&self);
+    ///     }
+    ///
+    ///     unsafe impl ProtocolType for dyn HelperProtocol {}
+    /// );
+    ///
+    /// let connection = NSXPCConnection::initWithMachServiceName_options(
+    ///     NSXPCConnection::alloc(),
+    ///     ns_string!("com.example.helper"),
+    ///     NSXPCConnectionOptions::Privileged,
+    /// );
+    /// connection.setRemoteObjectInterface(Some(&NSXPCInterface::from_protocol::<dyn HelperProtocol>()));
+    /// connection.resume();
+    ///
+    /// let proxy = connection.remote_object_proxy::<dyn HelperProtocol>(|error| {
+    ///     eprintln!("failed talking to helper: {error}");
+    /// });
+    /// proxy.ping();
+    /// ```
+    #[doc(alias = "remoteObjectProxyWithErrorHandler:")]
+    pub fn remote_object_proxy<P: ?Sized + ProtocolType>(
+        &self,
+        error_handler: impl Fn(&NSError) + Send + Sync + 'static,
+    ) -> Retained<ProtocolObject<P>> {
+        self.assert_remote_protocol::<P>();
+        let block = block2::RcBlock::new(move |error: NonNull<NSError>| {
+            // SAFETY: The error is valid for the duration of the call.
+            error_handler(unsafe { error.as_ref() })
+        });
+        // SAFETY: The block is `Send + Sync` and `'static`.
+        let proxy = unsafe { self.remoteObjectProxyWithErrorHandler(&block) };
+        // SAFETY: The proxy implements the methods of the remote interface's
+        // protocol, which we checked conforms to `P`.
+        unsafe { Retained::cast_unchecked(proxy) }
+    }
+
+    /// Get a proxy for the object exported by the other side of the
+    /// connection, whose methods wait for the reply before returning.
+    ///
+    /// See [`remote_object_proxy`][Self::remote_object_proxy] for details.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if `remoteObjectInterface` has not been set, or if its
+    /// protocol does not conform to `P`.
+    #[doc(alias = "synchronousRemoteObjectProxyWithErrorHandler:")]
+    pub fn synchronous_remote_object_proxy<P: ?Sized + ProtocolType>(
+        &self,
+        error_handler: impl Fn(&NSError) + Send + Sync + 'static,
+    ) -> Retained<ProtocolObject<P>> {
+        self.assert_remote_protocol::<P>();
+        let block = block2::RcBlock::new(move |error: NonNull<NSError>| {
+            // SAFETY: The error is valid for the duration of the call.
+            error_handler(unsafe { error.as_ref() })
+        });
+        // SAFETY: The block is `Send + Sync` and `'static`.
+        let proxy = unsafe { self.synchronousRemoteObjectProxyWithErrorHandler(&block) };
+        // SAFETY: Same as in `remote_object_proxy`.
+        unsafe { Retained::cast_unchecked(proxy) }
+    }
+
+    fn assert_remote_protocol<P: ?Sized + ProtocolType>(&self) {
+        let interface = self
+            .remoteObjectInterface()
+            .expect("remoteObjectInterface must be set before creating a proxy");
+        let remote = interface.protocol();
+        let expected = protocol::<P>();
+        assert!(
+            remote.conforms_to(expected) || *remote == *expected,
+            "remote interface protocol {:?} does not conform to {}",
+            remote.name(),
+            P::NAME,
+        );
+    }
+
+    /// Set the object that the other side of the connection can send
+    /// messages to.
+    ///
+    ///
+    /// # Panics
+    ///
+    /// Panics if `exportedInterface` has not been set, or if `P` does not
+    /// conform to its protocol.
+    #[doc(alias = "setExportedObject:")]
+    pub fn set_exported_object<P: ?Sized + ProtocolType>(&self, object: &ProtocolObject<P>) {
+        let interface = self
+            .exportedInterface()
+            .expect("exportedInterface must be set before exporting an object");
+        let exported = interface.protocol();
+        let protocol = protocol::<P>();
+        assert!(
+            protocol.conforms_to(&exported) || *protocol == *exported,
+            "{} does not conform to exported interface protocol {:?}",
+            P::NAME,
+            exported.name(),
+        );
+        let object: *const ProtocolObject<P> = object;
+        // SAFETY: Protocol objects are always valid objects.
+        let object = unsafe { &*object.cast::<AnyObject>() };
+        // SAFETY: The object implements the exported interface's protocol.
+        unsafe { self.setExportedObject(Some(object)) };
+    }
+}
+
+/// Handlers.
+///
+/// The handlers may be invoked on any thread, and are therefore required to
+/// be `Send + Sync`.
+impl NSXPCConnection {
+    /// Set the closure to run when the process on the other side of the
+    /// connection crashes or exits.
+    ///
+    /// The connection remains valid, and sending a new message to a proxy
+    /// relaunches the service.
+    #[doc(alias = "setInterruptionHandler:")]
+    pub fn set_interruption_handler(&self, handler: impl Fn() + Send + Sync + 'static) {
+        let block = block2::RcBlock::new(handler);
+        // SAFETY: The block is `Send + Sync` and `'static`.
+        unsafe { self.setInterruptionHandler(Some(&block)) };
+    }
+
+    /// Set the closure to run when the connection is invalidated.
+    ///
+    /// The connection cannot be used afterwards.
+    #[doc(alias = "setInvalidationHandler:")]
+    pub fn set_invalidation_handler(&self, handler: impl Fn() + Send + Sync + 'static) {
+        let block = block2::RcBlock::new(handler);
+        // SAFETY: The block is `Send + Sync` and `'static`.
+        unsafe { self.setInvalidationHandler(Some(&block)) };
+    }
+}
+
+type ConnectionHandler = Box<dyn Fn(&NSXPCConnection) -> bool + Send + Sync + 'static>;
+
+define_class!(
+    // SAFETY:
+    // - The superclass NSObject does not have any subclassing requirements.
+    // - `ListenerDelegate` does not implement `Drop`.
+    #[unsafe(super(NSObject))]
+    #[name = "Objc2XPCListenerDelegate"]
+    #[ivars = ConnectionHandler]
+    struct ListenerDelegate;
+
+    unsafe impl NSObjectProtocol for ListenerDelegate {}
+
+    unsafe impl NSXPCListenerDelegate for ListenerDelegate {
+        #[method(listener:shouldAcceptNewConnection:)]
+        fn should_accept_new_connection(
+            &self,
+            _listener: &NSXPCListener,
+            connection: &NSXPCConnection,
+        ) -> bool {
+            (self.ivars())(connection)
+        }
+    }
+);
+
+impl NSXPCListener {
+    /// Set a closure to decide whether to accept new connections.
+    ///
+    /// The closure should configure the exported interface and object of
+    /// the connection, resume it and return `true` to accept it, or return
+    /// `false` to reject it. It may be called on any thread.
+    ///
+    /// `NSXPCListener` declares its `delegate` property as `weak`, so the
+    /// listener does not keep the returned delegate alive. Keep it around for
+    /// as long as the listener should accept connections; once it is
+    /// dropped, the listener sees a `nil` delegate and rejects all new
+    /// connections.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use objc2_foundation::NSXPCListener;
+    ///
+    /// let listener = NSXPCListener::serviceListener();
+    /// let _delegate = listener.set_connection_handler(|connection| {
+    ///     // Configure `exportedInterface` and the exported object here.
+    ///     connection.resume();
+    ///     true
+    /// });
+    /// listener.resume();
+    /// ```
+    #[doc(alias = "setDelegate:")]
+    #[doc(alias = "listener:shouldAcceptNewConnection:")]
+    pub fn set_connection_handler(
+        &self,
+        handler: impl Fn(&NSXPCConnection) -> bool + Send + Sync + 'static,
+    ) -> Retained<ProtocolObject<dyn NSXPCListenerDelegate>> {
+        let handler: ConnectionHandler = Box::new(handler);
+        let delegate = ListenerDelegate::alloc().set_ivars(handler);
+        let delegate: Retained<ListenerDelegate> = unsafe { msg_send_id![super(delegate), init] };
+        let delegate = ProtocolObject::from_retained(delegate);
+        // SAFETY: The listener's `delegate` property is `weak`, not
+        // `assign`, so the runtime clears it when the delegate is
+        // deallocated, and the listener never messages a dangling pointer
+        // even if the caller drops the returned delegate.
+        unsafe { self.setDelegate(Some(&delegate)) };
+        delegate
+    }
+}